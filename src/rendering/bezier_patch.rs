@@ -0,0 +1,233 @@
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::MeshIndices;
+use crate::rendering::shapes::Mesh;
+
+use crate::rendering::Material;
+
+// Keeps very tight tolerances from producing huge meshes
+const MAX_SEGMENTS: usize = 64;
+
+// Inner grid needs two segments each way to leave a ring for stitching edges to
+const MINIMUM_INNER_SEGMENTS: usize = 2;
+
+// Distance parameters move toward center of patch where edges collapse to a point and have no normal
+const DEGENERATE_OFFSET: f64 = 0.0001;
+
+// Bicubic surface shaped by a 4 by 4 grid of control points, listed row by row with u running along rows and v across them
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct BezierPatch {
+  pub control_points: [Point; 16]
+}
+
+impl BezierPatch {
+  pub fn new(control_points: [Point; 16]) -> BezierPatch {
+    BezierPatch { control_points }
+  }
+
+  pub fn control_point(&self, row: usize, column: usize) -> Point {
+    self.control_points[row * 4 + column]
+  }
+
+  pub fn point_at(&self, u: f64, v: f64) -> Point {
+    let (x, y, z) = self.combine(&bernstein(u), &bernstein(v));
+
+    Point::new(x, y, z)
+  }
+
+  // Partial derivatives along u and v
+  pub fn tangents_at(&self, u: f64, v: f64) -> (Vector, Vector) {
+    let (u_x, u_y, u_z) = self.combine(&bernstein_derivative(u), &bernstein(v));
+    let (v_x, v_y, v_z) = self.combine(&bernstein(u), &bernstein_derivative(v));
+
+    (Vector::new(u_x, u_y, u_z), Vector::new(v_x, v_y, v_z))
+  }
+
+  pub fn normal_at(&self, u: f64, v: f64) -> Vector {
+    let (tangent_u, tangent_v) = self.tangents_at(u, v);
+    let normal = tangent_u.cross(&tangent_v);
+
+    if normal.magnitude() > 1e-12 {
+      return normal.normalize();
+    }
+
+    // Collapsed edges, like the top of a teapot lid, take normal from just inside patch
+    let nudged_u = u + (0.5 - u).signum() * DEGENERATE_OFFSET;
+    let nudged_v = v + (0.5 - v).signum() * DEGENERATE_OFFSET;
+    let (tangent_u, tangent_v) = self.tangents_at(nudged_u, nudged_v);
+
+    tangent_u.cross(&tangent_v).normalize()
+  }
+
+  fn combine(&self, weights_u: &[f64; 4], weights_v: &[f64; 4]) -> (f64, f64, f64) {
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+
+    for (row, weight_v) in weights_v.iter().enumerate() {
+      for (column, weight_u) in weights_u.iter().enumerate() {
+        let weight = weight_u * weight_v;
+        let control_point = self.control_point(row, column);
+
+        x += control_point.x * weight;
+        y += control_point.y * weight;
+        z += control_point.z * weight;
+      }
+    }
+
+    (x, y, z)
+  }
+
+  pub fn row(&self, row: usize) -> [Point; 4] {
+    [self.control_point(row, 0), self.control_point(row, 1), self.control_point(row, 2), self.control_point(row, 3)]
+  }
+
+  pub fn column(&self, column: usize) -> [Point; 4] {
+    [self.control_point(0, column), self.control_point(1, column), self.control_point(2, column), self.control_point(3, column)]
+  }
+
+  // Segments needed for chords of a cubic curve to stay within tolerance of it, from how far its control polygon bends
+  pub fn curve_segments(points: &[Point; 4], tolerance: f64) -> usize {
+    let bend = |a: &Point, b: &Point, c: &Point| Vector::new(a.x - 2.0 * b.x + c.x, a.y - 2.0 * b.y + c.y, a.z - 2.0 * b.z + c.z).magnitude();
+
+    let curvature = bend(&points[0], &points[1], &points[2]).max(bend(&points[1], &points[2], &points[3]));
+
+    ((0.75 * curvature / tolerance).sqrt().ceil() as usize).clamp(1, MAX_SEGMENTS)
+  }
+
+  // Adds triangles within tolerance of patch to buffers, normals and texture coordinates share vertex indices.
+  // Each edge is split by its own curve alone, so patches sharing an edge split it the same way and leave no cracks
+  pub fn tessellate_into(&self, tolerance: f64, vertices: &mut Vec<Point>, normals: &mut Vec<Vector>, texture_vertices: &mut Vec<(f64, f64)>, triangles: &mut Vec<MeshIndices>) {
+    let mut add_vertex = |u: f64, v: f64| {
+      vertices.push(self.point_at(u, v));
+      normals.push(self.normal_at(u, v));
+      texture_vertices.push((u, v));
+
+      vertices.len() - 1
+    };
+
+    let bottom_segments = BezierPatch::curve_segments(&self.row(0), tolerance);
+    let top_segments = BezierPatch::curve_segments(&self.row(3), tolerance);
+    let left_segments = BezierPatch::curve_segments(&self.column(0), tolerance);
+    let right_segments = BezierPatch::curve_segments(&self.column(3), tolerance);
+
+    let segments_u = (0..4).map(|row| BezierPatch::curve_segments(&self.row(row), tolerance)).max().unwrap().max(MINIMUM_INNER_SEGMENTS);
+    let segments_v = (0..4).map(|column| BezierPatch::curve_segments(&self.column(column), tolerance)).max().unwrap().max(MINIMUM_INNER_SEGMENTS);
+
+    // Inner grid stops one segment short of each edge
+    let mut inner = Vec::new();
+    for j in 1..segments_v {
+      for i in 1..segments_u {
+        inner.push(add_vertex(i as f64 / segments_u as f64, j as f64 / segments_v as f64));
+      }
+    }
+
+    let inner_columns = segments_u - 1;
+    let inner_at = |i: usize, j: usize| inner[(j - 1) * inner_columns + (i - 1)];
+
+    let mut faces = Vec::new();
+
+    for j in 1..(segments_v - 1) {
+      for i in 1..(segments_u - 1) {
+        faces.push([inner_at(i, j), inner_at(i + 1, j), inner_at(i + 1, j + 1)]);
+        faces.push([inner_at(i, j), inner_at(i + 1, j + 1), inner_at(i, j + 1)]);
+      }
+    }
+
+    // Corners are shared by two edges, and edges list points from corner to corner going counter clockwise,
+    // so strips stitched along neighbouring edges meet at line from each corner to nearest inner point
+    let corners = [add_vertex(0.0, 0.0), add_vertex(1.0, 0.0), add_vertex(1.0, 1.0), add_vertex(0.0, 1.0)];
+
+    let mut edge = |segments: usize, start: usize, end: usize, position: &dyn Fn(f64) -> (f64, f64)| {
+      let mut points = vec![(0.0, start)];
+
+      for k in 1..segments {
+        let along = k as f64 / segments as f64;
+        let (u, v) = position(along);
+
+        points.push((along, add_vertex(u, v)));
+      }
+
+      points.push((1.0, end));
+      points
+    };
+
+    let bottom = edge(bottom_segments, corners[0], corners[1], &|along| (along, 0.0));
+    let right = edge(right_segments, corners[1], corners[2], &|along| (1.0, along));
+    let top = edge(top_segments, corners[2], corners[3], &|along| (1.0 - along, 1.0));
+    let left = edge(left_segments, corners[3], corners[0], &|along| (0.0, 1.0 - along));
+
+    // Matching sides of inner grid, placed by same distance along edge
+    let (last_i, last_j) = (segments_u - 1, segments_v - 1);
+    let side_u = |i: usize| i as f64 / segments_u as f64;
+    let side_v = |j: usize| j as f64 / segments_v as f64;
+
+    let inner_bottom: Vec<(f64, usize)> = (1..=last_i).map(|i| (side_u(i), inner_at(i, 1))).collect();
+    let inner_right: Vec<(f64, usize)> = (1..=last_j).map(|j| (side_v(j), inner_at(last_i, j))).collect();
+    let inner_top: Vec<(f64, usize)> = (1..=last_i).rev().map(|i| (1.0 - side_u(i), inner_at(i, last_j))).collect();
+    let inner_left: Vec<(f64, usize)> = (1..=last_j).rev().map(|j| (1.0 - side_v(j), inner_at(1, j))).collect();
+
+    stitch(&bottom, &inner_bottom, &mut faces);
+    stitch(&right, &inner_right, &mut faces);
+    stitch(&top, &inner_top, &mut faces);
+    stitch(&left, &inner_left, &mut faces);
+
+    for face in faces {
+      let edge_1 = vertices[face[1]].subtract_point(&vertices[face[0]]);
+      let edge_2 = vertices[face[2]].subtract_point(&vertices[face[0]]);
+
+      // Collapsed edges leave faces with no area, which could never be hit
+      if edge_1.cross(&edge_2).magnitude() < 1e-12 {
+        continue;
+      }
+
+      triangles.push(MeshIndices { vertices: face, normals: Some(face), texture_vertices: Some(face), material: 0 });
+    }
+  }
+
+  // Single mesh holding tessellation of every patch, with smooth normals and texture coordinates running across each patch
+  pub fn mesh(patches: &[BezierPatch], tolerance: f64, transform: Matrix4x4, material: Material) -> Mesh {
+    let mut vertices = Vec::new();
+    let mut normals = Vec::new();
+    let mut texture_vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for patch in patches {
+      patch.tessellate_into(tolerance, &mut vertices, &mut normals, &mut texture_vertices, &mut triangles);
+    }
+
+    Mesh::new(vertices, normals, texture_vertices, triangles, transform, vec![material])
+  }
+}
+
+// Cubic Bernstein polynomials at t
+pub fn bernstein(t: f64) -> [f64; 4] {
+  let s = 1.0 - t;
+
+  [s * s * s, 3.0 * t * s * s, 3.0 * t * t * s, t * t * t]
+}
+
+pub fn bernstein_derivative(t: f64) -> [f64; 4] {
+  let s = 1.0 - t;
+
+  [-3.0 * s * s, 3.0 * s * s - 6.0 * t * s, 6.0 * t * s - 3.0 * t * t, 3.0 * t * t]
+}
+
+// Fills strip between an edge and the side of inner grid facing it, always advancing along whichever is behind
+fn stitch(outer: &[(f64, usize)], inner: &[(f64, usize)], faces: &mut Vec<[usize; 3]>) {
+  let (mut a, mut b) = (0, 0);
+
+  while a + 1 < outer.len() || b + 1 < inner.len() {
+    let advance_outer = b + 1 == inner.len() || (a + 1 < outer.len() && outer[a + 1].0 <= inner[b + 1].0);
+
+    if advance_outer {
+      faces.push([outer[a].1, outer[a + 1].1, inner[b].1]);
+      a += 1;
+    } else {
+      faces.push([outer[a].1, inner[b + 1].1, inner[b].1]);
+      b += 1;
+    }
+  }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::MeshIndices;
+
+  use crate::rendering::BezierPatch;
+  use crate::rendering::bezier_patch::bernstein;
+
+  use crate::rendering::Ray;
+  use crate::rendering::Computations;
+
+  use crate::rendering::Material;
+
+  // Patch over unit square in xz plane, with heights of control points added on
+  fn patch_with_heights(heights: [f64; 16]) -> BezierPatch {
+    let mut control_points = [Point::empty(); 16];
+
+    for row in 0..4 {
+      for column in 0..4 {
+        control_points[row * 4 + column] = Point::new(column as f64 / 3.0, heights[row * 4 + column], row as f64 / 3.0);
+      }
+    }
+
+    BezierPatch::new(control_points)
+  }
+
+  fn tessellate(patch: &BezierPatch, tolerance: f64) -> (Vec<Point>, Vec<(f64, f64)>, Vec<MeshIndices>) {
+    let mut vertices = Vec::new();
+    let mut normals = Vec::new();
+    let mut texture_vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    patch.tessellate_into(tolerance, &mut vertices, &mut normals, &mut texture_vertices, &mut triangles);
+
+    (vertices, texture_vertices, triangles)
+  }
+
+  #[test]
+  fn bernstein_weights_sum_to_one() {
+    for step in 0..=10 {
+      let weights = bernstein(step as f64 / 10.0);
+
+      assert!((weights.iter().sum::<f64>() - 1.0).abs() < 0.0000001);
+    }
+  }
+
+  #[test]
+  fn patch_passes_through_corner_control_points() {
+    let patch = patch_with_heights([0.0, 1.0, 1.0, 0.5, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0, 1.0, 0.25, 1.0, 1.0, 0.75]);
+
+    assert!(patch.point_at(0.0, 0.0) == patch.control_point(0, 0));
+    assert!(patch.point_at(1.0, 0.0) == patch.control_point(0, 3));
+    assert!(patch.point_at(0.0, 1.0) == patch.control_point(3, 0));
+    assert!(patch.point_at(1.0, 1.0) == patch.control_point(3, 3));
+
+    let middle = patch.point_at(0.5, 0.5);
+
+    assert!((middle.x - 0.5).abs() < 0.0001);
+    assert!((middle.z - 0.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn normal_of_flat_patch_is_perpendicular_to_it() {
+    let patch = patch_with_heights([0.0; 16]);
+
+    let normal = patch.normal_at(0.3, 0.7);
+
+    assert!(normal.x.abs() < 0.0001);
+    assert!((normal.y.abs() - 1.0).abs() < 0.0001);
+    assert!(normal.z.abs() < 0.0001);
+  }
+
+  #[test]
+  fn normal_found_where_edge_collapses_to_point() {
+    let mut patch = patch_with_heights([0.0, 0.0, 0.0, 0.0, 0.3, 0.3, 0.3, 0.3, 0.6, 0.6, 0.6, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    for column in 0..4 {
+      patch.control_points[12 + column] = Point::new(0.5, 1.0, 1.0);
+    }
+
+    let normal = patch.normal_at(0.5, 1.0);
+
+    assert!(!normal.x.is_nan() && !normal.y.is_nan() && !normal.z.is_nan());
+    assert!((normal.magnitude() - 1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn curved_edges_need_more_segments() {
+    let straight = [Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0)];
+    let curved = [Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 0.0), Point::new(2.0, 2.0, 0.0), Point::new(3.0, 0.0, 0.0)];
+
+    assert_eq!(BezierPatch::curve_segments(&straight, 0.01), 1);
+    assert!(BezierPatch::curve_segments(&curved, 0.01) > BezierPatch::curve_segments(&curved, 0.1));
+  }
+
+  #[test]
+  fn tessellation_of_flat_patch_covers_it_exactly() {
+    // Bent control points in plane give edges different numbers of segments
+    let mut patch = patch_with_heights([0.0; 16]);
+    patch.control_points[1] = Point::new(0.33, 0.0, -0.3);
+    patch.control_points[2] = Point::new(0.66, 0.0, 0.3);
+
+    let (vertices, _, triangles) = tessellate(&patch, 0.001);
+
+    let mut area = 0.0;
+    for triangle in &triangles {
+      let [index_1, index_2, index_3] = triangle.vertices;
+
+      let edge_1 = vertices[index_2].subtract_point(&vertices[index_1]);
+      let edge_2 = vertices[index_3].subtract_point(&vertices[index_1]);
+
+      area += edge_1.cross(&edge_2).magnitude() / 2.0;
+    }
+
+    // Wiggle along bottom edge adds and removes equal area
+    assert!((area - 1.0).abs() < 0.001);
+  }
+
+  #[test]
+  fn tessellation_leaves_no_holes_inside_patch() {
+    let patch = patch_with_heights([0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 2.0, 1.0, 0.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+    let (_, texture_vertices, triangles) = tessellate(&patch, 0.01);
+
+    let mut edge_uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for triangle in &triangles {
+      let [index_1, index_2, index_3] = triangle.vertices;
+
+      for (start, end) in [(index_1, index_2), (index_2, index_3), (index_3, index_1)].iter() {
+        *edge_uses.entry(((*start).min(*end), (*start).max(*end))).or_insert(0) += 1;
+      }
+    }
+
+    // Every edge is shared by two faces, except those on border of patch
+    for ((start, end), uses) in edge_uses {
+      let (u_1, v_1) = texture_vertices[start];
+      let (u_2, v_2) = texture_vertices[end];
+
+      let on_border = (u_1 == u_2 && (u_1 == 0.0 || u_1 == 1.0)) || (v_1 == v_2 && (v_1 == 0.0 || v_1 == 1.0));
+
+      assert_eq!(uses, if on_border { 1 } else { 2 });
+    }
+  }
+
+  #[test]
+  fn patches_sharing_edge_split_it_the_same_way() {
+    // Right patch is much more curved inside, but shares its left edge with left patch
+    let left = patch_with_heights([0.0, 0.1, 0.1, 0.0, 0.0, 0.1, 0.1, 0.5, 0.0, 0.1, 0.1, 0.5, 0.0, 0.1, 0.1, 0.0]);
+
+    let mut right_points = [Point::empty(); 16];
+    for row in 0..4 {
+      right_points[row * 4] = left.control_point(row, 3);
+
+      for column in 1..4 {
+        right_points[row * 4 + column] = Point::new(1.0 + column as f64 / 3.0, if column == 3 { 0.0 } else { 3.0 }, row as f64 / 3.0);
+      }
+    }
+    let right = BezierPatch::new(right_points);
+
+    let (left_vertices, left_uvs, _) = tessellate(&left, 0.001);
+    let (right_vertices, right_uvs, _) = tessellate(&right, 0.001);
+
+    let mut left_edge: Vec<Point> = left_vertices.iter().zip(left_uvs.iter()).filter(|(_, (u, _))| *u == 1.0).map(|(point, _)| *point).collect();
+    let mut right_edge: Vec<Point> = right_vertices.iter().zip(right_uvs.iter()).filter(|(_, (u, _))| *u == 0.0).map(|(point, _)| *point).collect();
+
+    left_edge.sort_by(|a, b| a.z.total_cmp(&b.z));
+    right_edge.sort_by(|a, b| a.z.total_cmp(&b.z));
+
+    assert_eq!(left_edge.len(), right_edge.len());
+    for (left_point, right_point) in left_edge.iter().zip(right_edge.iter()) {
+      assert!(left_point.subtract_point(right_point).magnitude() < 0.0000001);
+    }
+  }
+
+  #[test]
+  fn smaller_tolerance_gives_finer_tessellation() {
+    let patch = patch_with_heights([0.0, 1.0, 1.0, 0.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0, 1.0, 0.0, 1.0, 1.0, 0.0]);
+
+    let (_, _, coarse) = tessellate(&patch, 0.1);
+    let (_, _, fine) = tessellate(&patch, 0.001);
+
+    assert!(fine.len() > coarse.len());
+  }
+
+  #[test]
+  fn patch_mesh_has_smooth_normals_and_parametric_uvs() {
+    let patch = patch_with_heights([0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = BezierPatch::mesh(&[patch], 0.001, Matrix4x4::identity(), material);
+
+    // Control points are evenly spaced in x and z, so patch parameters match x and z
+    let ray = Ray::new(&Point::new(0.43, 5.0, 0.41), &Vector::new(0.0, -1.0, 0.0));
+    let intersections = mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+
+    let expected = patch.point_at(0.43, 0.41);
+    assert!((intersections[0].t - (5.0 - expected.y)).abs() < 0.001);
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+    let expected_normal = patch.normal_at(0.43, 0.41);
+
+    assert!(intersections[0].object.interpolates_normals());
+    assert!(computations.normal.subtract_vector(&expected_normal).magnitude() < 0.01 || computations.normal.add_vector(&expected_normal).magnitude() < 0.01);
+
+    let (u, v) = intersections[0].object.texture_coordinates(&computations.point, intersections[0].u, intersections[0].v);
+
+    assert!((u - 0.43).abs() < 0.001);
+    assert!((v - 0.41).abs() < 0.001);
+  }
+}
@@ -0,0 +1,125 @@
+use std::f64;
+
+use crate::rendering::math::Point;
+
+use crate::rendering::Ray;
+
+// Axis aligned bounding box
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Bounds {
+  pub minimum: Point,
+  pub maximum: Point
+}
+
+impl Bounds {
+  pub fn new(minimum: Point, maximum: Point) -> Bounds {
+    Bounds { minimum, maximum }
+  }
+
+  // Contains nothing, so adding any point produces bounds around only that point
+  pub fn empty() -> Bounds {
+    Bounds {
+      minimum: Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+      maximum: Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY)
+    }
+  }
+
+  pub fn from_points(points: &[Point]) -> Bounds {
+    let mut bounds = Bounds::empty();
+
+    for point in points {
+      bounds.add_point(point);
+    }
+
+    bounds
+  }
+
+  pub fn add_point(&mut self, point: &Point) {
+    self.minimum = Point::new(self.minimum.x.min(point.x), self.minimum.y.min(point.y), self.minimum.z.min(point.z));
+    self.maximum = Point::new(self.maximum.x.max(point.x), self.maximum.y.max(point.y), self.maximum.z.max(point.z));
+  }
+
+  pub fn merge(&self, other: &Bounds) -> Bounds {
+    let mut bounds = *self;
+
+    bounds.add_point(&other.minimum);
+    bounds.add_point(&other.maximum);
+
+    bounds
+  }
+
+  pub fn centroid(&self) -> Point {
+    Point::new(
+      (self.minimum.x + self.maximum.x) / 2.0,
+      (self.minimum.y + self.maximum.y) / 2.0,
+      (self.minimum.z + self.maximum.z) / 2.0
+    )
+  }
+
+  // Index of longest axis, 0 for x, 1 for y and 2 for z
+  pub fn largest_axis(&self) -> usize {
+    let x_extent = self.maximum.x - self.minimum.x;
+    let y_extent = self.maximum.y - self.minimum.y;
+    let z_extent = self.maximum.z - self.minimum.z;
+
+    if x_extent >= y_extent && x_extent >= z_extent {
+      0
+    } else if y_extent >= z_extent {
+      1
+    } else {
+      2
+    }
+  }
+
+  pub fn extent(&self, axis: usize) -> f64 {
+    axis_value(&self.maximum, axis) - axis_value(&self.minimum, axis)
+  }
+
+  // Tests whole line of ray, as intersections behind ray origin are still used for refraction
+  pub fn intersects(&self, ray: &Ray) -> bool {
+    self.intersection_range(ray).is_some()
+  }
+
+  // Distances along ray where it enters and leaves bounds
+  pub fn intersection_range(&self, ray: &Ray) -> Option<(f64, f64)> {
+    let mut t_min = f64::NEG_INFINITY;
+    let mut t_max = f64::INFINITY;
+
+    let origin = [ray.origin.x, ray.origin.y, ray.origin.z];
+    let direction = [ray.direction.x, ray.direction.y, ray.direction.z];
+
+    for axis in 0..3 {
+      let minimum = axis_value(&self.minimum, axis);
+      let maximum = axis_value(&self.maximum, axis);
+
+      // Ray parallel to slab misses unless origin lies between its planes
+      if direction[axis].abs() < f64::EPSILON {
+        if origin[axis] < minimum || origin[axis] > maximum {
+          return None;
+        }
+
+        continue;
+      }
+
+      let t_1 = (minimum - origin[axis]) / direction[axis];
+      let t_2 = (maximum - origin[axis]) / direction[axis];
+
+      t_min = t_min.max(t_1.min(t_2));
+      t_max = t_max.min(t_1.max(t_2));
+
+      if t_min > t_max {
+        return None;
+      }
+    }
+
+    Some((t_min, t_max))
+  }
+}
+
+pub fn axis_value(point: &Point, axis: usize) -> f64 {
+  match axis {
+    0 => point.x,
+    1 => point.y,
+    _ => point.z
+  }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::Bounds;
+
+  use crate::rendering::Ray;
+
+  #[test]
+  fn bounds_grow_to_contain_added_points() {
+    let mut bounds = Bounds::empty();
+
+    bounds.add_point(&Point::new(-5.0, 2.0, 0.0));
+    bounds.add_point(&Point::new(7.0, 0.0, -3.0));
+
+    assert!(bounds.minimum == Point::new(-5.0, 0.0, -3.0));
+    assert!(bounds.maximum == Point::new(7.0, 2.0, 0.0));
+    assert!(bounds.centroid() == Point::new(1.0, 1.0, -1.5));
+  }
+
+  #[test]
+  fn merging_bounds() {
+    let bounds_1 = Bounds::new(Point::new(-5.0, -2.0, 0.0), Point::new(7.0, 4.0, 4.0));
+    let bounds_2 = Bounds::from_points(&[Point::new(8.0, -7.0, -2.0), Point::new(14.0, 2.0, 8.0)]);
+
+    let merged = bounds_1.merge(&bounds_2);
+
+    assert!(merged.minimum == Point::new(-5.0, -7.0, -2.0));
+    assert!(merged.maximum == Point::new(14.0, 4.0, 8.0));
+  }
+
+  #[test]
+  fn finding_largest_axis() {
+    assert_eq!(Bounds::new(Point::new(0.0, 0.0, 0.0), Point::new(3.0, 1.0, 2.0)).largest_axis(), 0);
+    assert_eq!(Bounds::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 3.0, 2.0)).largest_axis(), 1);
+    assert_eq!(Bounds::new(Point::new(0.0, -4.0, 0.0), Point::new(1.0, -3.0, 2.0)).largest_axis(), 2);
+    assert_eq!(Bounds::new(Point::new(0.0, -4.0, 0.0), Point::new(1.0, -3.0, 2.0)).extent(2), 2.0);
+  }
+
+  #[test]
+  fn intersecting_ray_with_bounds() {
+    let bounds = Bounds::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+
+    let rays = vec![
+      (Point::new(15.0, 1.0, 2.0), Vector::new(-1.0, 0.0, 0.0), true),
+      (Point::new(-5.0, -1.0, 4.0), Vector::new(1.0, 0.0, 0.0), true),
+      (Point::new(7.0, 6.0, 5.0), Vector::new(0.0, -1.0, 0.0), true),
+      (Point::new(9.0, 0.0, 3.0), Vector::new(0.0, 0.0, 1.0), true),
+      (Point::new(8.0, 2.0, 12.0), Vector::new(0.0, 0.0, -1.0), true),
+      (Point::new(15.0, 0.0, 2.0), Vector::new(0.0, 0.0, 1.0), false),
+      (Point::new(9.0, -5.0, 3.0), Vector::new(1.0, 0.0, 0.0), false),
+      (Point::new(12.0, 5.0, 4.0), Vector::new(1.0, 0.0, 0.0), false),
+      (Point::new(-3.0, 2.0, 0.0), Vector::new(1.0, 1.0, 1.0), false)
+    ];
+
+    for (origin, direction, expected) in rays {
+      let ray = Ray::new(&origin, &direction);
+
+      assert_eq!(bounds.intersects(&ray), expected);
+    }
+  }
+
+  #[test]
+  fn bounds_behind_ray_origin_are_intersected() {
+    let bounds = Bounds::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, 5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert!(bounds.intersects(&ray));
+  }
+
+  #[test]
+  fn finding_where_ray_enters_and_leaves_bounds() {
+    let bounds = Bounds::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+    let ray = Ray::new(&Point::new(0.5, 0.0, -5.0), &Vector::new(0.0, 0.0, 2.0));
+
+    assert_eq!(bounds.intersection_range(&ray), Some((2.0, 3.0)));
+
+    let ray = Ray::new(&Point::new(2.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!(bounds.intersection_range(&ray), None);
+  }
+}
@@ -1,8 +1,10 @@
 use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
 
 use crate::rendering::math::Matrix4x4;
 
 use crate::rendering::Ray;
+use crate::rendering::RayDifferentials;
 
 pub struct Camera {
   pub horizontal_size: u64,
@@ -52,8 +54,13 @@ impl Camera {
   }
 
   pub fn ray_for_pixel(&self, x: u64, y: u64) -> Ray {
-    let x_offset = ((x as f64) + 0.5) * self.pixel_size;
-    let y_offset = ((y as f64) + 0.5) * self.pixel_size;
+    self.ray_through_pixel(x, y, (0.5, 0.5))
+  }
+
+  // Offset places ray within pixel, from its top left corner at zero to its bottom right at one
+  pub fn ray_through_pixel(&self, x: u64, y: u64, offset: (f64, f64)) -> Ray {
+    let x_offset = ((x as f64) + offset.0) * self.pixel_size;
+    let y_offset = ((y as f64) + offset.1) * self.pixel_size;
 
     let world_x = self.half_width - x_offset;
     let world_y = self.half_height - y_offset;
@@ -63,7 +70,28 @@ impl Camera {
     let origin = self.inverse.mult_point(&Point::new(0.0, 0.0, 0.0));
     let direction = pixel.subtract_point(&origin).normalize();
 
-    Ray::new(&origin, &direction)
+    self.with_pixel_differentials(Ray::new(&origin, &direction), 1.0)
+  }
+
+  // Differentials step to neighbouring samples on canvas, spacing is distance between samples in pixels
+  pub fn with_pixel_differentials(&self, ray: Ray, spacing: f64) -> Ray {
+    // Direction reaching canvas at z = -1, before it was normalized
+    let camera_direction = self.transform.mult_vector(&ray.direction);
+    let canvas_direction = self.inverse.mult_vector(&camera_direction.multiply(-1.0 / camera_direction.z));
+
+    // Canvas x and y run opposite to pixel columns and rows
+    let step = self.pixel_size * spacing;
+    let canvas_dx = self.inverse.mult_vector(&Vector::new(-step, 0.0, 0.0));
+    let canvas_dy = self.inverse.mult_vector(&Vector::new(0.0, -step, 0.0));
+
+    let differentials = RayDifferentials::new(
+      Vector::new(0.0, 0.0, 0.0),
+      Vector::new(0.0, 0.0, 0.0),
+      RayDifferentials::normalized_direction_offset(&canvas_direction, &canvas_dx),
+      RayDifferentials::normalized_direction_offset(&canvas_direction, &canvas_dy)
+    );
+
+    Ray::new_with_differentials(&ray.origin, &ray.direction, differentials)
   }
 
   pub fn rays_for_pixel_x4_sample_rate(&self, x: u64, y: u64) -> Vec<Ray> {
@@ -107,7 +135,8 @@ impl Camera {
     );    
     rays.push(Ray::new(&origin_point, &pixel.subtract_point(&origin_point).normalize()));
 
-    rays
+    // Samples are half a pixel apart
+    rays.into_iter().map(|ray| self.with_pixel_differentials(ray, 0.5)).collect()
   }
 
   pub fn rays_for_pixel_x16_sample_rate(&self, x: u64, y: u64) -> Vec<Ray> {
@@ -259,6 +288,7 @@ impl Camera {
     );    
     rays.push(Ray::new(&origin_point, &pixel.subtract_point(&origin_point).normalize()));
 
-    rays
+    // Samples are a quarter of a pixel apart
+    rays.into_iter().map(|ray| self.with_pixel_differentials(ray, 0.25)).collect()
   }
 }
@@ -2,6 +2,9 @@
 mod tests {
   use std::f64;
 
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Vector;
+
   use crate::rendering::math::Matrix4x4;
 
   use crate::rendering::Camera;
@@ -71,4 +74,53 @@ mod tests {
     assert!((ray.direction.y - 0.0).abs() < 0.0001);
     assert!((ray.direction.z - (-(2.0 as f64).sqrt() / 2.0)).abs() < 0.0001);
   }
+
+  #[test]
+  fn ray_for_pixel_carries_differentials_to_neighbouring_pixels() {
+    let camera = Camera::new(201, 101, f64::consts::PI / 2.0, Matrix4x4::rotate_y(f64::consts::PI / 4.0).mult4x4(&Matrix4x4::translate(0.0, -2.0, 5.0)));
+
+    let ray = camera.ray_for_pixel(30, 70);
+    let differentials = ray.differentials.unwrap();
+
+    let right = camera.ray_for_pixel(31, 70);
+    let down = camera.ray_for_pixel(30, 71);
+
+    assert!(differentials.origin_dx == Vector::new(0.0, 0.0, 0.0));
+    assert!(differentials.origin_dy == Vector::new(0.0, 0.0, 0.0));
+
+    // Differentials match steps to neighbouring pixels, up to small curvature of normalization
+    let step_x = right.direction.subtract_vector(&ray.direction);
+    let step_y = down.direction.subtract_vector(&ray.direction);
+
+    assert!(differentials.direction_dx.subtract_vector(&step_x).magnitude() < 0.0001);
+    assert!(differentials.direction_dy.subtract_vector(&step_y).magnitude() < 0.0001);
+  }
+
+  #[test]
+  fn supersampled_rays_carry_smaller_differentials() {
+    let camera = Camera::new(201, 101, f64::consts::PI / 2.0, Matrix4x4::identity());
+
+    let ray = camera.ray_for_pixel(100, 50);
+    let rays_x4 = camera.rays_for_pixel_x4_sample_rate(100, 50);
+    let rays_x16 = camera.rays_for_pixel_x16_sample_rate(100, 50);
+
+    let pixel_step = ray.differentials.unwrap().direction_dx.magnitude();
+
+    assert!((rays_x4[0].differentials.unwrap().direction_dx.magnitude() - pixel_step / 2.0).abs() < 0.0001);
+    assert!((rays_x16[0].differentials.unwrap().direction_dx.magnitude() - pixel_step / 4.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn ray_through_pixel_is_placed_by_offset_within_pixel() {
+    let camera = Camera::new(201, 101, f64::consts::PI / 2.0, Matrix4x4::identity());
+
+    let centered = camera.ray_through_pixel(100, 50, (0.5, 0.5));
+    assert!(centered.direction == camera.ray_for_pixel(100, 50).direction);
+
+    // Top left corner of canvas
+    let corner = camera.ray_through_pixel(0, 0, (0.0, 0.0));
+    let expected = Vector::new(camera.half_width, camera.half_height, -1.0).normalize();
+
+    assert!(corner.direction.subtract_vector(&expected).magnitude() < 0.0001);
+  }
 }
@@ -1,18 +1,25 @@
 use std::io::prelude::*;
+use std::io::{Error, ErrorKind};
 use std::fs::File;
+use std::cell::OnceCell;
 
 extern crate chrono;
 use chrono::Utc;
 
 use crate::rendering::math::Color;
 
+use crate::rendering::Mipmap;
+
 const MAX_PPM_LINE_WIDTH: usize = 70; 
 const CLEAR_COLOR: f64 = 1.0;
+const MAX_BOX_SAMPLES: u64 = 8;
 
 pub struct Canvas {
   pub width: u64,
   pub height: u64,
-  pub color_buffer: Vec<Color>
+  pub color_buffer: Vec<Color>,
+  // Built on first filtered lookup, and discarded when a pixel changes
+  pub mipmap: OnceCell<Mipmap>
 }
 
 impl Canvas {
@@ -25,17 +32,129 @@ impl Canvas {
       color_buffer.push(color);
     }
 
-    Canvas { width: width, height: height, color_buffer: color_buffer }
+    Canvas { width, height, color_buffer, mipmap: OnceCell::new() }
   }
 
   pub fn color_pixel(&mut self, row: u64, column: u64, color: Color) {
+    self.mipmap.take();
+
     self.color_buffer[((row * self.width) + column) as usize] = color
   }
 
+  pub fn mipmap(&self) -> &Mipmap {
+    self.mipmap.get_or_init(|| Mipmap::new(self))
+  }
+
   pub fn pixel_color(&self, row: u64, column: u64) -> &Color {
     self.color_buffer.get(((row * self.width) + column) as usize).unwrap()
   }
 
+  pub fn load_image(file_name: &str) -> std::io::Result<Canvas> {
+    let mut image_file = File::open(file_name)?;
+
+    let mut image_data = Vec::new();
+    image_file.read_to_end(&mut image_data)?;
+
+    Canvas::from_ppm(&image_data)
+  }
+
+  pub fn from_ppm(image_data: &[u8]) -> std::io::Result<Canvas> {
+    let mut position = 0;
+
+    let magic_number = next_ppm_token(image_data, &mut position)?;
+    let width = parse_ppm_value(&next_ppm_token(image_data, &mut position)?)?;
+    let height = parse_ppm_value(&next_ppm_token(image_data, &mut position)?)?;
+    let max_value = parse_ppm_value(&next_ppm_token(image_data, &mut position)?)?;
+
+    if width == 0 || height == 0 || max_value == 0 || max_value > 65535 {
+      return Err(Error::new(ErrorKind::InvalidData, "PPM header has invalid dimensions or color range"));
+    }
+
+    let mut canvas = Canvas::new(width, height);
+
+    let sample_count = (width * height * 3) as usize;
+    let mut samples = Vec::with_capacity(sample_count);
+
+    if magic_number == "P3" {
+      for _i in 0..sample_count {
+        samples.push(parse_ppm_value(&next_ppm_token(image_data, &mut position)?)?);
+      }
+
+    } else if magic_number == "P6" {
+      // Single whitespace character separates header from binary samples
+      position += 1;
+
+      let bytes_per_sample = if max_value < 256 { 1 } else { 2 };
+      if image_data.len() < position + sample_count * bytes_per_sample {
+        return Err(Error::new(ErrorKind::UnexpectedEof, "PPM pixel data is truncated"));
+      }
+
+      for i in 0..sample_count {
+        let offset = position + i * bytes_per_sample;
+
+        if bytes_per_sample == 1 {
+          samples.push(image_data[offset] as u64);
+        } else {
+          samples.push(((image_data[offset] as u64) << 8) | (image_data[offset + 1] as u64));
+        }
+      }
+
+    } else {
+      return Err(Error::new(ErrorKind::InvalidData, format!("Unsupported image format {}, expected P3 or P6 PPM", magic_number)));
+    }
+
+    for y in 0..height {
+      for x in 0..width {
+        let index = ((y * width + x) * 3) as usize;
+
+        canvas.color_pixel(y, x, Color::new(
+          (samples[index] as f64) / (max_value as f64),
+          (samples[index + 1] as f64) / (max_value as f64),
+          (samples[index + 2] as f64) / (max_value as f64),
+          1.0
+        ));
+      }
+    }
+
+    Ok(canvas)
+  }
+
+  pub fn color_at_uv(&self, u: f64, v: f64) -> Color {
+    // Coordinates outside of unit square repeat the image
+    let wrapped_u = if (0.0..=1.0).contains(&u) { u } else { u - u.floor() };
+    let wrapped_v = if (0.0..=1.0).contains(&v) { v } else { v - v.floor() };
+
+    // v runs from bottom to top of the image
+    let column = (wrapped_u * ((self.width - 1) as f64)).round() as u64;
+    let row = ((1.0 - wrapped_v) * ((self.height - 1) as f64)).round() as u64;
+
+    *self.pixel_color(row, column)
+  }
+
+  // Average of pixels in box around texture coordinates, half widths are in texture coordinates
+  pub fn color_in_uv_box(&self, u: f64, v: f64, u_width: f64, v_width: f64) -> Color {
+    // Samples are about a pixel apart, up to a limit along each side of box
+    let u_samples = ((2.0 * u_width * (self.width as f64)).ceil() as u64).clamp(1, MAX_BOX_SAMPLES);
+    let v_samples = ((2.0 * v_width * (self.height as f64)).ceil() as u64).clamp(1, MAX_BOX_SAMPLES);
+
+    if u_samples == 1 && v_samples == 1 {
+      return self.color_at_uv(u, v);
+    }
+
+    let mut color = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    for i in 0..u_samples {
+      for j in 0..v_samples {
+        let sample_u = u - u_width + (2.0 * u_width) * ((i as f64) + 0.5) / (u_samples as f64);
+        let sample_v = v - v_width + (2.0 * v_width) * ((j as f64) + 0.5) / (v_samples as f64);
+
+        color = color.add_color(&self.color_at_uv(sample_u, sample_v));
+      }
+    }
+
+    color.mult_scalar(1.0 / ((u_samples * v_samples) as f64))
+  }
+
   pub fn image_output(&self) -> Vec<String> {
     let mut image_data_lines: Vec<String> = Vec::new();
 
@@ -104,3 +223,33 @@ impl Canvas {
     Ok(())
   }
 }
+
+fn next_ppm_token(image_data: &[u8], position: &mut usize) -> std::io::Result<String> {
+  // Skip whitespace and comments preceding token
+  while *position < image_data.len() {
+    if image_data[*position] == b'#' {
+      while *position < image_data.len() && image_data[*position] != b'\n' {
+        *position += 1;
+      }
+    } else if image_data[*position].is_ascii_whitespace() {
+      *position += 1;
+    } else {
+      break;
+    }
+  }
+
+  let start = *position;
+  while *position < image_data.len() && !image_data[*position].is_ascii_whitespace() {
+    *position += 1;
+  }
+
+  if start == *position {
+    return Err(Error::new(ErrorKind::UnexpectedEof, "PPM data ended unexpectedly"));
+  }
+
+  Ok(String::from_utf8_lossy(&image_data[start..*position]).to_string())
+}
+
+fn parse_ppm_value(token: &str) -> std::io::Result<u64> {
+  token.parse::<u64>().map_err(|_| Error::new(ErrorKind::InvalidData, format!("Invalid PPM value {}", token)))
+}
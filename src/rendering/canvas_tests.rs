@@ -121,4 +121,74 @@ mod tests {
     assert_eq!(canvas_blue.b, 1.0);
     assert_eq!(canvas_blue.a, 1.0);
   }
+
+  #[test]
+  fn loads_ppm_v3_data_into_canvas() {
+    let image_data = "P3\n# comment line\n2 2\n255\n255 0 0  0 255 0\n0 0 255\n255 255 255\n";
+
+    let canvas = Canvas::from_ppm(image_data.as_bytes()).unwrap();
+
+    assert_eq!(canvas.width, 2);
+    assert_eq!(canvas.height, 2);
+    assert!(*canvas.pixel_color(0, 0) == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(*canvas.pixel_color(0, 1) == Color::new(0.0, 1.0, 0.0, 1.0));
+    assert!(*canvas.pixel_color(1, 0) == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(*canvas.pixel_color(1, 1) == Color::new(1.0, 1.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn loads_binary_ppm_data_into_canvas() {
+    let mut image_data = "P6\n2 1\n255\n".as_bytes().to_vec();
+    image_data.extend_from_slice(&[255, 0, 0, 0, 0, 255]);
+
+    let canvas = Canvas::from_ppm(&image_data).unwrap();
+
+    assert!(*canvas.pixel_color(0, 0) == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(*canvas.pixel_color(0, 1) == Color::new(0.0, 0.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn rejects_unsupported_or_truncated_image_data() {
+    assert!(Canvas::from_ppm("P5\n2 1\n255\n".as_bytes()).is_err());
+    assert!(Canvas::from_ppm("P3\n2 1\n255\n255 0 0\n".as_bytes()).is_err());
+    assert!(Canvas::from_ppm(&[b'P', b'6', b'\n', b'2', b' ', b'1', b'\n', b'2', b'5', b'5', b'\n', 255]).is_err());
+  }
+
+  #[test]
+  fn samples_canvas_with_texture_coordinates() {
+    let mut canvas = Canvas::new(2, 2);
+
+    canvas.color_pixel(0, 0, Color::new(1.0, 0.0, 0.0, 1.0));
+    canvas.color_pixel(0, 1, Color::new(0.0, 1.0, 0.0, 1.0));
+    canvas.color_pixel(1, 0, Color::new(0.0, 0.0, 1.0, 1.0));
+    canvas.color_pixel(1, 1, Color::new(0.0, 0.0, 0.0, 1.0));
+
+    // v of zero is bottom row of image
+    assert!(canvas.color_at_uv(0.0, 0.0) == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(canvas.color_at_uv(1.0, 0.0) == Color::new(0.0, 0.0, 0.0, 1.0));
+    assert!(canvas.color_at_uv(0.0, 1.0) == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(canvas.color_at_uv(1.0, 1.0) == Color::new(0.0, 1.0, 0.0, 1.0));
+
+    // Coordinates repeat outside of unit square
+    assert!(canvas.color_at_uv(1.9, -0.1) == Color::new(0.0, 1.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn color_in_uv_box_averages_pixels_under_box() {
+    let mut canvas = Canvas::new(2, 2);
+    canvas.color_pixel(0, 0, Color::new(0.0, 0.0, 0.0, 1.0));
+    canvas.color_pixel(1, 1, Color::new(0.0, 0.0, 0.0, 1.0));
+
+    // Box smaller than a pixel samples single pixel
+    assert!(canvas.color_in_uv_box(0.0, 1.0, 0.01, 0.01) == Color::new(0.0, 0.0, 0.0, 1.0));
+    assert!(canvas.color_in_uv_box(1.0, 1.0, 0.01, 0.01) == Color::new(1.0, 1.0, 1.0, 1.0));
+
+    // Box covering whole image averages its pixels
+    let color = canvas.color_in_uv_box(0.5, 0.5, 0.5, 0.5);
+
+    assert!((color.r - 0.5).abs() < 0.0001);
+    assert!((color.g - 0.5).abs() < 0.0001);
+    assert!((color.b - 0.5).abs() < 0.0001);
+    assert!((color.a - 1.0).abs() < 0.0001);
+  }
 }
@@ -1,11 +1,24 @@
+use std::rc::Rc;
+
 use crate::rendering::math::tuple::Tuple;
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
 
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
 use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::perpendicular_tangents;
 
 use crate::rendering::Intersection;
 use crate::rendering::Ray;
+use crate::rendering::RayDifferentials;
+use crate::rendering::SurfaceDifferentials;
+
+use crate::rendering::Material;
+
+const TANGENT_EPSILON: f64 = 1e-9;
 
 pub struct Computations<'a> {
   pub t: f64,
@@ -18,7 +31,13 @@ pub struct Computations<'a> {
   pub n1: f64,
   pub n2: f64,
   pub reflect_v: Vector,
-  pub object: &'a dyn Shape  
+  pub texture_u: f64,
+  pub texture_v: f64,
+  pub object: &'a dyn Shape,
+  pub world_to_container: Matrix4x4,
+  pub material: Option<Rc<Material>>,
+  pub ray_differentials: Option<RayDifferentials>,
+  pub differentials: Option<SurfaceDifferentials>
 }
 
 impl<'a> Computations<'a> {
@@ -30,14 +49,9 @@ impl<'a> Computations<'a> {
     
     let container_point = hit.world_to_container.mult_point(&point);
     
-    let mut normal;
-    if object.interpolates_normals() {
-      normal = hit.normal_to_world.mult_vector(&object.normal_at_with_uv(&container_point, hit.u, hit.v));
-    } else {
-      normal = hit.normal_to_world.mult_vector(&object.normal_at(&container_point)); 
-    }
-    normal.w = 0.0;
-    normal = normal.normalize();
+    let mut normal = world_normal(hit, &container_point);
+
+    let (texture_u, texture_v) = object.texture_coordinates(&container_point, hit.u, hit.v);
 
     let mut inside = false;
 
@@ -47,6 +61,38 @@ impl<'a> Computations<'a> {
       normal = normal.multiply(-1.0);
     }
 
+    // Footprint of neighbouring rays on surface, used to filter textures
+    let differentials = ray.differentials.map(|ray_differentials| {
+      let (point_dx, point_dy) = ray_differentials.point_offsets(&ray.direction, hit.t, &normal);
+
+      let offset_differentials = |point_offset: &Vector| {
+        let offset_point = hit.world_to_container.mult_point(&point.add_vector(point_offset));
+
+        // Offset normal is flipped with normal, when hit is inside object
+        let mut offset_normal = world_normal(hit, &offset_point);
+        if inside {
+          offset_normal = offset_normal.multiply(-1.0);
+        }
+        let normal_offset = offset_normal.subtract_vector(&normal);
+
+        let (offset_u, offset_v) = object.texture_coordinates(&offset_point, hit.u, hit.v);
+
+        (normal_offset, (wrapped_difference(offset_u, texture_u), wrapped_difference(offset_v, texture_v)))
+      };
+
+      let (normal_dx, texture_dx) = offset_differentials(&point_dx);
+      let (normal_dy, texture_dy) = offset_differentials(&point_dy);
+
+      SurfaceDifferentials {
+        point_dx,
+        point_dy,
+        normal_dx,
+        normal_dy,
+        texture_dx,
+        texture_dy
+      }
+    });
+
     // Point slightly off of object surface prevents shadow precision error
     let over_point = point.add_vector(&normal.multiply(0.001)); 
     
@@ -56,10 +102,18 @@ impl<'a> Computations<'a> {
     // Refractive indices of a materials being transitioned to and from
     let (n1, n2) = calculate_refractive_indices(hit, intersections);
 
+    // Normal and bump maps only change normal used for shading, offset points stay clear of real surface
+    let material = hit.get_material();
+    if material.perturbs_normal() {
+      let tangents = world_tangents(hit, &container_point, &normal);
+
+      normal = material.shading_normal(object, &point, texture_u, texture_v, &normal, &tangents);
+    }
+
     // Only calculate reflection vector if object material is reflective
     let mut reflect_v = Vector::new(0.0, 0.0, 0.0);
 
-    if object.get_material().reflectiveness > 0.0 {
+    if material.reflectiveness > 0.0 {
       reflect_v = ray.direction.reflect(&normal);
     }
 
@@ -74,20 +128,125 @@ impl<'a> Computations<'a> {
       n1: n1,
       n2: n2, 
       reflect_v: reflect_v,
-      object: object 
+      texture_u,
+      texture_v,
+      object,
+      world_to_container: hit.world_to_container,
+      material: hit.material.clone(),
+      ray_differentials: ray.differentials,
+      differentials
     } 
   }
+
+  pub fn get_material(&self) -> &Material {
+    match &self.material {
+      Some(material) => material,
+      None => self.object.get_material()
+    }
+  }
+
+  // Color of material at hit, including patterns and textures, filtered by footprint when ray has one
+  pub fn surface_color(&self) -> Color {
+    let material = self.get_material();
+
+    match &self.differentials {
+      Some(differentials) => material.filtered_color_at(self.object, &self.point, self.texture_u, self.texture_v, differentials),
+      None => material.color_at_with_uv(self.object, &self.point, self.texture_u, self.texture_v)
+    }
+  }
+
+  // Direction of ray bent through surface, none when light is totally reflected inside
+  pub fn refracted_direction(&self) -> Option<Vector> {
+    let n_ratio = self.n1 / self.n2;
+
+    // Dot calculates cosine of eye and normal vectors
+    let cos_i = self.eye_v.dot(&self.normal);
+
+    // Sin(theta_t)^2
+    let sin2_t = (n_ratio * n_ratio) * (1.0 - (cos_i * cos_i));
+
+    // Total internal reflection if sin2_t > 1
+    if sin2_t > 1.0 {
+      return None;
+    }
+
+    // Cos(theta_t)
+    let cos_t = (1.0 - sin2_t).sqrt();
+
+    Some(self.normal.multiply(n_ratio * cos_i - cos_t).subtract_vector(&self.eye_v.multiply(n_ratio)))
+  }
+}
+
+// Normal of object hit, carried from its container to world space
+pub fn world_normal(hit: &Intersection, container_point: &Point) -> Vector {
+  let object = hit.object;
+
+  let mut normal;
+  if object.interpolates_normals() {
+    normal = hit.normal_to_world.mult_vector(&object.normal_at_with_uv(container_point, hit.u, hit.v));
+  } else {
+    normal = hit.normal_to_world.mult_vector(&object.normal_at(container_point)); 
+  }
+  normal.w = 0.0;
+
+  normal.normalize()
+}
+
+// Unit tangents perpendicular to normal, bitangent keeps side of tangent that surface gives it
+fn world_tangents(hit: &Intersection, container_point: &Point, normal: &Vector) -> (Vector, Vector) {
+  let (tangent, bitangent) = hit.object.surface_tangents(container_point, hit.u, hit.v);
+
+  // Tangents lie along surface, so are carried to world space by forward transform
+  let container_to_world = hit.world_to_container.inverse();
+  let mut tangent = container_to_world.mult_vector(&tangent);
+  let mut bitangent = container_to_world.mult_vector(&bitangent);
+  tangent.w = 0.0;
+  bitangent.w = 0.0;
+
+  let mut tangent = tangent.subtract_vector(&normal.multiply(normal.dot(&tangent)));
+
+  // Degenerate tangent, such as at a pole, is replaced by any direction along surface
+  if tangent.magnitude() < TANGENT_EPSILON {
+    tangent = bitangent.cross(normal);
+
+    if tangent.magnitude() < TANGENT_EPSILON {
+      return perpendicular_tangents(normal);
+    }
+  }
+  let tangent = tangent.normalize();
+
+  // Bitangent side follows surface, as mirrored texture coordinates run v the other way
+  let frame_bitangent = tangent.cross(normal);
+  if frame_bitangent.dot(&bitangent) < 0.0 {
+    (tangent, frame_bitangent.multiply(-1.0))
+  } else {
+    (tangent, frame_bitangent)
+  }
+}
+
+// Texture coordinates wrap around, so a step across the seam is the short way around
+fn wrapped_difference(offset: f64, coordinate: f64) -> f64 {
+  let difference = offset - coordinate;
+
+  if difference > 0.5 {
+    difference - 1.0
+  } else if difference < -0.5 {
+    difference + 1.0
+  } else {
+    difference
+  }
 }
 
 fn calculate_refractive_indices<'a: 'b, 'b>(hit: &'b Intersection<'a>, intersections: &Vec<Intersection<'a>>) -> (f64, f64) {
-  let mut objects: Vec<&dyn Shape> = Vec::new();
+  // Intersections rather than objects are tracked, as containers may override object materials
+  let mut objects: Vec<&Intersection> = Vec::new();
   
   let mut n1 = 1.0;
   let mut n2 = 1.0;
 
   for intersection in intersections {
     // Set leaving refractor index when hit intersection found
-    if hit.t == intersection.t && hit.object.get_id() == intersection.object.get_id() {
+    if hit.t == intersection.t && hit.is_same_object(intersection) {
       if objects.len() == 0 {
         n1 = 1.0;
       }
@@ -98,18 +257,18 @@ fn calculate_refractive_indices<'a: 'b, 'b>(hit: &'b Intersection<'a>, intersect
       }
     }
 
-    let entered_object = objects.iter().position(|&o| o.get_id() == intersection.object.get_id());     
+    let entered_object = objects.iter().position(|o| o.is_same_object(intersection));     
     // Remove object if already encountered
     if let Some(i) = entered_object {
       objects.remove(i);  
 
     // Add object if first encountered
     } else {
-      objects.push(intersection.object);
+      objects.push(intersection);
     }
 
     // Set Entering refractor index when hit intersection found
-    if hit.t == intersection.t && hit.object.get_id() == intersection.object.get_id() {      
+    if hit.t == intersection.t && hit.is_same_object(intersection) {      
       if objects.len() == 0 {
         n2 = 1.0;
       }
@@ -2,6 +2,10 @@
 mod tests {
   use std::f64;
 
+  use std::rc::Rc;
+
+  use crate::rendering::math::tuple::Tuple;
+
   use crate::rendering::math::Point;
   use crate::rendering::math::Vector;
 
@@ -14,9 +18,18 @@ mod tests {
   use crate::rendering::shapes::SmoothTriangle;
 
   use crate::rendering::Material;
+  use crate::rendering::Canvas;
+
+  use crate::rendering::shapes::shape::Shape;
+
+  use crate::rendering::Camera;
+
+  use crate::rendering::Container;
+  use crate::rendering::Instance;
 
   use crate::rendering::Intersection;
   use crate::rendering::Ray;
+  use crate::rendering::RayDifferentials;
   use crate::rendering::Computations;
 
   #[test]
@@ -255,4 +268,183 @@ mod tests {
     assert_eq!(computations.n1, 1.5);
     assert_eq!(computations.n2, 1.0);
   }
+
+  #[test]
+  fn calculating_n1_and_n2_values_at_intersections_of_instances_of_one_sphere() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 1.0, 1.5, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let instance_1 = Instance::new(&sphere, Matrix4x4::scale(2.0, 2.0, 2.0));
+
+    let mut instance_2 = Instance::new(&sphere, Matrix4x4::translate(0.0, 0.0, -0.25));
+    instance_2.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 1.0, 2.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut instance_3 = Instance::new(&sphere, Matrix4x4::translate(0.0, 0.0, 0.25));
+    instance_3.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 1.0, 2.5, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let container = Container::new(Matrix4x4::identity(), vec![&instance_1 as &dyn Shape, &instance_2 as &dyn Shape, &instance_3 as &dyn Shape]);
+
+    // Every hit reports same sphere, entered and left through three different copies
+    let ray = Ray::new(&Point::new(0.0, 0.0, -4.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = container.intersect(&ray);
+
+    assert_eq!(intersections.len(), 6);
+
+    let expected = [(1.0, 1.5), (1.5, 2.0), (2.0, 2.5), (2.5, 2.5), (2.5, 1.5), (1.5, 1.0)];
+
+    for (intersection, (n1, n2)) in intersections.iter().zip(expected.iter()) {
+      let computations = Computations::new(intersection, &ray, &intersections);
+
+      assert_eq!(computations.n1, *n1);
+      assert_eq!(computations.n2, *n2);
+    }
+  }
+
+  #[test]
+  fn computations_track_footprint_of_camera_ray_on_plane() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let plane = Plane::new(Matrix4x4::translate(0.0, -1.0, 0.0), material);
+
+    let camera = Camera::new(100, 100, f64::consts::PI / 2.0, Matrix4x4::identity());
+
+    let hit_point = |x: u64, y: u64| {
+      let ray = camera.ray_for_pixel(x, y);
+      let intersections = plane.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+      ray.position(intersections[0].t)
+    };
+
+    let ray = camera.ray_for_pixel(50, 60);
+    let intersections = plane.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    let differentials = computations.differentials.unwrap();
+
+    // Offsets lie on plane
+    assert!(differentials.point_dx.y.abs() < 0.0001);
+    assert!(differentials.point_dy.y.abs() < 0.0001);
+
+    // Step across a row is exact on a plane
+    let step_x = hit_point(51, 60).subtract_point(&computations.point);
+
+    assert!(differentials.point_dx.subtract_vector(&step_x).magnitude() < 0.0001);
+
+    // Step toward horizon lies between steps to rows above and below
+    let step_down = hit_point(50, 61).subtract_point(&computations.point);
+    let step_up = computations.point.subtract_point(&hit_point(50, 59));
+
+    assert!(differentials.point_dy.z > step_down.z.min(step_up.z));
+    assert!(differentials.point_dy.z < step_down.z.max(step_up.z));
+
+    // Planar texture coordinates follow x and z
+    assert!((differentials.texture_dx.0 - differentials.point_dx.x).abs() < 0.0001);
+    assert!((differentials.texture_dy.1 - differentials.point_dy.z).abs() < 0.0001);
+    assert!(differentials.normal_dx.magnitude() < 0.0001);
+  }
+
+  #[test]
+  fn computations_of_rays_without_differentials_have_no_footprint() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = sphere.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.ray_differentials.is_none());
+    assert!(computations.differentials.is_none());
+  }
+
+  #[test]
+  fn footprint_on_sphere_includes_change_in_normal() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let differentials = RayDifferentials::new(Vector::new(0.01, 0.0, 0.0), Vector::new(0.0, 0.01, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0));
+    let ray = Ray::new_with_differentials(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0), differentials);
+    let intersections = sphere.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    let differentials = computations.differentials.unwrap();
+
+    // Normal of unit sphere turns as fast as point moves
+    assert!((differentials.point_dx.x - 0.01).abs() < 0.0001);
+    assert!((differentials.normal_dx.x - 0.01).abs() < 0.0001);
+    assert!((differentials.normal_dy.y - 0.01).abs() < 0.0001);
+  }
+
+  fn flat_normal_map(color: Color) -> Rc<Canvas> {
+    let mut normal_map = Canvas::new(1, 1);
+    normal_map.color_pixel(0, 0, color);
+
+    Rc::new(normal_map)
+  }
+
+  #[test]
+  fn normal_map_tilts_shading_normal_towards_tangent() {
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    material.normal_map = Some(flat_normal_map(Color::new(0.8, 0.5, 0.9, 1.0)));
+    let plane = Plane::new(Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(0.0, 1.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+
+    let intersections = vec![Intersection::new(1.0, &plane, Matrix4x4::identity(), Matrix4x4::identity())];
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!((computations.normal.x - 0.6).abs() < 0.0001);
+    assert!((computations.normal.y - 0.8).abs() < 0.0001);
+    assert!(computations.normal.z.abs() < 0.0001);
+
+    // Offset points still follow real surface
+    assert!(computations.over_point == Point::new(0.0, 0.001, 0.0));
+  }
+
+  #[test]
+  fn flat_normal_map_leaves_normal_of_transformed_sphere_unchanged() {
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    material.normal_map = Some(flat_normal_map(Color::new(0.5, 0.5, 1.0, 1.0)));
+    let sphere = Sphere::new(Matrix4x4::scale(1.0, 2.0, 1.0).mult4x4(&Matrix4x4::rotate_y(f64::consts::PI / 4.0)), material);
+
+    let ray = Ray::new(&Point::new(0.0, 1.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = sphere.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+    let unmapped_normal = sphere.normal_at(&computations.point);
+
+    assert!(computations.normal.subtract_vector(&unmapped_normal).magnitude() < 0.0001);
+  }
+
+  #[test]
+  fn bump_function_tilts_shading_normal_away_from_rising_height() {
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    material.bump_func = Some(|_material, _object, position| position.x);
+    material.bump_scale = 0.5;
+    let plane = Plane::new(Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(0.0, 1.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+
+    let intersections = vec![Intersection::new(1.0, &plane, Matrix4x4::identity(), Matrix4x4::identity())];
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.normal.subtract_vector(&Vector::new(-0.5, 1.0, 0.0).normalize()).magnitude() < 0.0001);
+  }
+
+  #[test]
+  fn normal_map_on_sphere_follows_direction_of_texture_coordinates() {
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    material.normal_map = Some(flat_normal_map(Color::new(0.5, 0.8, 0.9, 1.0)));
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = sphere.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    // Texture v increases towards top of sphere
+    assert!(computations.normal.subtract_vector(&Vector::new(0.0, 0.6, -0.8)).magnitude() < 0.0001);
+  }
 }
@@ -1,8 +1,4 @@
-extern crate chrono;
-use chrono::Utc;
-
-extern crate rand;
-use rand::Rng;
+use std::rc::Rc;
 
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
@@ -11,11 +7,19 @@ use crate::rendering::math::Matrix4x4;
 
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
 
 use crate::rendering::Material;
 
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
+use crate::rendering::computations::world_normal;
+
+// Probe rays start this far before a point, so surfaces on either side of it are found
+const PROBE_DISTANCE: f64 = 0.01;
+
+// Point lies on a surface when a probe ray crosses it within this distance
+const PROBE_EPSILON: f64 = 0.0001;
 
 #[derive(PartialEq)]
 pub enum ConstructiveOperation {
@@ -24,71 +28,159 @@ pub enum ConstructiveOperation {
   Difference
 }
 
+#[derive(PartialEq)]
+pub enum ConstructiveMaterialMode {
+  // Each surface keeps material of operand it belongs to
+  Operands,
+  // Material of constructive geometry is used for every surface
+  Constructive,
+  // Material of constructive geometry is used, except on faces cut by subtracted operands of a difference
+  Cutters
+}
+
 pub struct ConstructiveGeometry<'a> {
   pub id: u64,
   pub transform: Matrix4x4,
   pub inverse: Matrix4x4,
   pub transpose: Matrix4x4,
-  pub material: Material,
-  pub left_side: &'a dyn Shape,
-  pub right_side: &'a dyn Shape,
+  pub material: Rc<Material>,
+  pub material_mode: ConstructiveMaterialMode,
+  pub operands: Vec<&'a dyn Shape>,
   pub operation: ConstructiveOperation
 }
 
 impl<'a> ConstructiveGeometry<'a> {
   pub fn new(transform: Matrix4x4, material: Material, left_side: &'a dyn Shape, right_side: &'a dyn Shape, operation: ConstructiveOperation) -> ConstructiveGeometry<'a> {
-    let mut range = rand::thread_rng();
-  
-    let id = (Utc::now().timestamp_subsec_micros() as u64) + range.gen_range(0, 1000000);
-    
+    ConstructiveGeometry::from_operands(transform, material, vec![left_side, right_side], operation)
+  }
+
+  // Union or intersection of all operands, or first operand with all others subtracted from it
+  pub fn from_operands(transform: Matrix4x4, material: Material, operands: Vec<&'a dyn Shape>, operation: ConstructiveOperation) -> ConstructiveGeometry<'a> {
     let tmp_inverse = transform.inverse();
 
     ConstructiveGeometry { 
-      id: id, 
+      id: generate_shape_id(),
       transform: transform, 
       inverse: tmp_inverse,
       transpose: tmp_inverse.transpose(),
-      material: material,
-      left_side: left_side,
-      right_side: right_side,
+      material: Rc::new(material),
+      material_mode: ConstructiveMaterialMode::Operands,
+      operands,
       operation: operation
     }
   }
 
+  pub fn set_material_mode(&mut self, material_mode: ConstructiveMaterialMode) {
+    self.material_mode = material_mode;
+  }
+
+  pub fn overrides_material(&self, operand: usize) -> bool {
+    match self.material_mode {
+      ConstructiveMaterialMode::Operands => false,
+      ConstructiveMaterialMode::Constructive => true,
+      ConstructiveMaterialMode::Cutters => !(self.operation == ConstructiveOperation::Difference && operand > 0)
+    }
+  }
+
   pub fn intersection_allowed(&self, l_hit: bool, in_l: bool, in_r: bool) -> bool {
+    let hit_operand = if l_hit { 0 } else { 1 };
+
+    self.operand_intersection_allowed(hit_operand, &[in_l, in_r])
+  }
+
+  // Hit is on surface of result when remaining operands leave that operand's surface exposed
+  pub fn operand_intersection_allowed(&self, hit_operand: usize, inside: &[bool]) -> bool {
+    let mut others_inside = inside.iter().enumerate().filter(|(i, _)| *i != hit_operand).map(|(_, is_inside)| *is_inside);
+
     match self.operation {
-      ConstructiveOperation::Union => {
-        return (l_hit && !in_r) || (!l_hit && !in_l);
-      },
-      ConstructiveOperation::Intersection => {
-        return (l_hit && in_r) || (!l_hit && in_l);
-      },
+      ConstructiveOperation::Union => !others_inside.any(|is_inside| is_inside),
+      ConstructiveOperation::Intersection => others_inside.all(|is_inside| is_inside),
       ConstructiveOperation::Difference => {
-        return (l_hit && !in_r) || (!l_hit && in_l);
+        if hit_operand == 0 {
+          return !others_inside.any(|is_inside| is_inside);
+        }
+
+        let in_first = inside[0];
+        let in_other_cutter = others_inside.skip(1).any(|is_inside| is_inside);
+
+        in_first && !in_other_cutter
+      }
+    }
+  }
+
+  // Hit of surface of result at a point in space of its container, found by probe rays through point along its own axes
+  pub fn surface_at(&self, point: &Point) -> Option<Intersection<'_>> {
+    // Probes run in space of constructive geometry, so their distances scale with its transform
+    let object_point = self.inverse.mult_point(point);
+
+    for direction in &[Vector::x_axis(), Vector::y_axis(), Vector::z_axis()] {
+      let ray = Ray::new(&object_point.subtract_vector(&direction.multiply(PROBE_DISTANCE)), direction);
+
+      // Intersections carry transforms of constructive geometry and nested operands, from its container to surface hit
+      let surface = self.operand_intersections(&ray, self.inverse, self.transpose).into_iter()
+        .filter(|intersection| (intersection.t - PROBE_DISTANCE).abs() < PROBE_EPSILON)
+        .min_by(|intersection_a, intersection_b| (intersection_a.t - PROBE_DISTANCE).abs().total_cmp(&(intersection_b.t - PROBE_DISTANCE).abs()));
+
+      // Further probes are only needed when surface runs along this one
+      if surface.is_some() {
+        return surface;
       }
     }
+
+    None
   }
 
-  pub fn filter_intersections(&self, intersection_list: Vec<Intersection<'a>>) -> Vec<Intersection<'a>> {
+  // Surfaces of result crossed by a ray in space of constructive geometry
+  pub fn operand_intersections(&self, ray: &Ray, world_to_operands: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let mut operand_intersections: Vec<(Intersection, usize)> = Vec::new();
+
+    for (i, operand) in self.operands.iter().enumerate() {
+      // Surfaces of subtracted operands face into them, normals are flipped to face out of result
+      let operand_normal_to_world = match self.operation {
+        ConstructiveOperation::Difference if i > 0 => normal_to_world.mult4x4(&Matrix4x4::scale(-1.0, -1.0, -1.0)),
+        _ => normal_to_world
+      };
+
+      let mut intersections = operand.intersections(ray, world_to_operands, operand_normal_to_world);
+
+      // Materials of nested constructive geometries and containers take precedence
+      if self.overrides_material(i) {
+        for intersection in &mut intersections {
+          if intersection.material.is_none() {
+            intersection.material = Some(Rc::clone(&self.material));
+          }
+        }
+      }
+
+      operand_intersections.extend(intersections.into_iter().map(|intersection| (intersection, i)));
+    }
+
+    operand_intersections.sort_by(|(intersection_a, _), (intersection_b, _)| intersection_a.t.total_cmp(&intersection_b.t) );
+
+    self.filter_intersections(operand_intersections)
+  }
+
+  pub fn operand_including(&self, object: &dyn Shape) -> Option<usize> {
+    self.operands.iter().position(|operand| operand.includes(object))
+  }
+
+  // Operand of each intersection is recorded when it is found, so copies of shared geometry used as several operands are not confused
+  pub fn filter_intersections(&self, operand_intersections: Vec<(Intersection<'a>, usize)>) -> Vec<Intersection<'a>> {
     let mut filtered_intersection = Vec::new();
 
-    let mut l_hit: bool;
-    let mut in_l = false;
-    let mut in_r = false;
+    let mut inside = vec![false; self.operands.len()];
 
     // Consume intersections
-    for intersection in intersection_list {
-      l_hit = intersection.object.is_eq(self.left_side);
+    for (intersection, hit_operand) in operand_intersections {
+      let encloses_volume = intersection.object.encloses_volume();
 
-      if self.intersection_allowed(l_hit, in_l, in_r) {
+      if self.operand_intersection_allowed(hit_operand, &inside) {
         filtered_intersection.push(intersection);
       }
 
-      // Set for next intersection allowed computation
-      if l_hit {
-        in_l = !in_l;
-      } else {
-        in_r = !in_r;
+      // Set for next intersection allowed computation, flat shapes are kept or dropped without changing sides
+      if encloses_volume {
+        inside[hit_operand] = !inside[hit_operand];
       }
     }
 
@@ -117,21 +209,37 @@ impl<'a> Shape for ConstructiveGeometry<'a> {
     &self.material
   }
 
-  fn intersections(&self, ray: &Ray, _world_to_container: Matrix4x4, _normal_to_world: Matrix4x4) -> Vec<Intersection> {
-    let mut left_intersections = self.left_side.intersections(ray, self.inverse, self.transpose);
-    let mut right_intersections = self.right_side.intersections(ray, self.inverse, self.transpose);
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
 
-    let sorted_intersections = Intersection::insert_intersection(&mut left_intersections, &mut right_intersections);
+    // Transform of constructive geometry is chained into matrices used by computations of operand surfaces
+    let world_to_operands = self.inverse.mult4x4(&world_to_container);
+    let normal_to_world = normal_to_world.mult4x4(&self.transpose);
 
-    self.filter_intersections(sorted_intersections)
+    self.operand_intersections(&transformed_ray, world_to_operands, normal_to_world)
   }
 
-  fn normal_at(&self, _point: &Point) -> Vector {
-    Vector::new(0.0, 0.0, 0.0)
+  // Normal of operand surface at point, interpolated when that surface interpolates normals
+  fn normal_at(&self, point: &Point) -> Vector {
+    match self.surface_at(point) {
+      Some(surface) => world_normal(&surface, &surface.world_to_container.mult_point(point)),
+      None => Vector::new(0.0, 0.0, 0.0)
+    }
   }
 
-  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
-    Vector::new(0.0, 0.0, 0.0)
+  fn normal_at_with_uv(&self, point: &Point, _u: f64, _v: f64) -> Vector {
+    // Coordinates of operand surface come from its own hit
+    self.normal_at(point)
+  }
+
+  fn texture_coordinates(&self, point: &Point, u: f64, v: f64) -> (f64, f64) {
+    match self.surface_at(point) {
+      Some(surface) => surface.object.texture_coordinates(&surface.world_to_container.mult_point(point), surface.u, surface.v),
+      None => (u, v)
+    }
   }
 
   fn interpolates_normals(&self) -> bool {
@@ -141,4 +249,8 @@ impl<'a> Shape for ConstructiveGeometry<'a> {
   fn get_base_type(&self) -> ShapeType {
     ShapeType::ConstructiveGeometry
   }
+
+  fn includes(&self, object: &dyn Shape) -> bool {
+    self.is_eq(object) || self.operands.iter().any(|operand| operand.includes(object))
+  }
 }
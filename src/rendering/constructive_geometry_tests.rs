@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+  use crate::rendering::math::tuple::Tuple;
   use crate::rendering::math::Point;
   use crate::rendering::math::Vector;
   
@@ -10,12 +11,21 @@ mod tests {
   use crate::rendering::shapes::shape::Shape;
   use crate::rendering::shapes::Sphere;
   use crate::rendering::shapes::Cube;
+  use crate::rendering::shapes::Disk;
+  use crate::rendering::shapes::Quad;
+  use crate::rendering::shapes::Mesh;
+  use crate::rendering::shapes::MeshIndices;
 
   use crate::rendering::ConstructiveGeometry;
   use crate::rendering::ConstructiveOperation;
+  use crate::rendering::ConstructiveMaterialMode;
+
+  use crate::rendering::Container;
+  use crate::rendering::Instance;
 
   use crate::rendering::Ray;
   use crate::rendering::Intersection;
+  use crate::rendering::Computations;
   
   use crate::rendering::Material;
 
@@ -35,8 +45,8 @@ mod tests {
     assert!(constructive_geometry.material.diffuse == 0.9);
     assert!(constructive_geometry.material.specular == 0.9);
     assert!(constructive_geometry.material.shininess == 200.0);
-    assert_eq!(constructive_geometry.left_side.is_eq(&sphere), true);
-    assert_eq!(constructive_geometry.right_side.is_eq(&cube), true);
+    assert!(constructive_geometry.operands[0].is_eq(&sphere));
+    assert!(constructive_geometry.operands[1].is_eq(&cube));
     assert!(constructive_geometry.operation == ConstructiveOperation::Union);
   }
 
@@ -114,11 +124,11 @@ mod tests {
     let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
     let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Union);
 
-    let intersection_1 = Intersection::new(1.0, constructive_geometry.left_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_2 = Intersection::new(2.0, constructive_geometry.right_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_3 = Intersection::new(3.0, constructive_geometry.left_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_4 = Intersection::new(4.0, constructive_geometry.right_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersections = vec![intersection_1, intersection_2, intersection_3, intersection_4];
+    let intersection_1 = Intersection::new(1.0, constructive_geometry.operands[0], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_2 = Intersection::new(2.0, constructive_geometry.operands[1], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_3 = Intersection::new(3.0, constructive_geometry.operands[0], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_4 = Intersection::new(4.0, constructive_geometry.operands[1], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersections = vec![(intersection_1, 0), (intersection_2, 1), (intersection_3, 0), (intersection_4, 1)];
 
     let filtered_intersections = constructive_geometry.filter_intersections(intersections);
 
@@ -139,11 +149,11 @@ mod tests {
     let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
     let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Intersection);
 
-    let intersection_1 = Intersection::new(1.0, constructive_geometry.left_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_2 = Intersection::new(2.0, constructive_geometry.right_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_3 = Intersection::new(3.0, constructive_geometry.left_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_4 = Intersection::new(4.0, constructive_geometry.right_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersections = vec![intersection_1, intersection_2, intersection_3, intersection_4];
+    let intersection_1 = Intersection::new(1.0, constructive_geometry.operands[0], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_2 = Intersection::new(2.0, constructive_geometry.operands[1], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_3 = Intersection::new(3.0, constructive_geometry.operands[0], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_4 = Intersection::new(4.0, constructive_geometry.operands[1], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersections = vec![(intersection_1, 0), (intersection_2, 1), (intersection_3, 0), (intersection_4, 1)];
 
     let filtered_intersections = constructive_geometry.filter_intersections(intersections);
 
@@ -164,11 +174,11 @@ mod tests {
     let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
     let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
 
-    let intersection_1 = Intersection::new(1.0, constructive_geometry.left_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_2 = Intersection::new(2.0, constructive_geometry.right_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_3 = Intersection::new(3.0, constructive_geometry.left_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersection_4 = Intersection::new(4.0, constructive_geometry.right_side, Matrix4x4::identity(), Matrix4x4::identity());
-    let intersections = vec![intersection_1, intersection_2, intersection_3, intersection_4];
+    let intersection_1 = Intersection::new(1.0, constructive_geometry.operands[0], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_2 = Intersection::new(2.0, constructive_geometry.operands[1], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_3 = Intersection::new(3.0, constructive_geometry.operands[0], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_4 = Intersection::new(4.0, constructive_geometry.operands[1], Matrix4x4::identity(), Matrix4x4::identity());
+    let intersections = vec![(intersection_1, 0), (intersection_2, 1), (intersection_3, 0), (intersection_4, 1)];
 
     let filtered_intersections = constructive_geometry.filter_intersections(intersections);
 
@@ -196,9 +206,9 @@ mod tests {
     assert_eq!(intersections.len(), 2);
 
     assert_eq!(intersections[0].t, 4.0);
-    assert!(intersections[0].object.is_eq(constructive_geometry.left_side));
+    assert!(intersections[0].object.is_eq(constructive_geometry.operands[0]));
     assert_eq!(intersections[1].t, 6.5);
-    assert!(intersections[1].object.is_eq(constructive_geometry.right_side));
+    assert!(intersections[1].object.is_eq(constructive_geometry.operands[1]));
   }
 
   #[test]
@@ -218,4 +228,600 @@ mod tests {
 
     assert_eq!(intersections.len(), 0);
   }
+
+  #[test]
+  fn sphere_cuts_hole_in_disk() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 2.0, material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &disk, &sphere, ConstructiveOperation::Difference);
+
+    let through_hole = Ray::new(&Point::new(0.0, 5.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+    let through_disk = Ray::new(&Point::new(1.5, 5.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+
+    let hole_intersections = constructive_geometry.intersections(&through_hole, Matrix4x4::identity(), Matrix4x4::identity());
+    let disk_intersections = constructive_geometry.intersections(&through_disk, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(hole_intersections.len(), 0);
+    assert_eq!(disk_intersections.len(), 1);
+    assert!(disk_intersections[0].object.is_eq(&disk));
+  }
+
+  #[test]
+  fn disk_crossing_does_not_change_inside_of_volume() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 2.0, material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &disk, ConstructiveOperation::Intersection);
+
+    let ray = Ray::new(&Point::new(0.0, 5.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    // Only part of disk inside sphere remains
+    assert_eq!(intersections.len(), 1);
+    assert_eq!(intersections[0].t, 5.0);
+    assert!(intersections[0].object.is_eq(&disk));
+  }
+
+  #[test]
+  fn transformed_difference_reports_normals_of_operand_surfaces() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    // Hemisphere doubled in size, with flat face toward origin
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let transform = Matrix4x4::translate(0.0, 0.0, 10.0).mult4x4(&Matrix4x4::scale(2.0, 2.0, 2.0));
+    let constructive_geometry = ConstructiveGeometry::new(transform, material, &sphere, &cube, ConstructiveOperation::Difference);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 10.0).abs() < 0.0001);
+    assert!(intersections[0].object.is_eq(&cube));
+    assert!((intersections[1].t - 12.0).abs() < 0.0001);
+    assert!(intersections[1].object.is_eq(&sphere));
+
+    // Face cut by cube points out of hemisphere
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.point == Point::new(0.0, 0.0, 10.0));
+    assert!(computations.normal == Vector::new(0.0, 0.0, -1.0));
+    assert!(!computations.inside);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, 20.0), &Vector::new(0.0, 0.0, -1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!((computations.t - 8.0).abs() < 0.0001);
+    assert!(computations.point == Point::new(0.0, 0.0, 12.0));
+    assert!(computations.normal == Vector::new(0.0, 0.0, 1.0));
+    assert!(!computations.inside);
+  }
+
+  #[test]
+  fn intersection_with_nested_difference_keeps_operand_sides() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let hemisphere = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let slab = Cube::new(Matrix4x4::scale(2.0, 2.0, 0.5), material);
+
+    // Slice of hemisphere between its flat face and half its height
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::translate(0.0, 3.0, 0.0), material, &hemisphere, &slab, ConstructiveOperation::Intersection);
+
+    let ray = Ray::new(&Point::new(0.0, 3.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 5.0).abs() < 0.0001);
+    assert!(intersections[0].object.is_eq(&cube));
+    assert!((intersections[1].t - 5.5).abs() < 0.0001);
+    assert!(intersections[1].object.is_eq(&slab));
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.point == Point::new(0.0, 3.0, 0.0));
+    assert!(computations.normal == Vector::new(0.0, 0.0, -1.0));
+    assert!(!computations.inside);
+
+    let computations = Computations::new(&intersections[1], &ray, &intersections);
+
+    assert!(computations.point == Point::new(0.0, 3.0, 0.5));
+    assert!(computations.normal == Vector::new(0.0, 0.0, -1.0));
+    assert!(computations.inside);
+  }
+
+  #[test]
+  fn transformed_intersection_reports_texture_coordinates_and_material_of_operand() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::rectangle(4.0, 4.0, Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::translate(0.0, 0.0, 3.0), material, &quad, &sphere, ConstructiveOperation::Intersection);
+
+    let ray = Ray::new(&Point::new(0.5, 5.0, 3.0), &Vector::new(0.0, -1.0, 0.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.point == Point::new(0.5, 0.0, 3.0));
+    assert!(computations.normal == Vector::new(0.0, 1.0, 0.0));
+    assert!((computations.texture_u - 0.625).abs() < 0.0001);
+    assert!((computations.texture_v - 0.5).abs() < 0.0001);
+    assert!(computations.get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn constructive_geometry_includes_shapes_of_nested_operands() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let grouped_sphere = Sphere::new(Matrix4x4::identity(), material);
+    let container = Container::new(Matrix4x4::identity(), vec![&grouped_sphere]);
+    let group = Instance::of_container(&container, Matrix4x4::identity());
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = Mesh::from_triangles(vec![Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)], vec![[0, 1, 2]], Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let inner = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &group, ConstructiveOperation::Union);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &inner, &mesh, ConstructiveOperation::Difference);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let outside = Sphere::new(Matrix4x4::identity(), material);
+
+    // Faces of mesh are reported by intersections rather than mesh itself
+    let ray = Ray::new(&Point::new(0.0, 0.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let mesh_intersections = mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert!(constructive_geometry.includes(&inner));
+    assert!(constructive_geometry.includes(&sphere));
+    assert!(constructive_geometry.includes(&grouped_sphere));
+    assert!(constructive_geometry.includes(mesh_intersections[0].object));
+    assert!(!constructive_geometry.includes(&cube));
+    assert!(!constructive_geometry.includes(&outside));
+
+    assert_eq!(constructive_geometry.operand_including(&grouped_sphere), Some(0));
+    assert_eq!(constructive_geometry.operand_including(mesh_intersections[0].object), Some(1));
+    assert_eq!(constructive_geometry.operand_including(&cube), None);
+  }
+
+  #[test]
+  fn filtering_intersections_of_nested_operand() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let inner = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Union);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cutter = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &inner, &cutter, ConstructiveOperation::Difference);
+
+    // Hits of nested operand report its leaf shapes
+    let intersection_1 = Intersection::new(1.0, &sphere, Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_2 = Intersection::new(2.0, &cutter, Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_3 = Intersection::new(3.0, &cube, Matrix4x4::identity(), Matrix4x4::identity());
+    let intersection_4 = Intersection::new(4.0, &cutter, Matrix4x4::identity(), Matrix4x4::identity());
+    let intersections = vec![(intersection_1, 0), (intersection_2, 1), (intersection_3, 0), (intersection_4, 1)];
+
+    let filtered_intersections = constructive_geometry.filter_intersections(intersections);
+
+    assert_eq!(filtered_intersections.len(), 2);
+
+    assert_eq!(filtered_intersections[0].t, 1.0);
+    assert_eq!(filtered_intersections[1].t, 2.0);
+  }
+
+  #[test]
+  fn checking_if_intersection_allowed_for_many_operands() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 1.0, material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let union = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&sphere, &cube, &disk], ConstructiveOperation::Union);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let intersection = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&sphere, &cube, &disk], ConstructiveOperation::Intersection);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let difference = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&sphere, &cube, &disk], ConstructiveOperation::Difference);
+
+    assert!(union.operand_intersection_allowed(1, &[false, true, false]));
+    assert!(!union.operand_intersection_allowed(1, &[false, false, true]));
+    assert!(intersection.operand_intersection_allowed(2, &[true, true, false]));
+    assert!(!intersection.operand_intersection_allowed(2, &[true, false, false]));
+    assert!(difference.operand_intersection_allowed(0, &[false, false, false]));
+    assert!(!difference.operand_intersection_allowed(0, &[false, false, true]));
+    assert!(difference.operand_intersection_allowed(1, &[true, false, false]));
+    assert!(!difference.operand_intersection_allowed(1, &[true, false, true]));
+    assert!(!difference.operand_intersection_allowed(2, &[false, false, false]));
+  }
+
+  #[test]
+  fn ray_intersects_union_of_many_operands() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_1 = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_2 = Sphere::new(Matrix4x4::translate(1.5, 0.0, 0.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_3 = Sphere::new(Matrix4x4::translate(3.0, 0.0, 0.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&sphere_1, &sphere_2, &sphere_3], ConstructiveOperation::Union);
+
+    let ray = Ray::new(&Point::new(-5.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 4.0).abs() < 0.0001);
+    assert!(intersections[0].object.is_eq(&sphere_1));
+    assert!((intersections[1].t - 9.0).abs() < 0.0001);
+    assert!(intersections[1].object.is_eq(&sphere_3));
+  }
+
+  #[test]
+  fn ray_intersects_intersection_of_many_operands() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_1 = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_2 = Sphere::new(Matrix4x4::translate(0.5, 0.0, 0.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_3 = Sphere::new(Matrix4x4::translate(1.0, 0.0, 0.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&sphere_1, &sphere_2, &sphere_3], ConstructiveOperation::Intersection);
+
+    let ray = Ray::new(&Point::new(-5.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 5.0).abs() < 0.0001);
+    assert!(intersections[0].object.is_eq(&sphere_3));
+    assert!((intersections[1].t - 6.0).abs() < 0.0001);
+    assert!(intersections[1].object.is_eq(&sphere_1));
+  }
+
+  #[test]
+  fn ray_intersects_difference_of_many_operands_and_group() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cutter_1 = Sphere::new(Matrix4x4::translate(-1.0, 0.0, 0.0).mult4x4(&Matrix4x4::scale(0.5, 0.5, 0.5)), material);
+
+    // Second cutter is grouped, and moved into place by container
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cutter_2 = Sphere::new(Matrix4x4::scale(0.5, 0.5, 0.5), material);
+    let container = Container::new(Matrix4x4::translate(1.0, 0.0, 0.0), vec![&cutter_2]);
+    let group = Instance::of_container(&container, Matrix4x4::identity());
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&sphere, &cutter_1, &group], ConstructiveOperation::Difference);
+
+    let ray = Ray::new(&Point::new(-5.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 4.5).abs() < 0.0001);
+    assert!(intersections[0].object.is_eq(&cutter_1));
+    assert!((intersections[1].t - 5.5).abs() < 0.0001);
+    assert!(intersections[1].object.is_eq(&cutter_2));
+
+    // Cut faces of grouped cutter face out of result
+    let computations = Computations::new(&intersections[1], &ray, &intersections);
+
+    assert!(computations.point == Point::new(0.5, 0.0, 0.0));
+    assert!(computations.normal == Vector::new(-1.0, 0.0, 0.0));
+    assert!(computations.inside);
+  }
+
+  #[test]
+  fn constructive_geometry_keeps_materials_of_operands_by_default() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+
+    assert!(constructive_geometry.material_mode == ConstructiveMaterialMode::Operands);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!(intersections[0].material.is_none());
+    assert!(intersections[0].get_material().color_1 == Color::new(0.0, 1.0, 0.0, 1.0));
+    assert!(intersections[1].material.is_none());
+    assert!(intersections[1].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn constructive_geometry_material_overrides_materials_of_operands() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+    constructive_geometry.set_material_mode(ConstructiveMaterialMode::Constructive);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!(intersections[0].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(intersections[1].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn cut_faces_of_difference_keep_material_of_cutter() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+    constructive_geometry.set_material_mode(ConstructiveMaterialMode::Cutters);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    // Face cut by cube, then surface of sphere
+    assert_eq!(intersections.len(), 2);
+    assert!(intersections[0].get_material().color_1 == Color::new(0.0, 1.0, 0.0, 1.0));
+    assert!(intersections[1].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+
+    // Without a cutter every surface uses material of constructive geometry
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Union);
+    constructive_geometry.set_material_mode(ConstructiveMaterialMode::Cutters);
+
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!(intersections[0].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(intersections[1].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn material_of_nested_constructive_geometry_takes_precedence() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let mut inner = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+    inner.set_material_mode(ConstructiveMaterialMode::Cutters);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let other = Sphere::new(Matrix4x4::translate(0.0, 5.0, 0.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut constructive_geometry = ConstructiveGeometry::new(Matrix4x4::identity(), material, &inner, &other, ConstructiveOperation::Union);
+    constructive_geometry.set_material_mode(ConstructiveMaterialMode::Constructive);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    // Cut face was left to operands by nested geometry, so outer material applies there
+    assert_eq!(intersections.len(), 2);
+    assert!(intersections[0].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(intersections[1].get_material().color_1 == Color::new(1.0, 1.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn transformed_nested_difference_reports_normals_of_operand_surfaces_at_points() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let hemisphere = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cutter = Sphere::new(Matrix4x4::translate(0.0, 0.0, 1.0).mult4x4(&Matrix4x4::scale(0.5, 0.5, 0.5)), material);
+
+    // Hemisphere doubled in size, with flat face toward origin and a dent cut in its top
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let transform = Matrix4x4::translate(0.0, 0.0, 10.0).mult4x4(&Matrix4x4::scale(2.0, 2.0, 2.0));
+    let constructive_geometry = ConstructiveGeometry::new(transform, material, &hemisphere, &cutter, ConstructiveOperation::Difference);
+
+    // Flat face cut by cube of nested difference
+    assert!(constructive_geometry.normal_at(&Point::new(0.0, 0.0, 10.0)) == Vector::new(0.0, 0.0, -1.0));
+    assert!(constructive_geometry.surface_at(&Point::new(0.0, 0.0, 10.0)).unwrap().object.is_eq(&cube));
+
+    // Dent cut by sphere faces out of result
+    assert!(constructive_geometry.normal_at(&Point::new(0.0, 0.0, 11.0)) == Vector::new(0.0, 0.0, 1.0));
+    assert!(constructive_geometry.surface_at(&Point::new(0.0, 0.0, 11.0)).unwrap().object.is_eq(&cutter));
+
+    assert!(constructive_geometry.normal_at(&Point::new(1.2, 0.0, 11.6)).subtract_vector(&Vector::new(0.6, 0.0, 0.8)).magnitude() < 0.0001);
+    assert!(constructive_geometry.normal_at_with_uv(&Point::new(1.2, 0.0, 11.6), 0.0, 0.0).subtract_vector(&Vector::new(0.6, 0.0, 0.8)).magnitude() < 0.0001);
+
+    // Removed top of hemisphere is no longer a surface
+    assert!(constructive_geometry.surface_at(&Point::new(0.0, 0.0, 12.0)).is_none());
+    assert!(constructive_geometry.normal_at(&Point::new(0.0, 0.0, 12.0)) == Vector::new(0.0, 0.0, 0.0));
+  }
+
+  #[test]
+  fn scaled_constructive_geometry_reports_surfaces_at_points() {
+    for scale in [0.00001, 1000.0].iter() {
+      let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+      let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+      let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+      let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+      // Hemisphere far smaller or larger than distances probes travel
+      let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+      let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::scale(*scale, *scale, *scale), material, &sphere, &cube, ConstructiveOperation::Difference);
+
+      assert!(constructive_geometry.normal_at(&Point::new(0.0, 0.0, 0.0)).subtract_vector(&Vector::new(0.0, 0.0, -1.0)).magnitude() < 0.0001);
+      assert!(constructive_geometry.surface_at(&Point::new(0.0, 0.0, 0.0)).unwrap().object.is_eq(&cube));
+
+      assert!(constructive_geometry.normal_at(&Point::new(0.6 * scale, 0.0, 0.8 * scale)).subtract_vector(&Vector::new(0.6, 0.0, 0.8)).magnitude() < 0.0001);
+      assert!(constructive_geometry.surface_at(&Point::new(0.0, 0.0, 0.5 * scale)).is_none());
+    }
+  }
+
+  #[test]
+  fn transformed_intersection_with_nested_difference_reports_surfaces_at_points() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(Matrix4x4::translate(0.0, 0.0, -1.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let hemisphere = ConstructiveGeometry::new(Matrix4x4::identity(), material, &sphere, &cube, ConstructiveOperation::Difference);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity());
+    let slab = Cube::new(Matrix4x4::scale(2.0, 2.0, 0.5), material);
+
+    // Slice of hemisphere between its flat face and half its height
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::translate(0.0, 3.0, 0.0), material, &hemisphere, &slab, ConstructiveOperation::Intersection);
+
+    assert!(constructive_geometry.normal_at(&Point::new(0.0, 3.0, 0.0)) == Vector::new(0.0, 0.0, -1.0));
+    assert!(constructive_geometry.normal_at(&Point::new(0.0, 3.0, 0.5)) == Vector::new(0.0, 0.0, 1.0));
+    assert!(constructive_geometry.normal_at(&Point::new(0.96, 3.0, 0.28)).subtract_vector(&Vector::new(0.96, 0.0, 0.28)).magnitude() < 0.0001);
+
+    let cut_face = constructive_geometry.surface_at(&Point::new(0.0, 3.0, 0.0)).unwrap();
+    let slab_face = constructive_geometry.surface_at(&Point::new(0.0, 3.0, 0.5)).unwrap();
+    let sphere_side = constructive_geometry.surface_at(&Point::new(0.96, 3.0, 0.28)).unwrap();
+
+    assert!(cut_face.get_material().color_1 == Color::new(0.0, 1.0, 0.0, 1.0));
+    assert!(slab_face.get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(sphere_side.get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+
+    // Texture coordinates are spherical mapping of sphere operand
+    let (u, v) = constructive_geometry.texture_coordinates(&Point::new(0.96, 3.0, 0.28), 0.0, 0.0);
+    let (sphere_u, sphere_v) = sphere.texture_coordinates(&Point::new(0.96, 0.0, 0.28), 0.0, 0.0);
+    assert!((u - sphere_u).abs() < 0.0001);
+    assert!((v - sphere_v).abs() < 0.0001);
+  }
+
+  #[test]
+  fn constructive_geometry_reports_interpolated_normals_of_smooth_mesh_operand() {
+    let vertices = vec![Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)];
+    let normals = vec![Vector::new(0.0, 1.0, 0.0), Vector::new(-1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)];
+    let texture_vertices = vec![(0.5, 1.0), (0.0, 0.0), (1.0, 0.0)];
+
+    let mut triangle = MeshIndices::new([0, 1, 2]);
+    triangle.normals = Some([0, 1, 2]);
+    triangle.texture_vertices = Some([0, 1, 2]);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = Mesh::new(vertices, normals, texture_vertices, vec![triangle], Matrix4x4::identity(), vec![material]);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::scale(3.0, 3.0, 3.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::new(Matrix4x4::translate(0.0, 0.0, 4.0), material, &mesh, &sphere, ConstructiveOperation::Intersection);
+
+    // Point at barycentric coordinates 0.45 and 0.25 of face
+    let normal = constructive_geometry.normal_at(&Point::new(-0.2, 0.3, 4.0));
+    assert!((normal.x - -0.5547).abs() < 0.0001);
+    assert!((normal.y - 0.83205).abs() < 0.0001);
+    assert!((normal.z - 0.0).abs() < 0.0001);
+
+    let (u, v) = constructive_geometry.texture_coordinates(&Point::new(-0.2, 0.3, 4.0), 0.0, 0.0);
+    assert!((u - 0.4).abs() < 0.0001);
+    assert!((v - 0.3).abs() < 0.0001);
+  }
+
+  #[test]
+  fn instances_of_one_sphere_are_separate_operands() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let instance_a = Instance::new(&sphere, Matrix4x4::identity());
+    let instance_b = Instance::new(&sphere, Matrix4x4::translate(0.0, 0.0, 1.0));
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let constructive_geometry = ConstructiveGeometry::from_operands(Matrix4x4::identity(), material, vec![&instance_a, &instance_b], ConstructiveOperation::Difference);
+
+    // Both operands include sphere, so only operand that found each hit tells them apart
+    assert_eq!(constructive_geometry.operand_including(&sphere), Some(0));
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = constructive_geometry.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    // Front of first copy, then face cut by second copy
+    assert_eq!(intersections.len(), 2);
+    assert_eq!(intersections[0].t, 4.0);
+    assert_eq!(intersections[1].t, 5.0);
+
+    let computations = Computations::new(&intersections[1], &ray, &intersections);
+
+    // Ray leaves result through cut face, which faces away from it
+    assert!(computations.point == Point::new(0.0, 0.0, 0.0));
+    assert!(computations.normal == Vector::new(0.0, 0.0, -1.0));
+    assert!(computations.inside);
+
+    assert!(constructive_geometry.normal_at(&Point::new(0.0, 0.0, 0.0)) == Vector::new(0.0, 0.0, 1.0));
+  }
 }
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use crate::rendering::math::Matrix4x4;
 
 use crate::rendering::shapes::shape::Shape;
@@ -5,39 +7,198 @@ use crate::rendering::shapes::shape::Shape;
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Material;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::GeometryLight;
+
 pub struct Container<'a> {
+  pub name: String,
   pub transform: Matrix4x4,
   pub inverse: Matrix4x4,
   pub transpose: Matrix4x4,
-  pub shapes: Vec<&'a dyn Shape>
+  pub material: Option<Rc<Material>>,
+  pub shapes: Vec<&'a dyn Shape>,
+  pub children: Vec<Container<'a>>
 }
 
 impl<'a> Container<'a> {
   pub fn new(transform: Matrix4x4, shapes: Vec<&'a dyn Shape> ) -> Container {
+    Container::named("", transform, shapes)
+  }
+
+  pub fn named(name: &str, transform: Matrix4x4, shapes: Vec<&'a dyn Shape>) -> Container<'a> {
     let tmp_inverse = transform.inverse();
     
     Container { 
+      name: name.to_string(),
       transform: transform,
       inverse: tmp_inverse,
       transpose: tmp_inverse.transpose(),
-      shapes: shapes
+      material: None,
+      shapes,
+      children: Vec::new()
     }
   }
 
+  pub fn set_transform(&mut self, transform: Matrix4x4) {
+    self.transform = transform;
+    self.inverse = transform.inverse();
+    self.transpose = self.inverse.transpose();
+  }
+
+  // Overrides materials of every shape in container and its children
+  pub fn set_material(&mut self, material: Material) {
+    self.material = Some(Rc::new(material));
+  }
+
+  pub fn add_child(&mut self, child: Container<'a>) {
+    self.children.push(child);
+  }
+
+  pub fn child(&self, name: &str) -> Option<&Container<'a>> {
+    self.children.iter().find(|child| child.name == name)
+  }
+
+  pub fn child_mut(&mut self, name: &str) -> Option<&mut Container<'a>> {
+    self.children.iter_mut().find(|child| child.name == name)
+  }
+
+  // Material representing container, used when container is instanced without a material
+  pub fn first_material(&self) -> Option<&Material> {
+    if let Some(material) = &self.material {
+      return Some(material);
+    }
+
+    if let Some(shape) = self.shapes.first() {
+      return Some(shape.get_material());
+    }
+
+    self.children.iter().find_map(|child| child.first_material())
+  }
+
+  // Whether any shape of container or its children emits light, under materials of container overriding its own
+  pub fn emits_light(&self) -> bool {
+    if let Some(material) = &self.material {
+      return material.emits_light();
+    }
+
+    self.shapes.iter().any(|shape| shape.emits_light()) || self.children.iter().any(|child| child.emits_light())
+  }
+
+  // Whether object is one of the shapes of container or its children
+  pub fn includes(&self, object: &dyn Shape) -> bool {
+    self.shapes.iter().any(|shape| shape.includes(object)) || self.children.iter().any(|child| child.includes(object))
+  }
+
+  // Whether a hit on object is on a shape of container or its children, parent inverse takes world space to space of parent container
+  pub fn includes_hit(&self, object: &dyn Shape, hit_world_to_container: &Matrix4x4, parent_inverse: &Matrix4x4) -> bool {
+    let world_to_container = self.inverse.mult4x4(parent_inverse);
+
+    self.shapes.iter().any(|shape| shape.includes_hit(object, hit_world_to_container, &world_to_container)) ||
+      self.children.iter().any(|child| child.includes_hit(object, hit_world_to_container, &world_to_container))
+  }
+
   pub fn intersect<'b>(&self, ray: &'b Ray) -> Vec<Intersection<'a>> {
+    self.intersect_in_parent(ray, Matrix4x4::identity(), Matrix4x4::identity())
+  }
+
+  // Ray is in space of parent container, parent matrices convert between that space and world space
+  pub fn intersect_in_parent(&self, ray: &Ray, parent_inverse: Matrix4x4, parent_transpose: Matrix4x4) -> Vec<Intersection<'a>> {
     let transformed_point = self.inverse.mult_point(&ray.origin);
     let transformed_vector = self.inverse.mult_vector(&ray.direction);
 
     let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let world_to_container = self.inverse.mult4x4(&parent_inverse);
+    let normal_to_world = parent_transpose.mult4x4(&self.transpose);
     
     let mut intersections = Vec::new();
 
     for shape in &self.shapes {
-      let mut new_intersections = shape.intersections(&transformed_ray, self.inverse, self.transpose);
+      let mut new_intersections = shape.intersections(&transformed_ray, world_to_container, normal_to_world);
+
+      intersections = Intersection::insert_intersection(&mut intersections, &mut new_intersections);
+    }
+
+    for child in &self.children {
+      let mut new_intersections = child.intersect_in_parent(&transformed_ray, world_to_container, normal_to_world);
 
       intersections = Intersection::insert_intersection(&mut intersections, &mut new_intersections);
     }
 
+    // Materials of nested containers take precedence
+    if let Some(material) = &self.material {
+      for intersection in &mut intersections {
+        if intersection.material.is_none() {
+          intersection.material = Some(Rc::clone(material));
+        }
+      }
+    }
+
     intersections 
   }
+
+  pub fn geometry_lights(&self) -> Vec<GeometryLight<'a>> {
+    self.geometry_lights_in_parent(&Matrix4x4::identity(), &Matrix4x4::identity(), None)
+  }
+
+  // Shapes of container and its children that emit light, parent transform and inverse convert between space of parent container and world space
+  pub fn geometry_lights_in_parent(&self, parent_transform: &Matrix4x4, parent_inverse: &Matrix4x4, parent_material: Option<&Rc<Material>>) -> Vec<GeometryLight<'a>> {
+    let container_transform = parent_transform.mult4x4(&self.transform);
+
+    // Chained as in intersections, so lights match hits on their shapes exactly
+    let world_to_container = self.inverse.mult4x4(parent_inverse);
+
+    // Materials of nested containers take precedence
+    let material = self.material.as_ref().or(parent_material);
+
+    let mut lights = Vec::new();
+
+    for shape in &self.shapes {
+      let emits_light = match material {
+        Some(material) => material.emits_light(),
+        None => shape.emits_light()
+      };
+
+      if emits_light {
+        lights.extend(GeometryLight::new(*shape, material.cloned(), &container_transform, &world_to_container));
+      }
+    }
+
+    for child in &self.children {
+      lights.extend(child.geometry_lights_in_parent(&container_transform, &world_to_container, material));
+    }
+
+    lights
+  }
+
+  // Shapes of container and its children as triangles, in space of parent container
+  pub fn tessellate(&self, resolution: usize, extent: f64) -> Vec<Tessellation> {
+    let mut tessellations = Vec::new();
+
+    for shape in &self.shapes {
+      for tessellation in shape.tessellate(resolution, extent) {
+        tessellations.push(tessellation.transform(shape.get_transform()));
+      }
+    }
+
+    for child in &self.children {
+      tessellations.extend(child.tessellate(resolution, extent));
+    }
+
+    // Materials and names of nested containers take precedence
+    for tessellation in &mut tessellations {
+      if let (None, Some(material)) = (&tessellation.override_material, &self.material) {
+        tessellation.override_material = Some(Rc::clone(material));
+      }
+
+      if tessellation.group.is_empty() {
+        tessellation.group = self.name.clone();
+      }
+    }
+
+    tessellations.into_iter().map(|tessellation| tessellation.transform(&self.transform)).collect()
+  }
 }
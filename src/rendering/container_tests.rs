@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+  use std::f64;
+
   use crate::rendering::math::Point;
   use crate::rendering::math::Vector;
   
@@ -14,6 +16,7 @@ mod tests {
   use crate::rendering::Container;
 
   use crate::rendering::Ray;
+  use crate::rendering::Computations;
   
   use crate::rendering::Material;
 
@@ -99,4 +102,154 @@ mod tests {
 
     assert!(intersections.len() == 2);
   }
+
+  #[test]
+  fn container_finds_children_by_name() {
+    let mut container = Container::new(Matrix4x4::identity(), Vec::new());
+    container.add_child(Container::named("wheels", Matrix4x4::identity(), Vec::new()));
+    container.add_child(Container::named("body", Matrix4x4::identity(), Vec::new()));
+
+    assert!(container.child("body").unwrap().name == "body");
+    assert!(container.child("doors").is_none());
+
+    container.child_mut("wheels").unwrap().set_transform(Matrix4x4::translate(0.0, 1.0, 0.0));
+
+    let wheels = container.child("wheels").unwrap();
+    assert!(wheels.transform == Matrix4x4::translate(0.0, 1.0, 0.0));
+    assert!(wheels.inverse == Matrix4x4::translate(0.0, -1.0, 0.0));
+    assert!(wheels.transpose == Matrix4x4::translate(0.0, -1.0, 0.0).transpose());
+  }
+
+  #[test]
+  fn intersecting_shapes_in_transformed_child_container() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::translate(5.0, 0.0, 0.0), material);
+
+    let child = Container::named("child", Matrix4x4::scale(1.0, 2.0, 3.0), vec![sphere as &dyn Shape]);
+
+    let mut parent = Container::new(Matrix4x4::rotate_y(f64::consts::PI / 2.0), Vec::new());
+    parent.add_child(child);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -20.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = parent.intersect(&ray);
+
+    assert!(intersections.len() == 2);
+    assert!(intersections[0].object.is_eq(sphere as &dyn Shape));
+
+    let world_to_container = Matrix4x4::scale(1.0, 2.0, 3.0).inverse().mult4x4(&Matrix4x4::rotate_y(f64::consts::PI / 2.0).inverse());
+    assert!(intersections[0].world_to_container.is_eq(&world_to_container));
+    assert!(intersections[0].normal_to_world.is_eq(&world_to_container.transpose()));
+  }
+
+  #[test]
+  fn converting_normal_from_nested_container_to_world_space() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::translate(5.0, 0.0, 0.0), material);
+
+    let child = Container::named("child", Matrix4x4::scale(1.0, 2.0, 3.0), vec![sphere as &dyn Shape]);
+
+    let mut parent = Container::new(Matrix4x4::rotate_y(f64::consts::PI / 2.0), Vec::new());
+    parent.add_child(child);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -20.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = parent.intersect(&ray);
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!((computations.point.x - 0.0).abs() < 0.0001);
+    assert!((computations.point.y - 0.0).abs() < 0.0001);
+    assert!((computations.point.z - -6.0).abs() < 0.0001);
+    assert!((computations.normal.x - 0.0).abs() < 0.0001);
+    assert!((computations.normal.y - 0.0).abs() < 0.0001);
+    assert!((computations.normal.z - -1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn container_material_overrides_shape_material() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_1 = &Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_2 = &Sphere::new(Matrix4x4::translate(0.0, 0.0, 5.0), material);
+
+    let mut child = Container::named("child", Matrix4x4::identity(), vec![sphere_2 as &dyn Shape]);
+    child.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut parent = Container::new(Matrix4x4::identity(), vec![sphere_1 as &dyn Shape]);
+    parent.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()));
+    parent.add_child(child);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = parent.intersect(&ray);
+
+    assert!(intersections.len() == 4);
+    assert!(intersections[0].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(intersections[2].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(sphere_1.get_material().color_1 == Color::new(1.0, 1.0, 1.0, 1.0));
+
+    let computations = Computations::new(&intersections[2], &ray, &intersections);
+    assert!(computations.get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn tessellating_container_applies_transforms_materials_and_names() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube_1 = Cube::new(Matrix4x4::scale(2.0, 2.0, 2.0), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube_2 = Cube::new(Matrix4x4::identity(), material);
+
+    let mut child = Container::named("child", Matrix4x4::translate(0.0, 0.0, 10.0), vec![&cube_2 as &dyn Shape]);
+    child.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut container = Container::named("parent", Matrix4x4::translate(5.0, 0.0, 0.0), vec![&cube_1 as &dyn Shape]);
+    container.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()));
+    container.add_child(child);
+
+    let tessellations = container.tessellate(16, 100.0);
+
+    assert_eq!(tessellations.len(), 2);
+
+    assert_eq!(tessellations[0].group, "parent");
+    assert!(tessellations[0].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(tessellations[0].vertices.iter().all(|vertex| vertex.x == 3.0 || vertex.x == 7.0));
+
+    assert_eq!(tessellations[1].group, "child");
+    assert!(tessellations[1].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(tessellations[1].vertices.iter().all(|vertex| vertex.z == 9.0 || vertex.z == 11.0));
+    assert!(tessellations[1].vertices.iter().all(|vertex| vertex.x == 4.0 || vertex.x == 6.0));
+  }
+
+  #[test]
+  fn geometry_lights_follow_nested_transforms_and_materials() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::translate(1.0, 0.0, 0.0), material);
+
+    let material = Material::emissive(Color::new(1.0, 1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let glowing_sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let mut child = Container::new(Matrix4x4::scale(2.0, 2.0, 2.0), vec![sphere as &dyn Shape]);
+    child.set_material(Material::emissive(Color::new(3.0, 0.0, 0.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut container = Container::new(Matrix4x4::translate(0.0, 5.0, 0.0), vec![glowing_sphere as &dyn Shape]);
+    container.add_child(child);
+
+    let lights = container.geometry_lights();
+    assert_eq!(lights.len(), 2);
+
+    assert!(lights[0].emission(glowing_sphere) == Color::new(1.0, 1.0, 1.0, 1.0));
+
+    // Sphere of child emits through material of child, and is moved by both containers
+    assert!(lights[1].emission(sphere) == Color::new(3.0, 0.0, 0.0, 1.0));
+    assert!(lights[1].transform.mult_point(&Point::new(0.0, 0.0, 0.0)) == Point::new(2.0, 5.0, 0.0));
+
+    // Material of parent container stops any of its shapes from emitting
+    container.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+    let lights = container.geometry_lights();
+
+    assert_eq!(lights.len(), 1);
+    assert!(lights[0].includes(sphere));
+  }
 }
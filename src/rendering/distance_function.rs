@@ -0,0 +1,149 @@
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+// Tree of signed distances, negative inside surface, built from primitives and combined with operations
+pub enum DistanceFunction {
+  Sphere { radius: f64 },
+  RoundedBox { half_extents: Vector, radius: f64 },
+  Capsule { start: Point, end: Point, radius: f64 },
+  Torus { major_radius: f64, minor_radius: f64 },
+  Union { left: Box<DistanceFunction>, right: Box<DistanceFunction> },
+  Intersection { left: Box<DistanceFunction>, right: Box<DistanceFunction> },
+  Difference { left: Box<DistanceFunction>, right: Box<DistanceFunction> },
+  SmoothUnion { left: Box<DistanceFunction>, right: Box<DistanceFunction>, smoothness: f64 },
+  Translate { function: Box<DistanceFunction>, offset: Vector },
+  Repeat { function: Box<DistanceFunction>, period: Vector },
+  Twist { function: Box<DistanceFunction>, rate: f64 }
+}
+
+impl DistanceFunction {
+  pub fn sphere(radius: f64) -> DistanceFunction {
+    DistanceFunction::Sphere { radius }
+  }
+
+  // Box reaching half extents from origin along each axis, with edges rounded by radius
+  pub fn rounded_box(half_extents: Vector, radius: f64) -> DistanceFunction {
+    DistanceFunction::RoundedBox { half_extents, radius }
+  }
+
+  pub fn capsule(start: Point, end: Point, radius: f64) -> DistanceFunction {
+    DistanceFunction::Capsule { start, end, radius }
+  }
+
+  pub fn torus(major_radius: f64, minor_radius: f64) -> DistanceFunction {
+    DistanceFunction::Torus { major_radius, minor_radius }
+  }
+
+  pub fn union(self, other: DistanceFunction) -> DistanceFunction {
+    DistanceFunction::Union { left: Box::new(self), right: Box::new(other) }
+  }
+
+  pub fn intersection(self, other: DistanceFunction) -> DistanceFunction {
+    DistanceFunction::Intersection { left: Box::new(self), right: Box::new(other) }
+  }
+
+  pub fn difference(self, other: DistanceFunction) -> DistanceFunction {
+    DistanceFunction::Difference { left: Box::new(self), right: Box::new(other) }
+  }
+
+  // Blends surfaces together where they come within smoothness of each other
+  pub fn smooth_union(self, other: DistanceFunction, smoothness: f64) -> DistanceFunction {
+    DistanceFunction::SmoothUnion { left: Box::new(self), right: Box::new(other), smoothness }
+  }
+
+  pub fn translate(self, offset: Vector) -> DistanceFunction {
+    DistanceFunction::Translate { function: Box::new(self), offset }
+  }
+
+  // Copies function every period along each axis, axes with a period of zero are not repeated
+  pub fn repeat(self, period: Vector) -> DistanceFunction {
+    DistanceFunction::Repeat { function: Box::new(self), period }
+  }
+
+  // Rotates function around y axis by rate radians per unit of height
+  pub fn twist(self, rate: f64) -> DistanceFunction {
+    DistanceFunction::Twist { function: Box::new(self), rate }
+  }
+
+  pub fn distance(&self, point: &Point) -> f64 {
+    match self {
+      DistanceFunction::Sphere { radius } => {
+        Vector::new(point.x, point.y, point.z).magnitude() - radius
+      },
+      DistanceFunction::RoundedBox { half_extents, radius } => {
+        let q = Vector::new(
+          point.x.abs() - half_extents.x + radius,
+          point.y.abs() - half_extents.y + radius,
+          point.z.abs() - half_extents.z + radius
+        );
+
+        let outside = Vector::new(q.x.max(0.0), q.y.max(0.0), q.z.max(0.0)).magnitude();
+        let inside = q.x.max(q.y).max(q.z).min(0.0);
+
+        outside + inside - radius
+      },
+      DistanceFunction::Capsule { start, end, radius } => {
+        let start_to_point = point.subtract_point(start);
+        let start_to_end = end.subtract_point(start);
+
+        // Nearest point on segment between ends
+        let along = (start_to_point.dot(&start_to_end) / start_to_end.dot(&start_to_end)).clamp(0.0, 1.0);
+
+        start_to_point.subtract_vector(&start_to_end.multiply(along)).magnitude() - radius
+      },
+      DistanceFunction::Torus { major_radius, minor_radius } => {
+        let ring_distance = (point.x * point.x + point.z * point.z).sqrt() - major_radius;
+
+        (ring_distance * ring_distance + point.y * point.y).sqrt() - minor_radius
+      },
+      DistanceFunction::Union { left, right } => {
+        left.distance(point).min(right.distance(point))
+      },
+      DistanceFunction::Intersection { left, right } => {
+        left.distance(point).max(right.distance(point))
+      },
+      DistanceFunction::Difference { left, right } => {
+        left.distance(point).max(-right.distance(point))
+      },
+      DistanceFunction::SmoothUnion { left, right, smoothness } => {
+        let left_distance = left.distance(point);
+        let right_distance = right.distance(point);
+
+        if *smoothness <= 0.0 {
+          return left_distance.min(right_distance);
+        }
+
+        let blend = (0.5 + 0.5 * (right_distance - left_distance) / smoothness).clamp(0.0, 1.0);
+
+        right_distance + (left_distance - right_distance) * blend - smoothness * blend * (1.0 - blend)
+      },
+      DistanceFunction::Translate { function, offset } => {
+        function.distance(&point.subtract_vector(offset))
+      },
+      DistanceFunction::Repeat { function, period } => {
+        let wrap = |value: f64, period: f64| {
+          if period > 0.0 {
+            value - period * (value / period).round()
+          } else {
+            value
+          }
+        };
+
+        function.distance(&Point::new(wrap(point.x, period.x), wrap(point.y, period.y), wrap(point.z, period.z)))
+      },
+      DistanceFunction::Twist { function, rate } => {
+        let angle = rate * point.y;
+        let (sin, cos) = angle.sin_cos();
+
+        let untwisted = Point::new(cos * point.x - sin * point.z, point.y, sin * point.x + cos * point.z);
+
+        // Twisting stretches space further from axis, so distance is shrunk to keep steps from passing surface
+        let axis_distance = (point.x * point.x + point.z * point.z).sqrt();
+        let stretch = (1.0 + rate * rate * axis_distance * axis_distance).sqrt();
+
+        function.distance(&untwisted) / stretch
+      }
+    }
+  }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::DistanceFunction;
+
+  #[test]
+  fn sphere_distance_is_signed() {
+    let function = DistanceFunction::sphere(2.0);
+
+    assert!((function.distance(&Point::new(5.0, 0.0, 0.0)) - 3.0).abs() < 0.0001);
+    assert!((function.distance(&Point::new(0.0, 0.0, 0.0)) + 2.0).abs() < 0.0001);
+    assert!(function.distance(&Point::new(0.0, 2.0, 0.0)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn rounded_box_distance_to_faces_and_corners() {
+    let function = DistanceFunction::rounded_box(Vector::new(1.0, 2.0, 3.0), 0.0);
+
+    assert!((function.distance(&Point::new(3.0, 0.0, 0.0)) - 2.0).abs() < 0.0001);
+    assert!((function.distance(&Point::new(0.0, 0.0, 0.0)) + 1.0).abs() < 0.0001);
+    assert!((function.distance(&Point::new(2.0, 3.0, 0.0)) - 2.0_f64.sqrt()).abs() < 0.0001);
+
+    // Rounding keeps faces in place and pulls corners in
+    let rounded = DistanceFunction::rounded_box(Vector::new(1.0, 1.0, 1.0), 0.5);
+
+    assert!(rounded.distance(&Point::new(1.0, 0.0, 0.0)).abs() < 0.0001);
+    assert!(rounded.distance(&Point::new(1.0, 1.0, 0.0)) > 0.0);
+  }
+
+  #[test]
+  fn capsule_distance_measured_from_segment() {
+    let function = DistanceFunction::capsule(Point::new(0.0, -1.0, 0.0), Point::new(0.0, 1.0, 0.0), 0.5);
+
+    assert!((function.distance(&Point::new(2.0, 0.5, 0.0)) - 1.5).abs() < 0.0001);
+    assert!((function.distance(&Point::new(0.0, 3.0, 0.0)) - 1.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn torus_distance_measured_from_ring() {
+    let function = DistanceFunction::torus(2.0, 0.5);
+
+    assert!(function.distance(&Point::new(2.5, 0.0, 0.0)).abs() < 0.0001);
+    assert!((function.distance(&Point::new(0.0, 0.0, 0.0)) - 1.5).abs() < 0.0001);
+    assert!((function.distance(&Point::new(0.0, 0.0, -2.0)) + 0.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn boolean_operations_combine_distances() {
+    let left = || DistanceFunction::sphere(1.0);
+    let right = || DistanceFunction::sphere(1.0).translate(Vector::new(1.5, 0.0, 0.0));
+
+    let point = Point::new(-0.5, 0.0, 0.0);
+
+    assert!((left().union(right()).distance(&point) + 0.5).abs() < 0.0001);
+    assert!((left().intersection(right()).distance(&point) - 1.0).abs() < 0.0001);
+    assert!((left().difference(right()).distance(&point) + 0.5).abs() < 0.0001);
+    assert!((left().difference(right()).distance(&Point::new(0.75, 0.0, 0.0)) - 0.25).abs() < 0.0001);
+  }
+
+  #[test]
+  fn smooth_union_fills_gap_between_shapes() {
+    let sharp = DistanceFunction::sphere(1.0).union(DistanceFunction::sphere(1.0).translate(Vector::new(2.5, 0.0, 0.0)));
+    let smooth = DistanceFunction::sphere(1.0).smooth_union(DistanceFunction::sphere(1.0).translate(Vector::new(2.5, 0.0, 0.0)), 1.0);
+
+    let gap = Point::new(1.25, 0.0, 0.0);
+
+    assert!(sharp.distance(&gap) > 0.0);
+    assert!(smooth.distance(&gap) < sharp.distance(&gap));
+
+    // Far from where shapes meet, surface is unchanged
+    assert!(smooth.distance(&Point::new(-1.0, 0.0, 0.0)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn repeat_copies_function_along_axes() {
+    let function = DistanceFunction::sphere(0.5).repeat(Vector::new(4.0, 0.0, 0.0));
+
+    assert!((function.distance(&Point::new(8.0, 0.0, 0.0)) + 0.5).abs() < 0.0001);
+    assert!((function.distance(&Point::new(-4.0, 0.0, 0.0)) + 0.5).abs() < 0.0001);
+    assert!((function.distance(&Point::new(8.0, 2.0, 0.0)) - 1.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn twist_rotates_function_with_height() {
+    let function = DistanceFunction::rounded_box(Vector::new(2.0, 10.0, 0.5), 0.0).twist(std::f64::consts::PI / 2.0);
+
+    // At height of one box is turned a quarter turn, so its long side lies along z
+    assert!(function.distance(&Point::new(0.0, 1.0, 1.5)) < 0.0);
+    assert!(function.distance(&Point::new(1.5, 1.0, 0.0)) > 0.0);
+    assert!(function.distance(&Point::new(1.5, 0.0, 0.0)) < 0.0);
+  }
+}
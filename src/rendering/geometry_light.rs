@@ -0,0 +1,123 @@
+use std::rc::Rc;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+
+use crate::rendering::Material;
+
+// Point on surface of a shape with its normal, pdf is chance of choosing it per unit area
+pub struct SurfaceSample<'a> {
+  pub point: Point,
+  pub normal: Vector,
+  pub pdf: f64,
+  pub object: &'a dyn Shape,
+  // Material of containers between light and object, when they override that of object
+  pub material: Option<Rc<Material>>
+}
+
+impl<'a> SurfaceSample<'a> {
+  pub fn new(point: Point, normal: Vector, pdf: f64, object: &'a dyn Shape) -> SurfaceSample<'a> {
+    SurfaceSample { point, normal, pdf, object, material: None }
+  }
+
+  // Transpose is of inverse of transform, as used for normals
+  pub fn transform(&self, transform: &Matrix4x4, transpose: &Matrix4x4) -> SurfaceSample<'a> {
+    let normal = transpose.mult_vector(&self.normal);
+
+    // Areas grow by determinant of transform, less whatever is lost to turning them relative to normal
+    let area_scale = transform.determinant().abs() * normal.magnitude();
+
+    SurfaceSample {
+      point: transform.mult_point(&self.point),
+      normal: normal.normalize(),
+      pdf: self.pdf / area_scale,
+      object: self.object,
+      material: self.material.clone()
+    }
+  }
+}
+
+// Shape whose material emits light, sampled over its area so it can light other surfaces
+pub struct GeometryLight<'a> {
+  pub shape: &'a dyn Shape,
+  pub material: Option<Rc<Material>>,
+  pub transform: Matrix4x4,
+  pub transpose: Matrix4x4,
+  // Takes world space to container of shape, as carried by hits on it
+  pub world_to_container: Matrix4x4,
+  pub area: f64
+}
+
+impl<'a> GeometryLight<'a> {
+  // Container transform takes container of shape to world space, material overrides that of shape as in containers
+  pub fn new(shape: &'a dyn Shape, material: Option<Rc<Material>>, container_transform: &Matrix4x4, world_to_container: &Matrix4x4) -> Option<GeometryLight<'a>> {
+    let area = shape.surface_area()?;
+    let transform = container_transform.mult4x4(shape.get_transform());
+
+    Some(GeometryLight {
+      shape,
+      material,
+      transform,
+      transpose: transform.inverse().transpose(),
+      world_to_container: *world_to_container,
+      area
+    })
+  }
+
+  // Point spread uniformly over surface in world space, from a sample in the unit square
+  pub fn sample(&self, sample: (f64, f64)) -> Option<SurfaceSample<'a>> {
+    self.shape.sample_surface(sample).map(|surface_sample| surface_sample.transform(&self.transform, &self.transpose))
+  }
+
+  // Pdf per unit area of sample landing at point of surface with world normal
+  pub fn pdf(&self, normal: &Vector) -> f64 {
+    let object_normal = self.transform.transpose().mult_vector(normal);
+
+    object_normal.magnitude() / (self.area * self.transform.determinant().abs())
+  }
+
+  // Emission is two sided, and light falls off with square of distance and cosine of surface to direction
+  pub fn solid_angle_pdf(&self, surface_sample: &SurfaceSample, from: &Point) -> f64 {
+    let towards_surface = surface_sample.point.subtract_point(from);
+    let distance_squared = towards_surface.dot(&towards_surface);
+
+    let cos = surface_sample.normal.dot(&towards_surface.normalize()).abs();
+    if cos <= 0.0 {
+      return 0.0;
+    }
+
+    surface_sample.pdf * distance_squared / cos
+  }
+
+  // Faces of a mesh may emit differently, so emission is that of object sampled or hit
+  pub fn emission(&self, object: &dyn Shape) -> Color {
+    match &self.material {
+      Some(material) => material.emission,
+      None => object.get_material().emission
+    }
+  }
+
+  // Materials nearer sampled object take precedence, as in containers
+  pub fn sample_emission(&self, surface_sample: &SurfaceSample) -> Color {
+    match &surface_sample.material {
+      Some(material) => material.emission,
+      None => self.emission(surface_sample.object)
+    }
+  }
+
+  pub fn includes(&self, object: &dyn Shape) -> bool {
+    self.shape.includes(object)
+  }
+
+  // Instances place shapes in several spots, so hits are only on this light if they came through its container
+  pub fn is_same_object(&self, object: &dyn Shape, world_to_container: &Matrix4x4) -> bool {
+    self.shape.includes_hit(object, world_to_container, &self.world_to_container)
+  }
+}
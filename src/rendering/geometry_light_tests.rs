@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Sphere;
+  use crate::rendering::shapes::Plane;
+  use crate::rendering::shapes::Quad;
+
+  use crate::rendering::Material;
+
+  use crate::rendering::SurfaceSample;
+  use crate::rendering::GeometryLight;
+
+  fn glowing_material() -> Material {
+    Material::emissive(Color::new(1.0, 1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity())
+  }
+
+  #[test]
+  fn transformed_samples_spread_pdf_over_transformed_area() {
+    let quad = Quad::new(Point::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0), Matrix4x4::identity(), glowing_material());
+
+    let surface_sample = SurfaceSample::new(Point::new(0.5, 0.0, 0.5), Vector::new(0.0, 1.0, 0.0), 1.0, &quad);
+
+    // Stretching along surface spreads samples thinner, stretching along normal leaves them alone
+    let transform = Matrix4x4::scale(3.0, 2.0, 1.0);
+    let transformed = surface_sample.transform(&transform, &transform.inverse().transpose());
+
+    assert!(transformed.point == Point::new(1.5, 0.0, 0.5));
+    assert!(transformed.normal == Vector::new(0.0, 1.0, 0.0));
+    assert!((transformed.pdf - 1.0 / 3.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn light_samples_surface_of_shape_in_world_space() {
+    let sphere = Sphere::new(Matrix4x4::scale(2.0, 2.0, 2.0), glowing_material());
+
+    let light = GeometryLight::new(&sphere, None, &Matrix4x4::translate(0.0, 10.0, 0.0), &Matrix4x4::identity()).unwrap();
+
+    for sample in [(0.1, 0.2), (0.5, 0.5), (0.9, 0.7)] {
+      let surface_sample = light.sample(sample).unwrap();
+      let from_center = surface_sample.point.subtract_point(&Point::new(0.0, 10.0, 0.0));
+
+      assert!((from_center.magnitude() - 2.0).abs() < 0.0001);
+      assert!((surface_sample.pdf - 1.0 / (16.0 * f64::consts::PI)).abs() < 0.0001);
+      assert!((light.pdf(&surface_sample.normal) - surface_sample.pdf).abs() < 0.0001);
+    }
+
+    assert!(light.emission(&sphere) == Color::new(1.0, 1.0, 1.0, 1.0));
+    assert!(light.includes(&sphere));
+  }
+
+  #[test]
+  fn pdf_of_unevenly_scaled_surface_depends_on_normal() {
+    let sphere = Sphere::new(Matrix4x4::scale(1.0, 4.0, 1.0), glowing_material());
+
+    let light = GeometryLight::new(&sphere, None, &Matrix4x4::identity(), &Matrix4x4::identity()).unwrap();
+
+    for sample in [(0.05, 0.2), (0.5, 0.5), (0.8, 0.9)] {
+      let surface_sample = light.sample(sample).unwrap();
+
+      assert!((light.pdf(&surface_sample.normal) - surface_sample.pdf).abs() < 0.0001);
+    }
+
+    // Stretched sides are sampled more thinly than poles
+    assert!(light.pdf(&Vector::new(1.0, 0.0, 0.0)) < light.pdf(&Vector::new(0.0, 1.0, 0.0)));
+  }
+
+  #[test]
+  fn solid_angle_pdf_grows_with_distance_and_tilt() {
+    let quad = Quad::new(Point::new(-0.5, 0.0, -0.5), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0), Matrix4x4::identity(), glowing_material());
+
+    let light = GeometryLight::new(&quad, None, &Matrix4x4::identity(), &Matrix4x4::identity()).unwrap();
+    let surface_sample = light.sample((0.5, 0.5)).unwrap();
+
+    assert!((light.solid_angle_pdf(&surface_sample, &Point::new(0.0, 2.0, 0.0)) - 4.0).abs() < 0.0001);
+    assert!((light.solid_angle_pdf(&surface_sample, &Point::new(0.0, -2.0, 0.0)) - 4.0).abs() < 0.0001);
+    assert!((light.solid_angle_pdf(&surface_sample, &Point::new(2.0, 2.0, 0.0)) - 8.0 * 2.0_f64.sqrt()).abs() < 0.0001);
+  }
+
+  #[test]
+  fn unbounded_shapes_are_not_lights() {
+    let plane = Plane::new(Matrix4x4::identity(), glowing_material());
+
+    assert!(plane.surface_area().is_none());
+    assert!(GeometryLight::new(&plane, None, &Matrix4x4::identity(), &Matrix4x4::identity()).is_none());
+  }
+}
@@ -0,0 +1,250 @@
+use std::rc::Rc;
+
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+
+use crate::rendering::Material;
+
+use crate::rendering::Container;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::GeometryLight;
+use crate::rendering::SurfaceSample;
+
+pub enum InstanceGeometry<'a> {
+  Shape(&'a dyn Shape),
+  Container(&'a Container<'a>)
+}
+
+// Places shared geometry under its own transform, without copying it
+pub struct Instance<'a> {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub material: Option<Rc<Material>>,
+  // Reported for instances of empty containers, which have no material of their own
+  pub fallback_material: Material,
+  pub geometry: InstanceGeometry<'a>
+}
+
+impl<'a> Instance<'a> {
+  pub fn new(shape: &'a dyn Shape, transform: Matrix4x4) -> Instance<'a> {
+    Instance::with_geometry(InstanceGeometry::Shape(shape), transform)
+  }
+
+  pub fn of_container(container: &'a Container<'a>, transform: Matrix4x4) -> Instance<'a> {
+    Instance::with_geometry(InstanceGeometry::Container(container), transform)
+  }
+
+  fn with_geometry(geometry: InstanceGeometry<'a>, transform: Matrix4x4) -> Instance<'a> {
+    let tmp_inverse = transform.inverse();
+
+    Instance {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      material: None,
+      fallback_material: Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()),
+      geometry
+    }
+  }
+
+  // Overrides materials of instanced geometry, shared geometry keeps its own materials
+  pub fn set_material(&mut self, material: Material) {
+    self.material = Some(Rc::new(material));
+  }
+
+  // Emitting shapes of instanced geometry, placed in space of instance
+  fn geometry_lights(&self) -> Vec<GeometryLight<'a>> {
+    let identity = Matrix4x4::identity();
+
+    match &self.geometry {
+      InstanceGeometry::Shape(shape) => GeometryLight::new(*shape, self.material.clone(), &identity, &identity).into_iter().collect(),
+      InstanceGeometry::Container(container) => container.geometry_lights_in_parent(&identity, &identity, self.material.as_ref())
+    }
+  }
+}
+
+// Area of light in space of instance, exact for transforms that scale evenly in every direction
+fn instance_area(light: &GeometryLight) -> f64 {
+  light.area * light.transform.determinant().abs().powf(2.0 / 3.0)
+}
+
+impl<'a> Shape for Instance<'a> {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    if let Some(material) = &self.material {
+      return material;
+    }
+
+    match &self.geometry {
+      InstanceGeometry::Shape(shape) => shape.get_material(),
+      InstanceGeometry::Container(container) => container.first_material().unwrap_or(&self.fallback_material)
+    }
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    // Instance transform is chained into matrices used by computations of shared geometry
+    let world_to_instance = self.inverse.mult4x4(&world_to_container);
+    let normal_to_world = normal_to_world.mult4x4(&self.transpose);
+
+    let mut intersections = match &self.geometry {
+      InstanceGeometry::Shape(shape) => shape.intersections(&transformed_ray, world_to_instance, normal_to_world),
+      InstanceGeometry::Container(container) => container.intersect_in_parent(&transformed_ray, world_to_instance, normal_to_world)
+    };
+
+    // Materials of nested containers take precedence
+    if let Some(material) = &self.material {
+      for intersection in &mut intersections {
+        if intersection.material.is_none() {
+          intersection.material = Some(Rc::clone(material));
+        }
+      }
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, _point: &Point) -> Vector {
+    // Not defined, normals come from instanced geometry
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined, normals come from instanced geometry
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, _point: &Point, u: f64, v: f64) -> (f64, f64) {
+    (u, v)
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Instance
+  }
+
+  // Instanced containers emit light if any of their shapes do, not only the one whose material represents them
+  fn emits_light(&self) -> bool {
+    if let Some(material) = &self.material {
+      return material.emits_light();
+    }
+
+    match &self.geometry {
+      InstanceGeometry::Shape(shape) => shape.emits_light(),
+      InstanceGeometry::Container(container) => container.emits_light()
+    }
+  }
+
+  fn includes(&self, object: &dyn Shape) -> bool {
+    match &self.geometry {
+      InstanceGeometry::Shape(shape) => shape.includes(object),
+      InstanceGeometry::Container(container) => container.includes(object)
+    }
+  }
+
+  // Hits on instanced geometry carry transform of instance, chained as in intersections
+  fn includes_hit(&self, object: &dyn Shape, hit_world_to_container: &Matrix4x4, world_to_container: &Matrix4x4) -> bool {
+    let world_to_instance = self.inverse.mult4x4(world_to_container);
+
+    match &self.geometry {
+      InstanceGeometry::Shape(shape) => shape.includes_hit(object, hit_world_to_container, &world_to_instance),
+      InstanceGeometry::Container(container) => container.includes_hit(object, hit_world_to_container, &world_to_instance)
+    }
+  }
+
+  // Only emitting shapes of instanced geometry count, as only they are sampled
+  fn surface_area(&self) -> Option<f64> {
+    let lights = self.geometry_lights();
+    if lights.is_empty() {
+      return None;
+    }
+
+    Some(lights.iter().map(instance_area).sum())
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let lights = self.geometry_lights();
+    let areas: Vec<f64> = lights.iter().map(instance_area).collect();
+    let total_area: f64 = areas.iter().sum();
+
+    if total_area <= 0.0 {
+      return None;
+    }
+
+    // First part of sample picks a light by its area, and what remains of it places point on that light
+    let mut index = 0;
+    let mut area_sample = sample.0 * total_area;
+    while index + 1 < areas.len() && area_sample >= areas[index] {
+      area_sample -= areas[index];
+      index += 1;
+    }
+
+    let light = &lights[index];
+    let mut surface_sample = light.sample(((area_sample / areas[index]).clamp(0.0, 1.0), sample.1))?;
+
+    // Chance of picking light spreads its pdf over area of all of them
+    surface_sample.pdf *= areas[index] / total_area;
+
+    // Materials nearer sampled object take precedence
+    if surface_sample.material.is_none() {
+      surface_sample.material = light.material.clone();
+    }
+
+    Some(surface_sample)
+  }
+
+  fn tessellate(&self, resolution: usize, extent: f64) -> Vec<Tessellation> {
+    let mut tessellations = match self.geometry {
+      InstanceGeometry::Shape(shape) => shape.tessellate(resolution, extent).into_iter().map(|tessellation| tessellation.transform(shape.get_transform())).collect(),
+      InstanceGeometry::Container(container) => container.tessellate(resolution, extent)
+    };
+
+    if let Some(material) = &self.material {
+      for tessellation in &mut tessellations {
+        if tessellation.override_material.is_none() {
+          tessellation.override_material = Some(Rc::clone(material));
+        }
+      }
+    }
+
+    tessellations
+  }
+}
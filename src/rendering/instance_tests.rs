@@ -0,0 +1,309 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Sphere;
+  use crate::rendering::shapes::Mesh;
+
+  use crate::rendering::Container;
+  use crate::rendering::Instance;
+
+  use crate::rendering::Scene;
+  use crate::rendering::Camera;
+  use crate::rendering::Integrator;
+  use crate::rendering::PathTracer;
+
+  use crate::rendering::Ray;
+  use crate::rendering::Computations;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn instance_created_with_shape_and_transform() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let instance = Instance::new(&sphere, Matrix4x4::translate(5.0, 0.0, 0.0));
+
+    assert!(instance.transform == Matrix4x4::translate(5.0, 0.0, 0.0));
+    assert!(instance.inverse == Matrix4x4::translate(-5.0, 0.0, 0.0));
+    assert!(instance.material.is_none());
+    assert!(instance.get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(!instance.is_eq(&sphere));
+  }
+
+  #[test]
+  fn intersecting_instances_of_one_shape() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::scale(2.0, 2.0, 2.0), material);
+
+    let instance_1 = Instance::new(&sphere, Matrix4x4::translate(0.0, 0.0, 10.0));
+    let instance_2 = Instance::new(&sphere, Matrix4x4::translate(5.0, 0.0, 0.0));
+    let container = Container::new(Matrix4x4::identity(), vec![&instance_1 as &dyn Shape, &instance_2 as &dyn Shape]);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = container.intersect(&ray);
+
+    assert_eq!(intersections.len(), 2);
+    assert_eq!(intersections[0].t, 13.0);
+    assert_eq!(intersections[1].t, 17.0);
+    assert!(intersections[0].object.is_eq(&sphere));
+
+    let ray = Ray::new(&Point::new(5.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = container.intersect(&ray);
+
+    assert_eq!(intersections.len(), 2);
+    assert_eq!(intersections[0].t, 3.0);
+  }
+
+  #[test]
+  fn instance_transform_chains_with_container_transforms() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::translate(5.0, 0.0, 0.0), material);
+
+    let instance = Instance::new(&sphere, Matrix4x4::scale(1.0, 2.0, 3.0));
+    let container = Container::new(Matrix4x4::rotate_y(f64::consts::PI / 2.0), vec![&instance as &dyn Shape]);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -20.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = container.intersect(&ray);
+
+    assert_eq!(intersections.len(), 2);
+
+    let world_to_instance = Matrix4x4::scale(1.0, 2.0, 3.0).inverse().mult4x4(&Matrix4x4::rotate_y(f64::consts::PI / 2.0).inverse());
+    assert!(intersections[0].world_to_container.is_eq(&world_to_instance));
+    assert!(intersections[0].normal_to_world.is_eq(&world_to_instance.transpose()));
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!((computations.point.z - -6.0).abs() < 0.0001);
+    assert!((computations.normal.x - 0.0).abs() < 0.0001);
+    assert!((computations.normal.y - 0.0).abs() < 0.0001);
+    assert!((computations.normal.z - -1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn instances_share_mesh_geometry() {
+    let vertices = vec![Point::new(-1.0, -1.0, 0.0), Point::new(1.0, -1.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = Mesh::from_triangles(vertices, vec![[0, 1, 2]], Matrix4x4::identity(), material);
+
+    let mut instances = Vec::new();
+    for i in 0..100 {
+      instances.push(Instance::new(&mesh, Matrix4x4::translate(i as f64 * 3.0, 0.0, 0.0)));
+    }
+
+    let shapes = instances.iter().map(|instance| instance as &dyn Shape).collect();
+    let container = Container::new(Matrix4x4::identity(), shapes);
+
+    let ray = Ray::new(&Point::new(150.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = container.intersect(&ray);
+
+    assert_eq!(intersections.len(), 1);
+    assert!(intersections[0].object.is_eq(&mesh));
+    assert_eq!(mesh.faces.len(), 1);
+  }
+
+  #[test]
+  fn instancing_container_of_shapes() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere_1 = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_2 = Sphere::new(Matrix4x4::translate(0.0, 0.0, 3.0), material);
+
+    let group = Container::named("pair", Matrix4x4::translate(0.0, 0.0, 1.0), vec![&sphere_1 as &dyn Shape, &sphere_2 as &dyn Shape]);
+    let instance = Instance::of_container(&group, Matrix4x4::translate(10.0, 0.0, 0.0));
+
+    assert!(instance.get_material().color_1 == Color::new(0.0, 1.0, 0.0, 1.0));
+
+    let ray = Ray::new(&Point::new(10.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = instance.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 4);
+    assert_eq!(intersections[0].t, 5.0);
+    assert!(intersections[0].object.is_eq(&sphere_1));
+    assert_eq!(intersections[3].t, 10.0);
+    assert!(intersections[3].object.is_eq(&sphere_2));
+  }
+
+  #[test]
+  fn instance_material_overrides_shared_geometry_material() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let mut red_instance = Instance::new(&sphere, Matrix4x4::translate(0.0, 0.0, 10.0));
+    red_instance.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()));
+
+    let plain_instance = Instance::new(&sphere, Matrix4x4::identity());
+
+    let container = Container::new(Matrix4x4::identity(), vec![&red_instance as &dyn Shape, &plain_instance as &dyn Shape]);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = container.intersect(&ray);
+
+    assert_eq!(intersections.len(), 4);
+    assert!(intersections[0].get_material().color_1 == Color::new(1.0, 1.0, 1.0, 1.0));
+    assert!(intersections[2].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(red_instance.get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(sphere.get_material().color_1 == Color::new(1.0, 1.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn instanced_container_material_takes_precedence() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let mut group = Container::new(Matrix4x4::identity(), vec![&sphere as &dyn Shape]);
+    group.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 0.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut instance = Instance::of_container(&group, Matrix4x4::identity());
+    instance.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()));
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = instance.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert!(intersections[0].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn instance_of_empty_container_has_fallback_material() {
+    let empty = Container::new(Matrix4x4::identity(), Vec::new());
+    let instance = Instance::of_container(&empty, Matrix4x4::identity());
+
+    assert!(instance.get_material().color_1 == Color::new(1.0, 1.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn tessellating_instance_applies_geometry_transform() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::translate(0.0, 3.0, 0.0), material);
+
+    let mut instance = Instance::new(&sphere, Matrix4x4::translate(5.0, 0.0, 0.0));
+    instance.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()));
+
+    let container = Container::new(Matrix4x4::identity(), vec![&instance as &dyn Shape]);
+    let tessellations = container.tessellate(8, 100.0);
+
+    assert_eq!(tessellations.len(), 1);
+    assert!(tessellations[0].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+
+    for vertex in &tessellations[0].vertices {
+      let offset = vertex.subtract_point(&Point::new(5.0, 3.0, 0.0));
+
+      assert!((offset.magnitude() - 1.0).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn instanced_container_emits_light_if_any_shape_does() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::emissive(Color::new(1.0, 1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let glowing_sphere = Sphere::new(Matrix4x4::translate(3.0, 0.0, 0.0), material);
+
+    let group = Container::new(Matrix4x4::identity(), vec![&sphere as &dyn Shape, &glowing_sphere as &dyn Shape]);
+    let mut instance = Instance::of_container(&group, Matrix4x4::identity());
+
+    assert!(instance.emits_light());
+
+    instance.set_material(Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()));
+
+    assert!(!instance.emits_light());
+  }
+
+  #[test]
+  fn instanced_emitters_are_sampled_through_instance_transform() {
+    let material = Material::emissive(Color::new(1.0, 1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::translate(1.0, 0.0, 0.0), material);
+    let instance = Instance::new(&sphere, Matrix4x4::translate(0.0, 5.0, 0.0).mult4x4(&Matrix4x4::scale(2.0, 2.0, 2.0)));
+
+    // Area is before transform of instance is applied, as for any shape
+    assert!((instance.surface_area().unwrap() - 4.0 * f64::consts::PI).abs() < 0.0001);
+
+    let container = Container::new(Matrix4x4::identity(), vec![&sphere as &dyn Shape, &instance as &dyn Shape]);
+    let lights = container.geometry_lights();
+
+    assert_eq!(lights.len(), 2);
+
+    // Points are spread evenly over copy of sphere placed by instance
+    for sample in [(0.1, 0.2), (0.5, 0.5), (0.9, 0.7)] {
+      let surface_sample = lights[1].sample(sample).unwrap();
+
+      assert!((surface_sample.point.subtract_point(&Point::new(2.0, 5.0, 0.0)).magnitude() - 2.0).abs() < 0.0001);
+      assert!((surface_sample.pdf - 1.0 / (16.0 * f64::consts::PI)).abs() < 0.0001);
+    }
+
+    // Hits match light of copy they are on, though both copies are same sphere
+    let direct_hit = &container.intersect(&Ray::new(&Point::new(1.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0)))[0];
+    let instanced_hit = &container.intersect(&Ray::new(&Point::new(2.0, 5.0, -5.0), &Vector::new(0.0, 0.0, 1.0)))[0];
+
+    assert!(lights[0].is_same_object(direct_hit.object, &direct_hit.world_to_container));
+    assert!(!lights[0].is_same_object(instanced_hit.object, &instanced_hit.world_to_container));
+    assert!(lights[1].is_same_object(instanced_hit.object, &instanced_hit.world_to_container));
+    assert!(!lights[1].is_same_object(direct_hit.object, &direct_hit.world_to_container));
+  }
+
+  #[test]
+  fn instanced_containers_sample_only_their_emitting_shapes() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), material);
+
+    let material = Material::emissive(Color::new(2.0, 2.0, 2.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let glowing_sphere = Sphere::new(Matrix4x4::translate(3.0, 0.0, 0.0), material);
+
+    let mut child = Container::new(Matrix4x4::identity(), vec![&glowing_sphere as &dyn Shape]);
+    child.set_material(Material::emissive(Color::new(0.0, 4.0, 0.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut group = Container::new(Matrix4x4::identity(), vec![&sphere as &dyn Shape]);
+    group.add_child(child);
+
+    let mut instance = Instance::of_container(&group, Matrix4x4::scale(3.0, 3.0, 3.0));
+    instance.set_material(Material::emissive(Color::new(1.0, 0.0, 0.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    // Material of instance makes every shape emit, though that of child still takes precedence for its own
+    assert!((instance.surface_area().unwrap() - 8.0 * f64::consts::PI).abs() < 0.0001);
+
+    let container = Container::new(Matrix4x4::identity(), vec![&instance as &dyn Shape]);
+    let lights = container.geometry_lights();
+
+    let surface_sample = lights[0].sample((0.25, 0.5)).unwrap();
+    assert!((surface_sample.point.subtract_point(&Point::new(0.0, 0.0, 0.0)).magnitude() - 3.0).abs() < 0.0001);
+    assert!(lights[0].sample_emission(&surface_sample) == Color::new(1.0, 0.0, 0.0, 1.0));
+
+    let surface_sample = lights[0].sample((0.75, 0.5)).unwrap();
+    assert!((surface_sample.point.subtract_point(&Point::new(9.0, 0.0, 0.0)).magnitude() - 3.0).abs() < 0.0001);
+    assert!(lights[0].sample_emission(&surface_sample) == Color::new(0.0, 4.0, 0.0, 1.0));
+    assert!((surface_sample.pdf - 1.0 / (72.0 * f64::consts::PI)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn path_tracing_scene_with_instance_of_empty_container() {
+    let empty = Container::new(Matrix4x4::identity(), Vec::new());
+    let instance = Instance::of_container(&empty, Matrix4x4::identity());
+
+    let material = Material::emissive(Color::new(1.0, 1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::translate(0.0, 0.0, -5.0).mult4x4(&Matrix4x4::scale(3.0, 3.0, 3.0)), material);
+
+    assert!(!instance.emits_light());
+    assert!(instance.get_material().emission == Color::new(0.0, 0.0, 0.0, 1.0));
+
+    let container = Container::new(Matrix4x4::identity(), vec![&instance as &dyn Shape, &sphere as &dyn Shape]);
+    let scene = Scene::new(Camera::new(4, 2, f64::consts::PI / 2.0, Matrix4x4::identity()), Vec::new(), vec![container]);
+
+    assert_eq!(scene.geometry_lights().len(), 1);
+
+    let canvas = scene.render_with(&Integrator::PathTraced(PathTracer::new(1, 2)));
+
+    assert!(*canvas.pixel_color(1, 2) == Color::new(1.0, 1.0, 1.0, 1.0));
+  }
+}
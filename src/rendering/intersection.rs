@@ -1,14 +1,19 @@
+use std::rc::Rc;
+
 use crate::rendering::math::Matrix4x4;
 
 use crate::rendering::shapes::shape::Shape;
 
+use crate::rendering::Material;
+
 pub struct Intersection<'a> {
   pub t: f64,
   pub object: &'a dyn Shape,
   pub world_to_container: Matrix4x4,
   pub normal_to_world: Matrix4x4,
   pub u: f64,
-  pub v: f64
+  pub v: f64,
+  pub material: Option<Rc<Material>>
 }
 
 impl<'a> Intersection<'a> {
@@ -40,7 +45,8 @@ impl<'a> Intersection<'a> {
       world_to_container: world_to_container, 
       normal_to_world: normal_to_world,
       u: 0.0,
-      v: 0.0
+      v: 0.0,
+      material: None
     }
   }
 
@@ -51,7 +57,21 @@ impl<'a> Intersection<'a> {
       world_to_container: world_to_container, 
       normal_to_world: normal_to_world,
       u: u,
-      v: v
+      v,
+      material: None
+    }
+  }
+
+  // Copies of shared geometry report the same object, so are told apart by transforms that reached them
+  pub fn is_same_object(&self, other: &Intersection) -> bool {
+    self.object.get_id() == other.object.get_id() && self.world_to_container == other.world_to_container
+  }
+
+  // Material of containing group, if overridden, otherwise material of object
+  pub fn get_material(&self) -> &Material {
+    match &self.material {
+      Some(material) => material,
+      None => self.object.get_material()
     }
   }
 }
@@ -1,17 +1,40 @@
+use std::rc::Rc;
+
 use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
 
 use crate::rendering::math::Color;
 
 use crate::rendering::math::Matrix4x4;
 
+use crate::rendering::math::noise::fractal_noise;
+
 use crate::rendering::shapes::shape::Shape;
 
+use crate::rendering::Canvas;
+use crate::rendering::TextureFilter;
+
+use crate::rendering::SurfaceDifferentials;
+
+use crate::rendering::ShadingModel;
+use crate::rendering::Phong;
+use crate::rendering::MetallicRoughness;
+
+// Distance either side of point that bump heights are compared over
+const BUMP_EPSILON: f64 = 0.0001;
+
+const BUMP_OCTAVES: usize = 4;
+
+// Height of surface above point, scaled by bump_scale of material
+pub type BumpFunc = fn(material: &Material, object: &dyn Shape, position: &Point) -> f64;
+
 // const VACUUM_REFRACTIVE_INDEX: f64 = 1.0;
 // const AIR_REFRACTIVE_INDEX: f64 = 1.00029;
 // const WATER_REFRACTIVE_INDEX: f64 = 1.333;
 // const GLASS_REFRACTIVE_INDEX: f64 = 1.52;
 // const DIAMOND_REFRACTIVE_INDEX: f64 = 2.417;
 
+#[derive(Clone)]
 pub struct Material {
   pub ambient: f64,
   pub diffuse: f64,
@@ -24,7 +47,17 @@ pub struct Material {
   pub color_2: Color,
   pub transform: Matrix4x4,
   pub inverse: Matrix4x4,
-  pub pattern_func: fn(material: &Material, object: &dyn Shape, position: &Point) -> Color
+  pub pattern_func: fn(material: &Material, object: &dyn Shape, position: &Point) -> Color,
+  pub filtered_pattern_func: fn(material: &Material, object: &dyn Shape, position: &Point, widths: &Vector) -> Color,
+  pub texture: Option<Rc<Canvas>>,
+  pub texture_filter: TextureFilter,
+  pub normal_map: Option<Rc<Canvas>>,
+  pub bump_func: Option<BumpFunc>,
+  pub bump_scale: f64,
+  pub shading_model: Rc<dyn ShadingModel>,
+  pub metallic: f64,
+  pub roughness: f64,
+  pub emission: Color
 }
 
 impl Material {
@@ -52,7 +85,17 @@ impl Material {
       color_2: color,
       transform: transform,
       inverse: transform.inverse(),
-      pattern_func: solid_pattern_func
+      pattern_func: solid_pattern_func,
+      filtered_pattern_func: unfiltered_pattern_func,
+      texture: None,
+      texture_filter: TextureFilter::Trilinear,
+      normal_map: None,
+      bump_func: None,
+      bump_scale: 1.0,
+      shading_model: Rc::new(Phong),
+      metallic: 0.0,
+      roughness: 0.5,
+      emission: Color::new(0.0, 0.0, 0.0, 1.0)
     }
   }
 
@@ -81,7 +124,17 @@ impl Material {
       color_2: color_2,
       transform: transform,
       inverse: transform.inverse(),
-      pattern_func: checker_pattern_func
+      pattern_func: checker_pattern_func,
+      filtered_pattern_func: filtered_checker_pattern_func,
+      texture: None,
+      texture_filter: TextureFilter::Trilinear,
+      normal_map: None,
+      bump_func: None,
+      bump_scale: 1.0,
+      shading_model: Rc::new(Phong),
+      metallic: 0.0,
+      roughness: 0.5,
+      emission: Color::new(0.0, 0.0, 0.0, 1.0)
     }
   }
 
@@ -110,7 +163,17 @@ impl Material {
       color_2: color_2,
       transform: transform,
       inverse: transform.inverse(),
-      pattern_func: stripe_pattern_func
+      pattern_func: stripe_pattern_func,
+      filtered_pattern_func: filtered_stripe_pattern_func,
+      texture: None,
+      texture_filter: TextureFilter::Trilinear,
+      normal_map: None,
+      bump_func: None,
+      bump_scale: 1.0,
+      shading_model: Rc::new(Phong),
+      metallic: 0.0,
+      roughness: 0.5,
+      emission: Color::new(0.0, 0.0, 0.0, 1.0)
     }
   }
 
@@ -139,7 +202,17 @@ impl Material {
       color_2: color_2,
       transform: transform,
       inverse: transform.inverse(),
-      pattern_func: ring_pattern_func
+      pattern_func: ring_pattern_func,
+      filtered_pattern_func: unfiltered_pattern_func,
+      texture: None,
+      texture_filter: TextureFilter::Trilinear,
+      normal_map: None,
+      bump_func: None,
+      bump_scale: 1.0,
+      shading_model: Rc::new(Phong),
+      metallic: 0.0,
+      roughness: 0.5,
+      emission: Color::new(0.0, 0.0, 0.0, 1.0)
     }
   }
 
@@ -168,10 +241,51 @@ impl Material {
       color_2: color_2,
       transform: transform,
       inverse: transform.inverse(),
-      pattern_func: gradient_pattern_func
+      pattern_func: gradient_pattern_func,
+      filtered_pattern_func: unfiltered_pattern_func,
+      texture: None,
+      texture_filter: TextureFilter::Trilinear,
+      normal_map: None,
+      bump_func: None,
+      bump_scale: 1.0,
+      shading_model: Rc::new(Phong),
+      metallic: 0.0,
+      roughness: 0.5,
+      emission: Color::new(0.0, 0.0, 0.0, 1.0)
     }
   }
 
+  // Lit by microfacet model, diffuse and specular are left at full strength as they do not apply
+  pub fn metallic_roughness(
+    ambient: f64, 
+    metallic: f64, 
+    roughness: f64, 
+    transparency: f64, 
+    refractive_index: f64,
+    color: Color,
+    transform: Matrix4x4
+  ) -> Material {
+
+    let mut material = Material::solid(ambient, 1.0, 1.0, 200.0, 0.0, transparency, refractive_index, color, transform);
+    material.shading_model = Rc::new(MetallicRoughness);
+    material.metallic = metallic;
+    material.roughness = roughness;
+
+    material
+  }
+
+  // Glows with emission in every direction from both sides, and is otherwise lit like a matte surface
+  pub fn emissive(emission: Color, color: Color, transform: Matrix4x4) -> Material {
+    let mut material = Material::solid(0.0, 0.9, 0.0, 200.0, 0.0, 0.0, 1.0, color, transform);
+    material.emission = emission;
+
+    material
+  }
+
+  pub fn emits_light(&self) -> bool {
+    self.emission.r > 0.0 || self.emission.g > 0.0 || self.emission.b > 0.0
+  }
+
   pub fn convert_point(&self, object: &dyn Shape, position: &Point) -> Point {
     let object_point = object.get_inverse().mult_point(position);
     
@@ -181,6 +295,90 @@ impl Material {
   pub fn color_at(&self, object: &dyn Shape, position: &Point) -> Color {
     (self.pattern_func)(self, object, position)
   }
+
+  pub fn convert_vector(&self, object: &dyn Shape, vector: &Vector) -> Vector {
+    let object_vector = object.get_inverse().mult_vector(vector);
+
+    self.inverse.mult_vector(&object_vector)
+  }
+
+  // Color averaged over footprint of ray on surface, rather than sampled at a single point
+  pub fn filtered_color_at(&self, object: &dyn Shape, position: &Point, u: f64, v: f64, differentials: &SurfaceDifferentials) -> Color {
+    let pattern_dx = self.convert_vector(object, &differentials.point_dx);
+    let pattern_dy = self.convert_vector(object, &differentials.point_dy);
+
+    // Half widths of box covering footprint along each axis of pattern
+    let widths = Vector::new(
+      pattern_dx.x.abs().max(pattern_dy.x.abs()) / 2.0,
+      pattern_dx.y.abs().max(pattern_dy.y.abs()) / 2.0,
+      pattern_dx.z.abs().max(pattern_dy.z.abs()) / 2.0
+    );
+
+    let pattern_color = (self.filtered_pattern_func)(self, object, position, &widths);
+
+    match &self.texture {
+      Some(texture) => {
+        let texture_color = match self.texture_filter {
+          TextureFilter::Box => {
+            let (u_width, v_width) = differentials.texture_widths();
+
+            texture.color_in_uv_box(u, v, u_width, v_width)
+          },
+          TextureFilter::Trilinear => texture.mipmap().trilinear(u, v, differentials.texture_dx, differentials.texture_dy),
+          TextureFilter::Ewa => texture.mipmap().ewa(u, v, differentials.texture_dx, differentials.texture_dy)
+        };
+
+        texture_color.mult_color(&pattern_color)
+      },
+      None => pattern_color
+    }
+  }
+
+  pub fn perturbs_normal(&self) -> bool {
+    self.normal_map.is_some() || self.bump_func.is_some()
+  }
+
+  // Normal tilted by normal map and bump function, tangent and bitangent must be unit length and perpendicular to normal
+  pub fn shading_normal(&self, object: &dyn Shape, position: &Point, u: f64, v: f64, normal: &Vector, tangents: &(Vector, Vector)) -> Vector {
+    let (tangent, bitangent) = tangents;
+
+    // Normal map holds direction relative to tangents and normal, with components mapped from -1 to 1 onto colors
+    let (mut tangent_weight, mut bitangent_weight, normal_weight) = match &self.normal_map {
+      Some(normal_map) => {
+        let color = normal_map.mipmap().bilinear(0, u, v);
+
+        (color.r * 2.0 - 1.0, color.g * 2.0 - 1.0, color.b * 2.0 - 1.0)
+      },
+      None => (0.0, 0.0, 1.0)
+    };
+
+    // Normal leans away from direction height increases in
+    if let Some(bump_func) = self.bump_func {
+      let slope_along = |direction: &Vector| {
+        let offset = direction.multiply(BUMP_EPSILON);
+
+        let ahead = bump_func(self, object, &position.add_vector(&offset));
+        let behind = bump_func(self, object, &position.subtract_vector(&offset));
+
+        (ahead - behind) / (2.0 * BUMP_EPSILON)
+      };
+
+      tangent_weight -= self.bump_scale * slope_along(tangent);
+      bitangent_weight -= self.bump_scale * slope_along(bitangent);
+    }
+
+    tangent.multiply(tangent_weight).add_vector(&bitangent.multiply(bitangent_weight)).add_vector(&normal.multiply(normal_weight)).normalize()
+  }
+
+  pub fn color_at_with_uv(&self, object: &dyn Shape, position: &Point, u: f64, v: f64) -> Color {
+    let pattern_color = self.color_at(object, position);
+
+    // Texture is tinted by pattern color
+    match &self.texture {
+      Some(texture) => texture.color_at_uv(u, v).mult_color(&pattern_color),
+      None => pattern_color
+    }
+  }
 }
 
 pub fn solid_pattern_func(material: &Material, _object: &dyn Shape, _position: &Point) -> Color {
@@ -191,7 +389,7 @@ pub fn checker_pattern_func(material: &Material, object: &dyn Shape, position: &
   let pattern_point = material.convert_point(object, position);
 
   let summed_floor = pattern_point.x.round() + pattern_point.y.round() + pattern_point.z.round();
-  if (summed_floor as u64) % 2 == 0 {
+  if (summed_floor as i64).rem_euclid(2) == 0 {
     material.color_1
   } else {
     material.color_2
@@ -235,3 +433,66 @@ pub fn gradient_pattern_func(material: &Material, object: &dyn Shape, position:
 
   material.color_1.add_color(&distance.mult_scalar(remainder))
 }
+
+// Fractal noise through pattern space, taken across three planes so surfaces facing any axis are bumped
+pub fn noise_bump_func(material: &Material, object: &dyn Shape, position: &Point) -> f64 {
+  let pattern_point = material.convert_point(object, position);
+
+  let xy_noise = fractal_noise(pattern_point.x, pattern_point.y, BUMP_OCTAVES, 0);
+  let yz_noise = fractal_noise(pattern_point.y, pattern_point.z, BUMP_OCTAVES, 101);
+  let zx_noise = fractal_noise(pattern_point.z, pattern_point.x, BUMP_OCTAVES, 211);
+
+  (xy_noise + yz_noise + zx_noise) / 3.0
+}
+
+// Patterns without a filtered form are sampled at center of footprint
+pub fn unfiltered_pattern_func(material: &Material, object: &dyn Shape, position: &Point, _widths: &Vector) -> Color {
+  (material.pattern_func)(material, object, position)
+}
+
+pub fn filtered_checker_pattern_func(material: &Material, object: &dyn Shape, position: &Point, widths: &Vector) -> Color {
+  let pattern_point = material.convert_point(object, position);
+
+  // Checker is product of square waves along each axis, with cells centered on whole numbers
+  let average = filtered_square_wave(pattern_point.x + 0.5, widths.x) * 
+    filtered_square_wave(pattern_point.y + 0.5, widths.y) * 
+    filtered_square_wave(pattern_point.z + 0.5, widths.z);
+
+  blend_pattern_colors(material, average)
+}
+
+pub fn filtered_stripe_pattern_func(material: &Material, object: &dyn Shape, position: &Point, widths: &Vector) -> Color {
+  let pattern_point = material.convert_point(object, position);
+
+  let average = filtered_square_wave(pattern_point.x, widths.x);
+
+  blend_pattern_colors(material, average)
+}
+
+// Average of wave that is 1 on even unit intervals and -1 on odd ones, over box of given half width
+pub fn filtered_square_wave(x: f64, half_width: f64) -> f64 {
+  if half_width <= 0.0 {
+    return if (x.floor() as i64).rem_euclid(2) == 0 { 1.0 } else { -1.0 };
+  }
+
+  (square_wave_integral(x + half_width) - square_wave_integral(x - half_width)) / (2.0 * half_width)
+}
+
+fn square_wave_integral(x: f64) -> f64 {
+  let interval = x.floor();
+  let fraction = x - interval;
+
+  // Each pair of intervals cancels out, leaving a triangle wave
+  if (interval as i64).rem_euclid(2) == 0 {
+    fraction
+  } else {
+    1.0 - fraction
+  }
+}
+
+// Average of 1 is all first color, and average of -1 all second color
+fn blend_pattern_colors(material: &Material, average: f64) -> Color {
+  let color_1_weight = (1.0 + average) / 2.0;
+
+  material.color_1.mult_scalar(color_1_weight).add_color(&material.color_2.mult_scalar(1.0 - color_1_weight))
+}
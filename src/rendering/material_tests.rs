@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
+  use std::rc::Rc;
+
   use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
 
   use crate::rendering::math::Color;
 
@@ -9,7 +12,15 @@ mod tests {
   use crate::rendering::shapes::shape::Shape;
   use crate::rendering::shapes::Sphere;
 
+  use crate::rendering::Canvas;
+  use crate::rendering::TextureFilter;
+
+  use crate::rendering::SurfaceDifferentials;
+
   use crate::rendering::Material;
+  use crate::rendering::ShadingModelType;
+  use crate::rendering::material::filtered_square_wave;
+  use crate::rendering::material::noise_bump_func;
 
   #[test]
   fn sets_values() {
@@ -206,4 +217,240 @@ mod tests {
     assert!(gradient_material.color_at(sphere as &dyn Shape, &Point::new(0.5, 0.0, 0.0)) == Color::new(0.5, 0.5, 0.5, 1.0));
     assert!(gradient_material.color_at(sphere as &dyn Shape, &Point::new(0.75, 0.0, 0.0)) == Color::new(0.25, 0.25, 0.25, 1.0));
   }
+
+  #[test]
+  fn textured_material_samples_texture_tinted_by_color() {
+    let mut texture = Canvas::new(2, 1);
+    texture.color_pixel(0, 0, Color::new(1.0, 1.0, 1.0, 1.0));
+    texture.color_pixel(0, 1, Color::new(0.0, 1.0, 0.0, 1.0));
+
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.5, 0.5, 0.5, 1.0), Matrix4x4::identity());
+    material.texture = Some(Rc::new(texture));
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let textured_material = sphere.get_material();
+
+    assert!(textured_material.color_at_with_uv(sphere as &dyn Shape, &Point::empty(), 0.0, 0.0) == Color::new(0.5, 0.5, 0.5, 1.0));
+    assert!(textured_material.color_at_with_uv(sphere as &dyn Shape, &Point::empty(), 1.0, 0.0) == Color::new(0.0, 0.5, 0.0, 1.0));
+  }
+
+  #[test]
+  fn untextured_material_ignores_texture_coordinates() {
+    let material = Material::solid(0.1, 0.7, 0.2, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    assert!(sphere.get_material().color_at_with_uv(sphere as &dyn Shape, &Point::empty(), 0.25, 0.75) == Color::new(0.0, 1.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn checker_alternates_for_negative_coordinates() {
+    let checkered_material = Material::checkered(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Color::new(0.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    assert!(checkered_material.color_at(sphere as &dyn Shape, &Point::new(-1.0, 0.0, 0.0)) == Color::new(0.0, 0.0, 0.0, 1.0));
+    assert!(checkered_material.color_at(sphere as &dyn Shape, &Point::new(-2.0, 0.0, 0.0)) == Color::new(1.0, 1.0, 1.0, 1.0));
+    assert!(checkered_material.color_at(sphere as &dyn Shape, &Point::new(-1.0, 0.0, 1.0)) == Color::new(1.0, 1.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn filtered_square_wave_averages_over_box() {
+    assert_eq!(filtered_square_wave(0.5, 0.0), 1.0);
+    assert_eq!(filtered_square_wave(1.5, 0.0), -1.0);
+    assert_eq!(filtered_square_wave(-0.5, 0.0), -1.0);
+
+    assert!((filtered_square_wave(0.5, 0.5) - 1.0).abs() < 0.0001);
+    assert!(filtered_square_wave(1.0, 0.5).abs() < 0.0001);
+    assert!((filtered_square_wave(0.75, 0.5) - 0.5).abs() < 0.0001);
+    assert!(filtered_square_wave(13.3, 40.0).abs() < 0.05);
+  }
+
+  #[test]
+  fn filtered_checker_matches_checker_for_small_footprint() {
+    let checkered_material = Material::checkered(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Color::new(0.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let widths = Vector::new(0.01, 0.01, 0.01);
+
+    for point in [Point::new(0.0, 0.0, 0.0), Point::new(0.99, 0.0, 0.0), Point::new(-1.2, 0.3, 2.1), Point::new(3.4, -0.2, -0.7), Point::new(-1.0, 0.0, 0.0), Point::new(-2.3, 0.2, -0.6)].iter() {
+      let filtered_color = (checkered_material.filtered_pattern_func)(&checkered_material, sphere as &dyn Shape, point, &widths);
+
+      let color = checkered_material.color_at(sphere as &dyn Shape, point);
+
+      assert!((filtered_color.r - color.r).abs() < 0.0001);
+      assert!((filtered_color.g - color.g).abs() < 0.0001);
+      assert!((filtered_color.b - color.b).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn filtered_checker_blends_colors_for_large_footprint() {
+    let checkered_material = Material::checkered(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Color::new(0.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    // Footprint covering many cells along x and z of a plane
+    let differentials = SurfaceDifferentials {
+      point_dx: Vector::new(20.0, 0.0, 0.0),
+      point_dy: Vector::new(0.0, 0.0, 20.0),
+      normal_dx: Vector::new(0.0, 0.0, 0.0),
+      normal_dy: Vector::new(0.0, 0.0, 0.0),
+      texture_dx: (0.0, 0.0),
+      texture_dy: (0.0, 0.0)
+    };
+
+    let color = checkered_material.filtered_color_at(sphere as &dyn Shape, &Point::new(0.3, 0.0, 0.1), 0.0, 0.0, &differentials);
+
+    assert!((color.r - 0.5).abs() < 0.01);
+    assert!((color.g - 0.5).abs() < 0.01);
+    assert!((color.b - 0.5).abs() < 0.01);
+  }
+
+  #[test]
+  fn filtered_stripe_blends_colors_across_edge() {
+    let striped_material = Material::striped(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Color::new(0.0, 0.0, 0.0, 1.0), Matrix4x4::identity());
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let within_stripe = (striped_material.filtered_pattern_func)(&striped_material, sphere as &dyn Shape, &Point::new(0.5, 0.0, 0.0), &Vector::new(0.4, 0.0, 0.0));
+    let across_edge = (striped_material.filtered_pattern_func)(&striped_material, sphere as &dyn Shape, &Point::new(1.0, 0.0, 0.0), &Vector::new(0.4, 0.0, 0.0));
+
+    assert!(within_stripe == Color::new(1.0, 1.0, 1.0, 1.0));
+    assert!((across_edge.r - 0.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn filtered_texture_averages_image_under_footprint() {
+    let mut canvas = Canvas::new(2, 2);
+    canvas.color_pixel(0, 0, Color::new(0.0, 0.0, 0.0, 1.0));
+    canvas.color_pixel(1, 1, Color::new(0.0, 0.0, 0.0, 1.0));
+
+    let mut textured_material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    textured_material.texture = Some(Rc::new(canvas));
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let differentials = SurfaceDifferentials {
+      point_dx: Vector::new(0.0, 0.0, 0.0),
+      point_dy: Vector::new(0.0, 0.0, 0.0),
+      normal_dx: Vector::new(0.0, 0.0, 0.0),
+      normal_dy: Vector::new(0.0, 0.0, 0.0),
+      texture_dx: (1.0, 0.0),
+      texture_dy: (0.0, 1.0)
+    };
+
+    let color = textured_material.filtered_color_at(sphere as &dyn Shape, &Point::new(0.0, 0.0, 0.0), 0.5, 0.5, &differentials);
+
+    assert!((color.r - 0.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn texture_filter_chooses_lookup_of_filtered_texture() {
+    let mut canvas = Canvas::new(4, 4);
+    for row in 0..4 {
+      for column in 0..4 {
+        if (row + column) % 2 == 0 {
+          canvas.color_pixel(row, column, Color::new(0.0, 0.0, 0.0, 1.0));
+        }
+      }
+    }
+    let canvas = Rc::new(canvas);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let mut textured_material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    textured_material.texture = Some(Rc::clone(&canvas));
+
+    assert!(textured_material.texture_filter == TextureFilter::Trilinear);
+
+    let small_footprint = SurfaceDifferentials {
+      point_dx: Vector::new(0.0, 0.0, 0.0),
+      point_dy: Vector::new(0.0, 0.0, 0.0),
+      normal_dx: Vector::new(0.0, 0.0, 0.0),
+      normal_dy: Vector::new(0.0, 0.0, 0.0),
+      texture_dx: (0.01, 0.0),
+      texture_dy: (0.0, 0.01)
+    };
+
+    let mut large_footprint = small_footprint;
+    large_footprint.texture_dx = (1.0, 0.0);
+    large_footprint.texture_dy = (0.0, 1.0);
+
+    for texture_filter in [TextureFilter::Box, TextureFilter::Trilinear, TextureFilter::Ewa].iter() {
+      textured_material.texture_filter = *texture_filter;
+
+      let sharp = textured_material.filtered_color_at(sphere as &dyn Shape, &Point::new(0.0, 0.0, 0.0), 0.125, 0.875, &small_footprint);
+      let blurred = textured_material.filtered_color_at(sphere as &dyn Shape, &Point::new(0.0, 0.0, 0.0), 0.5, 0.5, &large_footprint);
+
+      assert!(sharp.r < 0.01);
+      assert!((blurred.r - 0.5).abs() < 0.01);
+    }
+  }
+
+  #[test]
+  fn materials_without_maps_leave_shading_normal_unchanged() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::identity(), Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let shading_normal = material.shading_normal(&sphere, &Point::new(0.0, 0.0, -1.0), 0.0, 0.5, &normal, &(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)));
+
+    assert!(!material.perturbs_normal());
+    assert!(shading_normal == normal);
+  }
+
+  #[test]
+  fn noise_bump_varies_smoothly_between_zero_and_one() {
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::scale(0.25, 0.25, 0.25));
+    material.bump_func = Some(noise_bump_func);
+    let sphere = Sphere::new(Matrix4x4::identity(), Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+
+    let mut heights = Vec::new();
+    for i in 0..20 {
+      let position = Point::new(i as f64 * 0.37, 0.5, -(i as f64) * 0.21);
+
+      let height = noise_bump_func(&material, &sphere, &position);
+      let nearby_height = noise_bump_func(&material, &sphere, &position.add_vector(&Vector::new(0.00001, 0.0, 0.0)));
+
+      assert!((0.0..=1.0).contains(&height));
+      assert!((height - nearby_height).abs() < 0.001);
+
+      heights.push(height);
+    }
+
+    assert!(material.perturbs_normal());
+    assert!(heights.iter().any(|height| (height - heights[0]).abs() > 0.01));
+  }
+
+  #[test]
+  fn materials_are_shaded_by_phong_unless_metallic_roughness() {
+    let phong = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let physically_based = Material::metallic_roughness(0.05, 1.0, 0.25, 0.5, 1.5, Color::new(1.0, 0.8, 0.3, 1.0), Matrix4x4::identity());
+
+    assert_eq!(phong.shading_model.name(), "phong");
+    assert_eq!(phong.shading_model.get_base_type(), ShadingModelType::Phong);
+
+    assert_eq!(physically_based.shading_model.name(), "metallic_roughness");
+    assert_eq!(physically_based.shading_model.get_base_type(), ShadingModelType::MetallicRoughness);
+    assert_eq!(physically_based.ambient, 0.05);
+    assert_eq!(physically_based.metallic, 1.0);
+    assert_eq!(physically_based.roughness, 0.25);
+    assert_eq!(physically_based.transparency, 0.5);
+    assert_eq!(physically_based.refractive_index, 1.5);
+    assert!(physically_based.color_1 == Color::new(1.0, 0.8, 0.3, 1.0));
+  }
+
+  #[test]
+  fn only_emissive_materials_emit_light() {
+    let solid = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let emissive = Material::emissive(Color::new(5.0, 4.0, 3.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+
+    assert!(!solid.emits_light());
+    assert!(solid.emission == Color::new(0.0, 0.0, 0.0, 1.0));
+
+    assert!(emissive.emits_light());
+    assert!(emissive.emission == Color::new(5.0, 4.0, 3.0, 1.0));
+  }
 }
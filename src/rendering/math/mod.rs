@@ -23,3 +23,9 @@ pub use self::matrix3x3::Matrix3x3;
 pub mod matrix4x4;
 pub mod matrix4x4_tests;
 pub use self::matrix4x4::Matrix4x4;
+
+pub mod polynomial;
+pub mod polynomial_tests;
+
+pub mod noise;
+pub mod noise_tests;
@@ -0,0 +1,53 @@
+// Smooth pseudo random value between zero and one, repeatable for same position and seed
+pub fn value_noise(x: f64, y: f64, seed: u64) -> f64 {
+  let cell_x = x.floor();
+  let cell_y = y.floor();
+
+  // Smoothstep weights keep slope continuous across lattice cells
+  let fade = |value: f64| value * value * (3.0 - 2.0 * value);
+  let weight_x = fade(x - cell_x);
+  let weight_y = fade(y - cell_y);
+
+  let (cell_x, cell_y) = (cell_x as i64, cell_y as i64);
+
+  let bottom = lattice_value(cell_x, cell_y, seed) * (1.0 - weight_x) + lattice_value(cell_x + 1, cell_y, seed) * weight_x;
+  let top = lattice_value(cell_x, cell_y + 1, seed) * (1.0 - weight_x) + lattice_value(cell_x + 1, cell_y + 1, seed) * weight_x;
+
+  bottom * (1.0 - weight_y) + top * weight_y
+}
+
+// Octaves of value noise, each at double frequency and half amplitude of the last, between zero and one
+pub fn fractal_noise(x: f64, y: f64, octaves: usize, seed: u64) -> f64 {
+  let mut total = 0.0;
+  let mut total_amplitude = 0.0;
+
+  let mut frequency = 1.0;
+  let mut amplitude = 1.0;
+
+  for octave in 0..octaves.max(1) {
+    total += value_noise(x * frequency, y * frequency, seed.wrapping_add(octave as u64)) * amplitude;
+    total_amplitude += amplitude;
+
+    frequency *= 2.0;
+    amplitude *= 0.5;
+  }
+
+  total / total_amplitude
+}
+
+// Value between zero and one at an integer lattice point, from a hash of its coordinates
+pub fn lattice_value(x: i64, y: i64, seed: u64) -> f64 {
+  let mut hash = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+  hash ^= (x as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  hash = hash.rotate_left(31);
+  hash ^= (y as u64).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+  // Final mix spreads every input bit over result
+  hash ^= hash >> 30;
+  hash = hash.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  hash ^= hash >> 27;
+  hash = hash.wrapping_mul(0x94D0_49BB_1331_11EB);
+  hash ^= hash >> 31;
+
+  (hash >> 11) as f64 / (1u64 << 53) as f64
+}
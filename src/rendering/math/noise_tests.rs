@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::noise;
+
+  #[test]
+  fn noise_is_repeatable_and_in_unit_range() {
+    for i in 0..100 {
+      let x = i as f64 * 0.37 - 10.0;
+      let y = i as f64 * 0.71 + 3.0;
+
+      let value = noise::value_noise(x, y, 7);
+
+      assert!((0.0..=1.0).contains(&value));
+      assert!(value == noise::value_noise(x, y, 7));
+    }
+  }
+
+  #[test]
+  fn noise_matches_lattice_values_at_integer_points() {
+    assert!((noise::value_noise(3.0, -2.0, 1) - noise::lattice_value(3, -2, 1)).abs() < 0.0000001);
+    assert!((noise::value_noise(0.0, 0.0, 1) - noise::lattice_value(0, 0, 1)).abs() < 0.0000001);
+  }
+
+  #[test]
+  fn noise_is_continuous_across_cells() {
+    let below = noise::value_noise(0.9999999, 0.5, 3);
+    let above = noise::value_noise(1.0000001, 0.5, 3);
+
+    assert!((below - above).abs() < 0.0001);
+  }
+
+  #[test]
+  fn seeds_give_different_noise() {
+    let matching = (0..20).filter(|i| noise::lattice_value(*i, 0, 1) == noise::lattice_value(*i, 0, 2)).count();
+
+    assert_eq!(matching, 0);
+  }
+
+  #[test]
+  fn fractal_noise_stays_in_unit_range() {
+    for i in 0..100 {
+      let value = noise::fractal_noise(i as f64 * 0.13, i as f64 * 0.29, 5, 11);
+
+      assert!((0.0..=1.0).contains(&value));
+    }
+
+    // Single octave is plain value noise
+    assert!(noise::fractal_noise(1.3, 2.7, 1, 5) == noise::value_noise(1.3, 2.7, 5));
+  }
+}
@@ -0,0 +1,159 @@
+use std::f64;
+
+// Coefficients this close to zero are treated as zero
+const COEFFICIENT_EPSILON: f64 = 1e-12;
+
+// Newton steps applied to each closed form root, removing error built up by cancellation
+const POLISH_ITERATIONS: usize = 4;
+
+// Real roots of a x^2 + b x + c, in ascending order
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+  if a.abs() < COEFFICIENT_EPSILON {
+    if b.abs() < COEFFICIENT_EPSILON {
+      return Vec::new();
+    }
+
+    return vec![-c / b];
+  }
+
+  let discriminant = b * b - 4.0 * a * c;
+
+  if discriminant < 0.0 {
+    return Vec::new();
+  }
+
+  // Avoids subtracting nearly equal values, which loses precision of smaller root
+  let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+
+  let mut roots = if q == 0.0 {
+    vec![0.0, 0.0]
+  } else {
+    vec![q / a, c / q]
+  };
+
+  roots.sort_by(|root_a, root_b| root_a.total_cmp(root_b));
+
+  roots
+}
+
+// Real roots of a x^3 + b x^2 + c x + d, in ascending order
+pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+  if a.abs() < COEFFICIENT_EPSILON {
+    return solve_quadratic(b, c, d);
+  }
+
+  let (b, c, d) = (b / a, c / a, d / a);
+
+  // Substituting x = t - b / 3 removes squared term
+  let p = c - b * b / 3.0;
+  let q = 2.0 * b * b * b / 27.0 - b * c / 3.0 + d;
+  let offset = -b / 3.0;
+
+  let discriminant = (q / 2.0) * (q / 2.0) + (p / 3.0) * (p / 3.0) * (p / 3.0);
+
+  let mut roots = if p.abs() < COEFFICIENT_EPSILON {
+    vec![(-q).cbrt() + offset]
+  } else if discriminant > 0.0 {
+    let discriminant_root = discriminant.sqrt();
+
+    vec![(-q / 2.0 + discriminant_root).cbrt() + (-q / 2.0 - discriminant_root).cbrt() + offset]
+  } else {
+    // Three real roots, found with trigonometric method
+    let magnitude = 2.0 * (-p / 3.0).sqrt();
+    let angle = ((3.0 * q / (2.0 * p)) * (-3.0 / p).sqrt()).clamp(-1.0, 1.0).acos() / 3.0;
+
+    (0..3).map(|k| magnitude * (angle - 2.0 * f64::consts::PI * k as f64 / 3.0).cos() + offset).collect()
+  };
+
+  for root in &mut roots {
+    *root = polish_root(&[1.0, b, c, d], *root);
+  }
+
+  roots.sort_by(|root_a, root_b| root_a.total_cmp(root_b));
+
+  roots
+}
+
+// Real roots of a x^4 + b x^3 + c x^2 + d x + e, in ascending order
+pub fn solve_quartic(a: f64, b: f64, c: f64, d: f64, e: f64) -> Vec<f64> {
+  if a.abs() < COEFFICIENT_EPSILON {
+    return solve_cubic(b, c, d, e);
+  }
+
+  let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+
+  // Substituting x = y - b / 4 removes cubed term
+  let p = c - 3.0 * b * b / 8.0;
+  let q = d - b * c / 2.0 + b * b * b / 8.0;
+  let r = e - b * d / 4.0 + b * b * c / 16.0 - 3.0 * b * b * b * b / 256.0;
+  let offset = -b / 4.0;
+
+  let mut roots = Vec::new();
+
+  if q.abs() < COEFFICIENT_EPSILON {
+    // Quadratic in y^2
+    for square in solve_quadratic(1.0, p, r) {
+      if square >= 0.0 {
+        roots.push(square.sqrt() + offset);
+        roots.push(-square.sqrt() + offset);
+      }
+    }
+  } else {
+    // Largest root of resolvent cubic splits quartic into two quadratics, and is positive when q is not zero
+    let resolvent_roots = solve_cubic(1.0, p, p * p / 4.0 - r, -q * q / 8.0);
+    let m = resolvent_roots[resolvent_roots.len() - 1];
+
+    if m <= 0.0 {
+      return Vec::new();
+    }
+
+    let s = (2.0 * m).sqrt();
+
+    for root in solve_quadratic(1.0, -s, p / 2.0 + m + q / (2.0 * s)) {
+      roots.push(root + offset);
+    }
+
+    for root in solve_quadratic(1.0, s, p / 2.0 + m - q / (2.0 * s)) {
+      roots.push(root + offset);
+    }
+  }
+
+  for root in &mut roots {
+    *root = polish_root(&[1.0, b, c, d, e], *root);
+  }
+
+  roots.sort_by(|root_a, root_b| root_a.total_cmp(root_b));
+
+  roots
+}
+
+// Coefficients ordered from highest power, evaluated with Horner's method
+pub fn evaluate(coefficients: &[f64], x: f64) -> f64 {
+  coefficients.iter().fold(0.0, |value, coefficient| value * x + coefficient)
+}
+
+pub fn polish_root(coefficients: &[f64], root: f64) -> f64 {
+  let degree = coefficients.len() - 1;
+  let derivative: Vec<f64> = coefficients[..degree].iter().enumerate().map(|(index, coefficient)| coefficient * (degree - index) as f64).collect();
+
+  let mut root = root;
+
+  for _ in 0..POLISH_ITERATIONS {
+    let value = evaluate(coefficients, root);
+    let slope = evaluate(&derivative, root);
+
+    if value == 0.0 || slope.abs() < COEFFICIENT_EPSILON {
+      break;
+    }
+
+    // Steps are only kept while they bring value closer to zero, as repeated roots converge slowly
+    let polished = root - value / slope;
+    if evaluate(coefficients, polished).abs() >= value.abs() {
+      break;
+    }
+
+    root = polished;
+  }
+
+  root
+}
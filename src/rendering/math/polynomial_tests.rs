@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::polynomial;
+
+  fn roots_match(roots: &[f64], expected: &[f64]) -> bool {
+    roots.len() == expected.len() && roots.iter().zip(expected.iter()).all(|(root, expected_root)| (root - expected_root).abs() < 0.000001)
+  }
+
+  #[test]
+  fn solves_quadratic_equations() {
+    assert!(roots_match(&polynomial::solve_quadratic(1.0, -3.0, 2.0), &[1.0, 2.0]));
+    assert!(roots_match(&polynomial::solve_quadratic(2.0, 0.0, -8.0), &[-2.0, 2.0]));
+    assert!(roots_match(&polynomial::solve_quadratic(1.0, 2.0, 1.0), &[-1.0, -1.0]));
+    assert!(polynomial::solve_quadratic(1.0, 0.0, 1.0).is_empty());
+
+    // Linear when leading coefficient is zero
+    assert!(roots_match(&polynomial::solve_quadratic(0.0, 2.0, -4.0), &[2.0]));
+  }
+
+  #[test]
+  fn quadratic_keeps_precision_of_small_root() {
+    let roots = polynomial::solve_quadratic(1.0, -100000000.0, 1.0);
+
+    assert!((roots[0] - 0.00000001).abs() < 1e-20);
+    assert!((roots[1] - 100000000.0).abs() < 0.000001);
+  }
+
+  #[test]
+  fn solves_cubic_equations() {
+    // (x - 1)(x - 2)(x - 3)
+    assert!(roots_match(&polynomial::solve_cubic(1.0, -6.0, 11.0, -6.0), &[1.0, 2.0, 3.0]));
+
+    // (x - 2)(x^2 + 1)
+    assert!(roots_match(&polynomial::solve_cubic(1.0, -2.0, 1.0, -2.0), &[2.0]));
+
+    // 2 (x + 1)^3
+    assert!(roots_match(&polynomial::solve_cubic(2.0, 6.0, 6.0, 2.0), &[-1.0]));
+  }
+
+  #[test]
+  fn solves_quartic_equations() {
+    // (x - 1)(x - 2)(x - 3)(x - 4)
+    assert!(roots_match(&polynomial::solve_quartic(1.0, -10.0, 35.0, -50.0, 24.0), &[1.0, 2.0, 3.0, 4.0]));
+
+    // (x^2 - 4)(x^2 - 9), with no odd terms
+    assert!(roots_match(&polynomial::solve_quartic(1.0, 0.0, -13.0, 0.0, 36.0), &[-3.0, -2.0, 2.0, 3.0]));
+
+    // (x - 1)(x + 2)(x^2 + 1)
+    assert!(roots_match(&polynomial::solve_quartic(1.0, 1.0, -1.0, 1.0, -2.0), &[-2.0, 1.0]));
+
+    // (x^2 + 1)(x^2 + 4)
+    assert!(polynomial::solve_quartic(1.0, 0.0, 5.0, 0.0, 4.0).is_empty());
+
+    // Cubic when leading coefficient is zero
+    assert!(roots_match(&polynomial::solve_quartic(0.0, 1.0, -6.0, 11.0, -6.0), &[1.0, 2.0, 3.0]));
+  }
+
+  #[test]
+  fn quartic_roots_are_accurate_for_widely_spaced_roots() {
+    // (x - 0.001)(x - 1)(x - 50)(x - 1000)
+    let roots = polynomial::solve_quartic(1.0, -1051.001, 51051.051, -50051.05, 50.0);
+
+    assert_eq!(roots.len(), 4);
+    assert!((roots[0] - 0.001).abs() < 1e-9);
+    assert!((roots[1] - 1.0).abs() < 1e-9);
+    assert!((roots[2] - 50.0).abs() < 1e-9);
+    assert!((roots[3] - 1000.0).abs() < 1e-9);
+  }
+
+  #[test]
+  fn evaluates_polynomials() {
+    assert_eq!(polynomial::evaluate(&[2.0, -3.0, 1.0], 2.0), 3.0);
+    assert_eq!(polynomial::evaluate(&[1.0, 0.0, 0.0, 0.0, -16.0], 2.0), 0.0);
+  }
+
+  #[test]
+  fn polishing_moves_root_closer() {
+    let coefficients = [1.0, 0.0, -2.0];
+    let root = polynomial::polish_root(&coefficients, 1.4);
+
+    assert!((root - 2.0_f64.sqrt()).abs() < 1e-12);
+  }
+}
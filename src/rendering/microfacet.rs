@@ -0,0 +1,81 @@
+use std::f64;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Vector;
+use crate::rendering::math::Color;
+
+// Reflectance of dielectrics seen straight on, shared by most non-metals
+pub const DIELECTRIC_REFLECTANCE: f64 = 0.04;
+
+// Perfectly smooth surfaces would reflect point lights from a single point, so roughness is kept above this
+const MIN_ROUGHNESS: f64 = 0.03;
+
+// Share of microfacets facing along half vector, GGX distribution with alpha as square of roughness
+pub fn ggx_distribution(normal_dot_half: f64, roughness: f64) -> f64 {
+  let alpha = alpha_for_roughness(roughness);
+  let alpha_squared = alpha * alpha;
+
+  let denominator = normal_dot_half * normal_dot_half * (alpha_squared - 1.0) + 1.0;
+
+  alpha_squared / (f64::consts::PI * denominator * denominator)
+}
+
+// Share of microfacets neither hidden from light nor from eye, Smith form of GGX
+pub fn smith_geometry(normal_dot_light: f64, normal_dot_eye: f64, roughness: f64) -> f64 {
+  let alpha = alpha_for_roughness(roughness);
+  let alpha_squared = alpha * alpha;
+
+  let masking = |cos: f64| 2.0 * cos / (cos + (alpha_squared + (1.0 - alpha_squared) * cos * cos).sqrt());
+
+  masking(normal_dot_light) * masking(normal_dot_eye)
+}
+
+// Schlick approximation of reflectance, rising from that seen straight on to full at a grazing angle
+pub fn schlick_fresnel(reflectance: &Color, cos: f64) -> Color {
+  let sub_cos = (1.0 - cos).clamp(0.0, 1.0);
+  let factor = sub_cos * sub_cos * sub_cos * sub_cos * sub_cos;
+
+  let white = Color::new(1.0, 1.0, 1.0, 1.0);
+
+  reflectance.add_color(&white.subtract_color(reflectance).mult_scalar(factor))
+}
+
+// Metals tint reflections with their own color, dielectrics reflect a little of every color
+pub fn base_reflectance(base_color: &Color, metallic: f64) -> Color {
+  let dielectric = Color::new(DIELECTRIC_REFLECTANCE, DIELECTRIC_REFLECTANCE, DIELECTRIC_REFLECTANCE, 1.0);
+
+  dielectric.mult_scalar(1.0 - metallic).add_color(&base_color.mult_scalar(metallic))
+}
+
+// Cook-Torrance reflection of light arriving along light_v, scaled by cosine of light and normal
+pub fn cook_torrance(base_color: &Color, metallic: f64, roughness: f64, normal: &Vector, eye_v: &Vector, light_v: &Vector) -> Color {
+  let normal_dot_light = normal.dot(light_v);
+  let normal_dot_eye = normal.dot(eye_v);
+
+  if normal_dot_light <= 0.0 || normal_dot_eye <= 0.0 {
+    return Color::new(0.0, 0.0, 0.0, 1.0);
+  }
+
+  let half_v = light_v.add_vector(eye_v).normalize();
+  let normal_dot_half = normal.dot(&half_v).max(0.0);
+  let eye_dot_half = eye_v.dot(&half_v).max(0.0);
+
+  let fresnel = schlick_fresnel(&base_reflectance(base_color, metallic), eye_dot_half);
+
+  let distribution = ggx_distribution(normal_dot_half, roughness);
+  let geometry = smith_geometry(normal_dot_light, normal_dot_eye, roughness);
+
+  let specular = fresnel.mult_scalar(distribution * geometry / (4.0 * normal_dot_light * normal_dot_eye));
+
+  // Light not reflected by surface is scattered, apart from by metals which absorb it
+  let white = Color::new(1.0, 1.0, 1.0, 1.0);
+  let diffuse = white.subtract_color(&fresnel).mult_color(base_color).mult_scalar((1.0 - metallic) / f64::consts::PI);
+
+  diffuse.add_color(&specular).mult_scalar(normal_dot_light)
+}
+
+fn alpha_for_roughness(roughness: f64) -> f64 {
+  let roughness = roughness.clamp(MIN_ROUGHNESS, 1.0);
+
+  roughness * roughness
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::Vector;
+  use crate::rendering::math::Color;
+
+  use crate::rendering::microfacet::ggx_distribution;
+  use crate::rendering::microfacet::smith_geometry;
+  use crate::rendering::microfacet::schlick_fresnel;
+  use crate::rendering::microfacet::base_reflectance;
+  use crate::rendering::microfacet::cook_torrance;
+
+  fn close(color: &Color, r: f64, g: f64, b: f64) -> bool {
+    (color.r - r).abs() < 0.0001 && (color.g - g).abs() < 0.0001 && (color.b - b).abs() < 0.0001
+  }
+
+  #[test]
+  fn ggx_distribution_peaks_along_normal_for_smooth_surfaces() {
+    assert!((ggx_distribution(1.0, 1.0) - 1.0 / f64::consts::PI).abs() < 0.0001);
+    assert!((ggx_distribution(1.0, 0.5) - 16.0 / f64::consts::PI).abs() < 0.0001);
+
+    assert!(ggx_distribution(1.0, 0.2) > ggx_distribution(0.9, 0.2));
+    assert!(ggx_distribution(0.9, 0.2) < ggx_distribution(0.9, 0.8));
+  }
+
+  #[test]
+  fn smith_geometry_hides_microfacets_at_grazing_angles() {
+    assert!((smith_geometry(1.0, 1.0, 0.5) - 1.0).abs() < 0.0001);
+    assert!(smith_geometry(0.1, 1.0, 0.5) < smith_geometry(0.5, 1.0, 0.5));
+    assert!(smith_geometry(0.1, 1.0, 0.9) < smith_geometry(0.1, 1.0, 0.2));
+    assert!(smith_geometry(0.0, 1.0, 0.5) == 0.0);
+  }
+
+  #[test]
+  fn schlick_fresnel_rises_to_full_reflectance_at_grazing_angle() {
+    let reflectance = Color::new(0.04, 0.5, 1.0, 1.0);
+
+    assert!(close(&schlick_fresnel(&reflectance, 1.0), 0.04, 0.5, 1.0));
+    assert!(close(&schlick_fresnel(&reflectance, 0.0), 1.0, 1.0, 1.0));
+    assert!(close(&schlick_fresnel(&reflectance, 0.5), 0.04 + 0.96 / 32.0, 0.5 + 0.5 / 32.0, 1.0));
+  }
+
+  #[test]
+  fn base_reflectance_blends_from_dielectric_to_metal_color() {
+    let gold = Color::new(1.0, 0.8, 0.3, 1.0);
+
+    assert!(close(&base_reflectance(&gold, 0.0), 0.04, 0.04, 0.04));
+    assert!(close(&base_reflectance(&gold, 1.0), 1.0, 0.8, 0.3));
+    assert!(close(&base_reflectance(&gold, 0.5), 0.52, 0.42, 0.17));
+  }
+
+  #[test]
+  fn cook_torrance_of_rough_dielectric_seen_and_lit_along_normal() {
+    let normal = Vector::new(0.0, 0.0, -1.0);
+
+    let reflected = cook_torrance(&Color::new(1.0, 1.0, 1.0, 1.0), 0.0, 1.0, &normal, &normal, &normal);
+
+    // Scattered light is what is not reflected, and reflection is distribution times reflectance over four
+    let expected = 0.96 / f64::consts::PI + 0.04 / (4.0 * f64::consts::PI);
+    assert!(close(&reflected, expected, expected, expected));
+  }
+
+  #[test]
+  fn cook_torrance_of_metal_has_no_diffuse_and_is_tinted() {
+    let normal = Vector::new(0.0, 0.0, -1.0);
+
+    let reflected = cook_torrance(&Color::new(1.0, 0.5, 0.0, 1.0), 1.0, 1.0, &normal, &normal, &normal);
+
+    let expected = 1.0 / (4.0 * f64::consts::PI);
+    assert!(close(&reflected, expected, expected * 0.5, 0.0));
+  }
+
+  #[test]
+  fn cook_torrance_is_dark_when_light_or_eye_is_behind_surface() {
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let behind = Vector::new(0.0, 0.0, 1.0);
+    let color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    assert!(close(&cook_torrance(&color, 0.0, 0.5, &normal, &normal, &behind), 0.0, 0.0, 0.0));
+    assert!(close(&cook_torrance(&color, 0.0, 0.5, &normal, &behind, &normal), 0.0, 0.0, 0.0));
+  }
+
+  #[test]
+  fn smoother_surfaces_concentrate_highlight_around_mirror_direction() {
+    let normal = Vector::new(0.0, 1.0, 0.0);
+    let eye_v = Vector::new(0.0, 1.0, -1.0).normalize();
+    let mirror_light_v = Vector::new(0.0, 1.0, 1.0).normalize();
+    let off_mirror_light_v = Vector::new(0.0, 1.0, 0.3).normalize();
+    let color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    let smooth_highlight = cook_torrance(&color, 1.0, 0.1, &normal, &eye_v, &mirror_light_v);
+    let rough_highlight = cook_torrance(&color, 1.0, 0.8, &normal, &eye_v, &mirror_light_v);
+    let smooth_off_highlight = cook_torrance(&color, 1.0, 0.1, &normal, &eye_v, &off_mirror_light_v);
+
+    assert!(smooth_highlight.r > rough_highlight.r);
+    assert!(smooth_off_highlight.r < rough_highlight.r);
+  }
+}
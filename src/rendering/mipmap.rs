@@ -0,0 +1,240 @@
+use crate::rendering::math::Color;
+
+use crate::rendering::Canvas;
+
+// Footprints longer than this many times their width are widened, to bound the cost of a lookup
+const MAX_ANISOTROPY: f64 = 8.0;
+
+// Sharpness of gaussian weighting texels inside ellipse
+const EWA_FALLOFF: f64 = 2.0;
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum TextureFilter {
+  // Average of pixels under box around footprint
+  Box,
+  // Bilinear samples of the two nearest levels, blended by size of footprint
+  Trilinear,
+  // Gaussian weighted average of texels under elliptical footprint
+  Ewa
+}
+
+// Image repeatedly halved in size down to a single pixel, first level is a copy of the image
+pub struct Mipmap {
+  pub levels: Vec<Canvas>
+}
+
+impl Mipmap {
+  pub fn new(image: &Canvas) -> Mipmap {
+    let mut first_level = Canvas::new(image.width, image.height);
+    first_level.color_buffer = image.color_buffer.clone();
+
+    let mut levels = vec![first_level];
+
+    loop {
+      let last_level = levels.last().unwrap();
+      if last_level.width <= 1 && last_level.height <= 1 {
+        break;
+      }
+
+      let next_level = Mipmap::downsample(last_level);
+      levels.push(next_level);
+    }
+
+    Mipmap { levels }
+  }
+
+  // Each pixel averages the up to four pixels it covers, odd sizes round up
+  pub fn downsample(image: &Canvas) -> Canvas {
+    let width = image.width.div_ceil(2).max(1);
+    let height = image.height.div_ceil(2).max(1);
+
+    let mut downsampled = Canvas::new(width, height);
+
+    for row in 0..height {
+      for column in 0..width {
+        let mut color = Color::new(0.0, 0.0, 0.0, 0.0);
+        let mut count = 0.0;
+
+        for source_row in (row * 2)..(row * 2 + 2).min(image.height) {
+          for source_column in (column * 2)..(column * 2 + 2).min(image.width) {
+            color = color.add_color(image.pixel_color(source_row, source_column));
+            count += 1.0;
+          }
+        }
+
+        downsampled.color_pixel(row, column, color.mult_scalar(1.0 / count));
+      }
+    }
+
+    downsampled
+  }
+
+  // Pixel of level, image repeats outside of its bounds
+  pub fn texel(&self, level: usize, column: i64, row: i64) -> Color {
+    let image = &self.levels[level];
+
+    let wrapped_column = column.rem_euclid(image.width as i64) as u64;
+    let wrapped_row = row.rem_euclid(image.height as i64) as u64;
+
+    *image.pixel_color(wrapped_row, wrapped_column)
+  }
+
+  // Position in pixels of level for texture coordinates, with pixel centers on whole numbers
+  fn texel_position(&self, level: usize, u: f64, v: f64) -> (f64, f64) {
+    let image = &self.levels[level];
+
+    // v runs from bottom to top of the image
+    (u * (image.width as f64) - 0.5, (1.0 - v) * (image.height as f64) - 0.5)
+  }
+
+  pub fn bilinear(&self, level: usize, u: f64, v: f64) -> Color {
+    let (x, y) = self.texel_position(level, u, v);
+
+    let column = x.floor();
+    let row = y.floor();
+    let x_fraction = x - column;
+    let y_fraction = y - row;
+
+    let column = column as i64;
+    let row = row as i64;
+
+    let top = self.texel(level, column, row).mult_scalar(1.0 - x_fraction).add_color(&self.texel(level, column + 1, row).mult_scalar(x_fraction));
+    let bottom = self.texel(level, column, row + 1).mult_scalar(1.0 - x_fraction).add_color(&self.texel(level, column + 1, row + 1).mult_scalar(x_fraction));
+
+    top.mult_scalar(1.0 - y_fraction).add_color(&bottom.mult_scalar(y_fraction))
+  }
+
+  // Level whose pixels are about as wide as footprint, fractional between levels
+  pub fn level_for_width(&self, width: f64) -> f64 {
+    let last_level = (self.levels.len() - 1) as f64;
+
+    if width <= 1.0 {
+      return 0.0;
+    }
+
+    width.log2().min(last_level)
+  }
+
+  pub fn trilinear(&self, u: f64, v: f64, texture_dx: (f64, f64), texture_dy: (f64, f64)) -> Color {
+    let (axis_0, axis_1) = self.texel_axes(texture_dx, texture_dy);
+    let width = magnitude(axis_0).max(magnitude(axis_1));
+
+    let level = self.level_for_width(width);
+    let lower_level = level.floor() as usize;
+
+    if lower_level + 1 >= self.levels.len() {
+      return self.bilinear(lower_level, u, v);
+    }
+
+    let blend = level - level.floor();
+
+    self.bilinear(lower_level, u, v).mult_scalar(1.0 - blend).add_color(&self.bilinear(lower_level + 1, u, v).mult_scalar(blend))
+  }
+
+  pub fn ewa(&self, u: f64, v: f64, texture_dx: (f64, f64), texture_dy: (f64, f64)) -> Color {
+    let (mut major_axis, mut minor_axis) = self.texel_axes(texture_dx, texture_dy);
+
+    if magnitude(minor_axis) > magnitude(major_axis) {
+      std::mem::swap(&mut major_axis, &mut minor_axis);
+    }
+
+    let major_length = magnitude(major_axis);
+    let mut minor_length = magnitude(minor_axis);
+
+    if major_length == 0.0 {
+      return self.bilinear(0, u, v);
+    }
+
+    // Very thin footprints are widened, a zero width one is widened across major axis
+    if minor_length * MAX_ANISOTROPY < major_length {
+      let scale = major_length / MAX_ANISOTROPY;
+
+      minor_axis = if minor_length > 0.0 {
+        (minor_axis.0 * scale / minor_length, minor_axis.1 * scale / minor_length)
+      } else {
+        (-major_axis.1 / MAX_ANISOTROPY, major_axis.0 / MAX_ANISOTROPY)
+      };
+      minor_length = scale;
+    }
+
+    // Level is chosen so minor axis covers a few pixels
+    let level = self.level_for_width(minor_length);
+    let lower_level = level.floor() as usize;
+
+    if lower_level + 1 >= self.levels.len() {
+      return self.texel(lower_level, 0, 0);
+    }
+
+    let blend = level - level.floor();
+
+    let lower_color = self.ewa_at_level(lower_level, u, v, major_axis, minor_axis);
+    let upper_color = self.ewa_at_level(lower_level + 1, u, v, major_axis, minor_axis);
+
+    lower_color.mult_scalar(1.0 - blend).add_color(&upper_color.mult_scalar(blend))
+  }
+
+  // Axes are in pixels of first level
+  fn ewa_at_level(&self, level: usize, u: f64, v: f64, axis_0: (f64, f64), axis_1: (f64, f64)) -> Color {
+    let image = &self.levels[level];
+    let x_scale = (image.width as f64) / (self.levels[0].width as f64);
+    let y_scale = (image.height as f64) / (self.levels[0].height as f64);
+
+    let (x, y) = self.texel_position(level, u, v);
+
+    let axis_0 = (axis_0.0 * x_scale, axis_0.1 * y_scale);
+    let axis_1 = (axis_1.0 * x_scale, axis_1.1 * y_scale);
+
+    // Implicit ellipse a x^2 + b x y + c y^2 < 1, padded by a pixel so it always covers one
+    let mut a = axis_0.1 * axis_0.1 + axis_1.1 * axis_1.1 + 1.0;
+    let mut b = -2.0 * (axis_0.0 * axis_0.1 + axis_1.0 * axis_1.1);
+    let mut c = axis_0.0 * axis_0.0 + axis_1.0 * axis_1.0 + 1.0;
+
+    let inverse_f = 1.0 / (a * c - b * b * 0.25);
+    a *= inverse_f;
+    b *= inverse_f;
+    c *= inverse_f;
+
+    // Bounding box of ellipse
+    let determinant = -b * b + 4.0 * a * c;
+    let inverse_determinant = 1.0 / determinant;
+    let x_extent = 2.0 * inverse_determinant * (determinant * c).sqrt();
+    let y_extent = 2.0 * inverse_determinant * (determinant * a).sqrt();
+
+    let mut color = Color::new(0.0, 0.0, 0.0, 0.0);
+    let mut total_weight = 0.0;
+
+    for row in ((y - y_extent).ceil() as i64)..=((y + y_extent).floor() as i64) {
+      let y_offset = (row as f64) - y;
+
+      for column in ((x - x_extent).ceil() as i64)..=((x + x_extent).floor() as i64) {
+        let x_offset = (column as f64) - x;
+
+        let radius_squared = a * x_offset * x_offset + b * x_offset * y_offset + c * y_offset * y_offset;
+        if radius_squared < 1.0 {
+          let weight = (-EWA_FALLOFF * radius_squared).exp() - (-EWA_FALLOFF).exp();
+
+          color = color.add_color(&self.texel(level, column, row).mult_scalar(weight));
+          total_weight += weight;
+        }
+      }
+    }
+
+    if total_weight <= 0.0 {
+      return self.bilinear(level, u, v);
+    }
+
+    color.mult_scalar(1.0 / total_weight)
+  }
+
+  // Steps in texture coordinates as steps in pixels of first level, rows run down the image
+  fn texel_axes(&self, texture_dx: (f64, f64), texture_dy: (f64, f64)) -> ((f64, f64), (f64, f64)) {
+    let width = self.levels[0].width as f64;
+    let height = self.levels[0].height as f64;
+
+    ((texture_dx.0 * width, -texture_dx.1 * height), (texture_dy.0 * width, -texture_dy.1 * height))
+  }
+}
+
+fn magnitude(axis: (f64, f64)) -> f64 {
+  (axis.0 * axis.0 + axis.1 * axis.1).sqrt()
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::Color;
+
+  use crate::rendering::Canvas;
+  use crate::rendering::Mipmap;
+
+  fn checker_canvas(width: u64, height: u64) -> Canvas {
+    let mut canvas = Canvas::new(width, height);
+
+    for row in 0..height {
+      for column in 0..width {
+        if (row + column) % 2 == 0 {
+          canvas.color_pixel(row, column, Color::new(0.0, 0.0, 0.0, 1.0));
+        }
+      }
+    }
+
+    canvas
+  }
+
+  // White and black stripes running top to bottom, four pixels wide
+  fn striped_canvas(size: u64) -> Canvas {
+    let mut canvas = Canvas::new(size, size);
+
+    for row in 0..size {
+      for column in 0..size {
+        if (column / 4) % 2 == 1 {
+          canvas.color_pixel(row, column, Color::new(0.0, 0.0, 0.0, 1.0));
+        }
+      }
+    }
+
+    canvas
+  }
+
+  #[test]
+  fn mipmap_halves_image_down_to_single_pixel() {
+    let canvas = checker_canvas(5, 3);
+    let mipmap = Mipmap::new(&canvas);
+
+    assert_eq!(mipmap.levels.len(), 4);
+    assert_eq!((mipmap.levels[0].width, mipmap.levels[0].height), (5, 3));
+    assert_eq!((mipmap.levels[1].width, mipmap.levels[1].height), (3, 2));
+    assert_eq!((mipmap.levels[2].width, mipmap.levels[2].height), (2, 1));
+    assert_eq!((mipmap.levels[3].width, mipmap.levels[3].height), (1, 1));
+
+    assert!(mipmap.levels[0].color_buffer == canvas.color_buffer);
+  }
+
+  #[test]
+  fn downsampling_averages_covered_pixels() {
+    let canvas = checker_canvas(3, 2);
+    let downsampled = Mipmap::downsample(&canvas);
+
+    assert_eq!((downsampled.width, downsampled.height), (2, 1));
+    assert!(*downsampled.pixel_color(0, 0) == Color::new(0.5, 0.5, 0.5, 1.0));
+
+    // Last column of odd width image only covers one column
+    assert!(*downsampled.pixel_color(0, 1) == Color::new(0.5, 0.5, 0.5, 1.0));
+
+    let mipmap = Mipmap::new(&checker_canvas(4, 4));
+
+    assert!(*mipmap.levels[2].pixel_color(0, 0) == Color::new(0.5, 0.5, 0.5, 1.0));
+  }
+
+  #[test]
+  fn canvas_builds_mipmap_once_and_rebuilds_after_change() {
+    let mut canvas = checker_canvas(4, 4);
+
+    let first = canvas.mipmap() as *const Mipmap;
+    let second = canvas.mipmap() as *const Mipmap;
+
+    assert!(first == second);
+
+    canvas.color_pixel(0, 0, Color::new(1.0, 0.0, 0.0, 1.0));
+
+    assert!(canvas.mipmap.get().is_none());
+    assert!(*canvas.mipmap().levels[0].pixel_color(0, 0) == Color::new(1.0, 0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn bilinear_lookup_blends_neighbouring_pixels() {
+    let mipmap = Mipmap::new(&checker_canvas(4, 4));
+
+    // Pixel centers sit half a pixel in from edges, v runs from bottom
+    assert!(mipmap.bilinear(0, 0.125, 0.875) == Color::new(0.0, 0.0, 0.0, 1.0));
+    assert!(mipmap.bilinear(0, 0.375, 0.875) == Color::new(1.0, 1.0, 1.0, 1.0));
+
+    let between = mipmap.bilinear(0, 0.25, 0.875);
+
+    assert!((between.r - 0.5).abs() < 0.0001);
+
+    // Image repeats past its edges
+    assert!(mipmap.bilinear(0, 1.125, -0.125) == Color::new(0.0, 0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn trilinear_lookup_chooses_level_by_footprint() {
+    let mipmap = Mipmap::new(&checker_canvas(16, 16));
+
+    let sharp = mipmap.trilinear(0.5 / 16.0, 1.0 - 0.5 / 16.0, (0.01, 0.0), (0.0, 0.01));
+    let blurred = mipmap.trilinear(0.5 / 16.0, 1.0 - 0.5 / 16.0, (0.5, 0.0), (0.0, 0.5));
+
+    assert!(sharp == Color::new(0.0, 0.0, 0.0, 1.0));
+    assert!((blurred.r - 0.5).abs() < 0.0001);
+
+    assert_eq!(mipmap.level_for_width(0.5), 0.0);
+    assert_eq!(mipmap.level_for_width(4.0), 2.0);
+    assert_eq!(mipmap.level_for_width(1000.0), 4.0);
+  }
+
+  #[test]
+  fn ewa_lookup_averages_whole_image_for_large_footprint() {
+    let mipmap = Mipmap::new(&checker_canvas(16, 16));
+
+    let color = mipmap.ewa(0.3, 0.7, (2.0, 0.0), (0.0, 2.0));
+
+    assert!((color.r - 0.5).abs() < 0.0001);
+    assert!((color.a - 1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn ewa_lookup_keeps_detail_across_thin_footprint() {
+    let mipmap = Mipmap::new(&striped_canvas(16));
+
+    // Footprint is long along stripes and thin across them, centered on a white stripe
+    let u = 2.0 / 16.0;
+    let ewa_color = mipmap.ewa(u, 0.5, (0.01, 0.0), (0.0, 0.5));
+    let trilinear_color = mipmap.trilinear(u, 0.5, (0.01, 0.0), (0.0, 0.5));
+
+    assert!(ewa_color.r > 0.99);
+    assert!(trilinear_color.r < 0.8);
+  }
+}
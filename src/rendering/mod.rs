@@ -4,8 +4,14 @@ pub mod canvas;
 pub mod canvas_tests;
 pub use self::canvas::Canvas;
 
+pub mod mipmap;
+pub mod mipmap_tests;
+pub use self::mipmap::TextureFilter;
+pub use self::mipmap::Mipmap;
+
 pub mod scene;
 pub mod scene_tests;
+pub use self::scene::Integrator;
 pub use self::scene::Scene;
 
 pub mod camera;
@@ -18,19 +24,42 @@ pub use self::point_light::PointLight;
 
 pub mod shapes;
 
+pub mod distance_function;
+pub mod distance_function_tests;
+pub use self::distance_function::DistanceFunction;
+
 pub mod constructive_geometry;
 pub mod constructive_geometry_tests;
 pub use self::constructive_geometry::ConstructiveOperation;
+pub use self::constructive_geometry::ConstructiveMaterialMode;
 pub use self::constructive_geometry::ConstructiveGeometry;
 
 pub mod container;
 pub mod container_tests;
 pub use self::container::Container;
 
+pub mod instance;
+pub mod instance_tests;
+pub use self::instance::InstanceGeometry;
+pub use self::instance::Instance;
+
 pub mod ray;
 pub mod ray_tests;
 pub use self::ray::Ray;
 
+pub mod ray_differentials;
+pub mod ray_differentials_tests;
+pub use self::ray_differentials::RayDifferentials;
+pub use self::ray_differentials::SurfaceDifferentials;
+
+pub mod bounds;
+pub mod bounds_tests;
+pub use self::bounds::Bounds;
+
+pub mod tessellation;
+pub mod tessellation_tests;
+pub use self::tessellation::Tessellation;
+
 pub mod intersection;
 pub mod intersection_tests;
 pub use self::intersection::Intersection;
@@ -43,7 +72,74 @@ pub mod material;
 pub mod material_tests;
 pub use self::material::Material;
 
+pub mod microfacet;
+pub mod microfacet_tests;
+
+pub mod shading_model;
+pub mod shading_model_tests;
+pub use self::shading_model::LightSample;
+pub use self::shading_model::ShadingModel;
+pub use self::shading_model::ShadingModelType;
+pub use self::shading_model::Phong;
+pub use self::shading_model::BlinnPhong;
+pub use self::shading_model::Lambert;
+pub use self::shading_model::MetallicRoughness;
+
+pub mod geometry_light;
+pub mod geometry_light_tests;
+pub use self::geometry_light::SurfaceSample;
+pub use self::geometry_light::GeometryLight;
+
+pub mod path_tracer;
+pub mod path_tracer_tests;
+pub use self::path_tracer::PathTracer;
+
+pub mod bezier_patch;
+pub mod bezier_patch_tests;
+pub use self::bezier_patch::BezierPatch;
+
+pub mod normal_generator;
+pub mod normal_generator_tests;
+pub use self::normal_generator::NormalWeighting;
+pub use self::normal_generator::NormalGenerator;
+
 pub mod obj_file_parser;
 pub mod obj_file_parser_tests;
 pub use self::obj_file_parser::ObjLineType;
+pub use self::obj_file_parser::ObjError;
+pub use self::obj_file_parser::ObjFaceVertex;
+pub use self::obj_file_parser::ObjData;
 pub use self::obj_file_parser::ObjFileParser;
+
+pub mod obj_file_exporter;
+pub mod obj_file_exporter_tests;
+pub use self::obj_file_exporter::ObjFileExporter;
+
+pub mod mtl_file_parser;
+pub mod mtl_file_parser_tests;
+pub use self::mtl_file_parser::MtlLineType;
+pub use self::mtl_file_parser::MtlMaterial;
+pub use self::mtl_file_parser::MtlFileParser;
+
+pub mod ply_file_parser;
+pub mod ply_file_parser_tests;
+pub use self::ply_file_parser::PlyFormat;
+pub use self::ply_file_parser::PlyDataType;
+pub use self::ply_file_parser::PlyProperty;
+pub use self::ply_file_parser::PlyElement;
+pub use self::ply_file_parser::PlyHeader;
+pub use self::ply_file_parser::PlyMesh;
+pub use self::ply_file_parser::PlyError;
+pub use self::ply_file_parser::PlyValueReader;
+pub use self::ply_file_parser::PlyFileParser;
+
+pub mod stl_file_parser;
+pub mod stl_file_parser_tests;
+pub use self::stl_file_parser::StlFacet;
+pub use self::stl_file_parser::StlError;
+pub use self::stl_file_parser::StlFileParser;
+
+pub mod patch_file_parser;
+pub mod patch_file_parser_tests;
+pub use self::patch_file_parser::PatchError;
+pub use self::patch_file_parser::PatchFileParser;
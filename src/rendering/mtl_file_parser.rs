@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use std::path::Path;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::Canvas;
+
+use crate::rendering::Material;
+use crate::rendering::ShadingModelType;
+use crate::rendering::MetallicRoughness;
+
+use crate::rendering::ObjError;
+use crate::rendering::ObjFileParser;
+
+// Scene has no global ambient light, so ambient colors are scaled by a dim constant
+const AMBIENT_LIGHT_INTENSITY: f64 = 0.1;
+
+#[derive(PartialEq)]
+pub enum MtlLineType {
+  NewMaterial,
+  AmbientColor,
+  DiffuseColor,
+  SpecularColor,
+  EmissiveColor,
+  SpecularExponent,
+  Dissolve,
+  Transparency,
+  OpticalDensity,
+  Illumination,
+  DiffuseTexture,
+  NormalTexture,
+  Roughness,
+  Metallic,
+  Invalid
+}
+
+#[derive(Clone)]
+pub struct MtlMaterial {
+  pub name: String,
+  pub ambient_color: Color,
+  pub diffuse_color: Color,
+  pub specular_color: Color,
+  pub emissive_color: Color,
+  pub specular_exponent: f64,
+  pub dissolve: f64,
+  pub optical_density: f64,
+  pub illumination: u64,
+  pub diffuse_texture: Option<Rc<Canvas>>,
+  pub normal_texture: Option<Rc<Canvas>>,
+  pub roughness: Option<f64>,
+  pub metallic: Option<f64>
+}
+
+impl MtlMaterial {
+  pub fn new(name: &str) -> MtlMaterial {
+    MtlMaterial {
+      name: name.to_string(),
+      ambient_color: Color::new(1.0, 1.0, 1.0, 1.0),
+      diffuse_color: Color::new(0.8, 0.8, 0.8, 1.0),
+      specular_color: Color::new(0.0, 0.0, 0.0, 1.0),
+      emissive_color: Color::new(0.0, 0.0, 0.0, 1.0),
+      specular_exponent: 4.0,
+      dissolve: 1.0,
+      optical_density: 1.0,
+      illumination: 2,
+      diffuse_texture: None,
+      normal_texture: None,
+      roughness: None,
+      metallic: None
+    }
+  }
+
+  // Reverses to_material, patterns keep only their first color and textures are left out
+  pub fn from_material(name: &str, material: &Material) -> MtlMaterial {
+    let ambient = material.ambient / AMBIENT_LIGHT_INTENSITY;
+
+    let mut mtl_material = MtlMaterial::new(name);
+    mtl_material.ambient_color = Color::new(ambient, ambient, ambient, 1.0);
+    mtl_material.diffuse_color = Color::new(material.color_1.r, material.color_1.g, material.color_1.b, 1.0);
+    mtl_material.specular_color = Color::new(material.specular, material.specular, material.specular, 1.0);
+    mtl_material.emissive_color = Color::new(material.emission.r, material.emission.g, material.emission.b, 1.0);
+    mtl_material.specular_exponent = material.shininess;
+    mtl_material.dissolve = 1.0 - material.transparency;
+    mtl_material.optical_density = material.refractive_index;
+    mtl_material.illumination = if material.reflectiveness > 0.0 { 3 } else { 2 };
+
+    if material.shading_model.get_base_type() == ShadingModelType::MetallicRoughness {
+      mtl_material.roughness = Some(material.roughness);
+      mtl_material.metallic = Some(material.metallic);
+    }
+
+    mtl_material
+  }
+
+  pub fn mtl_lines(&self) -> Vec<String> {
+    let mut lines = vec![
+      format!("newmtl {}", self.name),
+      format!("Ka {} {} {}", self.ambient_color.r, self.ambient_color.g, self.ambient_color.b),
+      format!("Kd {} {} {}", self.diffuse_color.r, self.diffuse_color.g, self.diffuse_color.b),
+      format!("Ks {} {} {}", self.specular_color.r, self.specular_color.g, self.specular_color.b),
+      format!("Ns {}", self.specular_exponent),
+      format!("d {}", self.dissolve),
+      format!("Ni {}", self.optical_density),
+      format!("illum {}", self.illumination)
+    ];
+
+    if average_component(&self.emissive_color) > 0.0 {
+      lines.push(format!("Ke {} {} {}", self.emissive_color.r, self.emissive_color.g, self.emissive_color.b));
+    }
+    if let Some(roughness) = self.roughness {
+      lines.push(format!("Pr {}", roughness));
+    }
+    if let Some(metallic) = self.metallic {
+      lines.push(format!("Pm {}", metallic));
+    }
+
+    lines
+  }
+
+  pub fn to_material(&self) -> Material {
+    let mut ambient = AMBIENT_LIGHT_INTENSITY * average_component(&self.ambient_color);
+    let mut diffuse = 1.0;
+    let mut specular = average_component(&self.specular_color);
+    let mut reflectiveness = 0.0;
+
+    match self.illumination {
+      // Color on, no lighting
+      0 => {
+        ambient = 1.0;
+        diffuse = 0.0;
+        specular = 0.0;
+      },
+      // Lambertian only
+      1 => {
+        specular = 0.0;
+      },
+      // Highlights only
+      2 => {},
+      // Ray traced reflections, with or without glass and fresnel
+      _ => {
+        reflectiveness = average_component(&self.specular_color);
+      }
+    }
+
+    let mut material = Material::solid(
+      ambient,
+      diffuse,
+      specular,
+      self.specular_exponent,
+      reflectiveness,
+      1.0 - self.dissolve,
+      self.optical_density,
+      self.diffuse_color,
+      Matrix4x4::identity()
+    );
+
+    material.texture = self.diffuse_texture.clone();
+    material.normal_map = self.normal_texture.clone();
+    material.emission = self.emissive_color;
+
+    // Physically based extension replaces phong terms, with defaults for whichever value is missing
+    if self.roughness.is_some() || self.metallic.is_some() {
+      material.shading_model = Rc::new(MetallicRoughness);
+      material.roughness = self.roughness.unwrap_or(1.0);
+      material.metallic = self.metallic.unwrap_or(0.0);
+    }
+
+    material
+  }
+}
+
+pub struct MtlFileParser {}
+
+impl MtlFileParser {
+  pub fn load_file(file_name: &str) -> Result<HashMap<String, Material>, ObjError> {
+    let mtl_lines = ObjFileParser::read_lines(file_name)?;
+
+    let mut materials = HashMap::new();
+    for mtl_material in MtlFileParser::parse_materials(&mtl_lines, file_name)? {
+      materials.insert(mtl_material.name.clone(), mtl_material.to_material());
+    }
+
+    Ok(materials)
+  }
+
+  pub fn parse_materials(mtl_lines: &[String], file_name: &str) -> Result<Vec<MtlMaterial>, ObjError> {
+    let mut mtl_materials: Vec<MtlMaterial> = Vec::new();
+
+    // Texture paths are relative to material library
+    let texture_directory = Path::new(file_name).parent().unwrap_or_else(|| Path::new(""));
+
+    for (line_index, line) in mtl_lines.iter().enumerate() {
+      let mtl_line = ObjFileParser::strip_comment(line);
+      let line_type = MtlFileParser::mtl_line_type(mtl_line);
+
+      if line_type == MtlLineType::NewMaterial {
+        mtl_materials.push(MtlMaterial::new(mtl_line.trim()["newmtl".len()..].trim()));
+        continue;
+      }
+
+      // Statements before first newmtl have no material to apply to
+      let mtl_material = match mtl_materials.last_mut() {
+        Some(mtl_material) => mtl_material,
+        None => continue
+      };
+
+      let result = match line_type {
+        MtlLineType::AmbientColor => MtlFileParser::parse_color(mtl_line).map(|color| mtl_material.ambient_color = color),
+        MtlLineType::DiffuseColor => MtlFileParser::parse_color(mtl_line).map(|color| mtl_material.diffuse_color = color),
+        MtlLineType::SpecularColor => MtlFileParser::parse_color(mtl_line).map(|color| mtl_material.specular_color = color),
+        MtlLineType::EmissiveColor => MtlFileParser::parse_color(mtl_line).map(|color| mtl_material.emissive_color = color),
+        MtlLineType::SpecularExponent => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.specular_exponent = value),
+        MtlLineType::Dissolve => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.dissolve = value),
+        MtlLineType::Transparency => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.dissolve = 1.0 - value),
+        MtlLineType::OpticalDensity => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.optical_density = value),
+        MtlLineType::Illumination => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.illumination = value as u64),
+        MtlLineType::DiffuseTexture => MtlFileParser::load_texture(mtl_line, texture_directory).map(|texture| mtl_material.diffuse_texture = Some(texture)),
+        MtlLineType::NormalTexture => MtlFileParser::load_texture(mtl_line, texture_directory).map(|texture| mtl_material.normal_texture = Some(texture)),
+        MtlLineType::Roughness => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.roughness = Some(value)),
+        MtlLineType::Metallic => MtlFileParser::parse_value(mtl_line).map(|value| mtl_material.metallic = Some(value)),
+        // Unsupported statements are skipped
+        _ => Ok(())
+      };
+
+      result.map_err(|message| ObjError::parse(file_name, line_index + 1, message))?;
+    }
+
+    Ok(mtl_materials)
+  }
+
+  pub fn mtl_line_type(mtl_line: &str) -> MtlLineType {
+    let keyword = match mtl_line.split_whitespace().next() {
+      Some(keyword) => keyword,
+      None => return MtlLineType::Invalid
+    };
+
+    match keyword {
+      "newmtl" => MtlLineType::NewMaterial,
+      "Ka" => MtlLineType::AmbientColor,
+      "Kd" => MtlLineType::DiffuseColor,
+      "Ks" => MtlLineType::SpecularColor,
+      "Ke" => MtlLineType::EmissiveColor,
+      "Ns" => MtlLineType::SpecularExponent,
+      "d" => MtlLineType::Dissolve,
+      "Tr" => MtlLineType::Transparency,
+      "Ni" => MtlLineType::OpticalDensity,
+      "illum" => MtlLineType::Illumination,
+      "map_Kd" => MtlLineType::DiffuseTexture,
+      "norm" => MtlLineType::NormalTexture,
+      "Pr" => MtlLineType::Roughness,
+      "Pm" => MtlLineType::Metallic,
+      _ => MtlLineType::Invalid
+    }
+  }
+
+  pub fn parse_color(mtl_line: &str) -> Result<Color, String> {
+    // Spectral and xyz color forms are not supported
+    let values = ObjFileParser::parse_values(mtl_line, 1)?;
+
+    // Single value applies to all channels
+    if values.len() < 3 {
+      return Ok(Color::new(values[0], values[0], values[0], 1.0));
+    }
+
+    Ok(Color::new(values[0], values[1], values[2], 1.0))
+  }
+
+  pub fn parse_value(mtl_line: &str) -> Result<f64, String> {
+    let values = ObjFileParser::parse_values(mtl_line, 1)?;
+
+    Ok(values[0])
+  }
+
+  pub fn load_texture(mtl_line: &str, texture_directory: &Path) -> Result<Rc<Canvas>, String> {
+    let texture_file = MtlFileParser::parse_texture_file(mtl_line);
+    let texture_path = texture_directory.join(texture_file).to_string_lossy().to_string();
+
+    match Canvas::load_image(&texture_path) {
+      Ok(texture) => Ok(Rc::new(texture)),
+      Err(error) => Err(format!("could not load texture ({}: {})", texture_path, error))
+    }
+  }
+
+  pub fn parse_texture_file(mtl_line: &str) -> String {
+    // Options such as -s or -o precede file name
+    match mtl_line.split_whitespace().last() {
+      Some(texture_file) => texture_file.to_string(),
+      None => String::new()
+    }
+  }
+}
+
+fn average_component(color: &Color) -> f64 {
+  (color.r + color.g + color.b) / 3.0
+}
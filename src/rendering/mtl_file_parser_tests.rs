@@ -0,0 +1,302 @@
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::env;
+  use std::rc::Rc;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::Sphere;
+
+  use crate::rendering::MtlLineType;
+  use crate::rendering::MtlMaterial;
+  use crate::rendering::MtlFileParser;
+
+  use crate::rendering::Material;
+  use crate::rendering::ShadingModel;
+  use crate::rendering::ShadingModelType;
+  use crate::rendering::LightSample;
+
+  // Custom model which happens to share its name with a built in one
+  struct Imitation;
+
+  impl ShadingModel for Imitation {
+    fn name(&self) -> &str {
+      "metallic_roughness"
+    }
+
+    fn get_base_type(&self) -> ShadingModelType {
+      ShadingModelType::Custom
+    }
+
+    fn shade(&self, _material: &Material, surface_color: &Color, _light: &LightSample, _eye_v: &Vector, _normal: &Vector) -> Color {
+      *surface_color
+    }
+  }
+
+  fn mtl_lines(lines: Vec<&str>) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+  }
+
+  #[test]
+  fn identifies_mtl_statement_types() {
+    assert!(MtlFileParser::mtl_line_type("newmtl red") == MtlLineType::NewMaterial);
+    assert!(MtlFileParser::mtl_line_type("Ka 0.1 0.1 0.1") == MtlLineType::AmbientColor);
+    assert!(MtlFileParser::mtl_line_type("Kd 1.0 0.0 0.0") == MtlLineType::DiffuseColor);
+    assert!(MtlFileParser::mtl_line_type("Ks 0.5 0.5 0.5") == MtlLineType::SpecularColor);
+    assert!(MtlFileParser::mtl_line_type("Ke 1.0 1.0 1.0") == MtlLineType::EmissiveColor);
+    assert!(MtlFileParser::mtl_line_type("Ns 96.0") == MtlLineType::SpecularExponent);
+    assert!(MtlFileParser::mtl_line_type("d 0.5") == MtlLineType::Dissolve);
+    assert!(MtlFileParser::mtl_line_type("Tr 0.5") == MtlLineType::Transparency);
+    assert!(MtlFileParser::mtl_line_type("Ni 1.5") == MtlLineType::OpticalDensity);
+    assert!(MtlFileParser::mtl_line_type("illum 2") == MtlLineType::Illumination);
+    assert!(MtlFileParser::mtl_line_type("map_Kd wood.ppm") == MtlLineType::DiffuseTexture);
+    assert!(MtlFileParser::mtl_line_type("norm wood_normal.ppm") == MtlLineType::NormalTexture);
+    assert!(MtlFileParser::mtl_line_type("Pr 0.4") == MtlLineType::Roughness);
+    assert!(MtlFileParser::mtl_line_type("Pm 1.0") == MtlLineType::Metallic);
+    assert!(MtlFileParser::mtl_line_type("# comment") == MtlLineType::Invalid);
+    assert!(MtlFileParser::mtl_line_type("") == MtlLineType::Invalid);
+  }
+
+  #[test]
+  fn parses_colors_with_one_or_three_components() {
+    assert!(MtlFileParser::parse_color("Kd 0.1 0.2 0.3").unwrap() == Color::new(0.1, 0.2, 0.3, 1.0));
+    assert!(MtlFileParser::parse_color("Kd 0.4").unwrap() == Color::new(0.4, 0.4, 0.4, 1.0));
+  }
+
+  #[test]
+  fn parses_texture_file_name_after_options() {
+    assert_eq!(MtlFileParser::parse_texture_file("map_Kd wood.ppm"), "wood.ppm");
+    assert_eq!(MtlFileParser::parse_texture_file("map_Kd -s 2 2 1 wood.ppm"), "wood.ppm");
+  }
+
+  #[test]
+  fn parses_statements_into_named_materials() {
+    let lines = mtl_lines(vec![
+      "# Exported material",
+      "Kd 0.0 0.0 0.0",
+      "newmtl red",
+      "Ka 0.5 0.5 0.5",
+      "Kd 1.0 0.0 0.0",
+      "Ks 0.2 0.4 0.6",
+      "Ns 96.0",
+      "d 0.25",
+      "Ni 1.5",
+      "illum 3",
+      "",
+      "newmtl  blue",
+      "Kd 0.0 0.0 1.0",
+      "Tr 0.75"
+    ]);
+
+    let mtl_materials = MtlFileParser::parse_materials(&lines, "test.mtl").unwrap();
+
+    assert_eq!(mtl_materials.len(), 2);
+
+    let red = &mtl_materials[0];
+    assert_eq!(red.name, "red");
+    assert!(red.ambient_color == Color::new(0.5, 0.5, 0.5, 1.0));
+    assert!(red.diffuse_color == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(red.specular_color == Color::new(0.2, 0.4, 0.6, 1.0));
+    assert_eq!(red.specular_exponent, 96.0);
+    assert_eq!(red.dissolve, 0.25);
+    assert_eq!(red.optical_density, 1.5);
+    assert_eq!(red.illumination, 3);
+
+    let blue = &mtl_materials[1];
+    assert_eq!(blue.name, "blue");
+    assert!(blue.diffuse_color == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert_eq!(blue.dissolve, 0.25);
+  }
+
+  #[test]
+  fn converts_mtl_values_into_material_fields() {
+    let mut mtl_material = MtlMaterial::new("glass");
+    mtl_material.ambient_color = Color::new(1.0, 1.0, 1.0, 1.0);
+    mtl_material.diffuse_color = Color::new(0.2, 0.4, 0.6, 1.0);
+    mtl_material.specular_color = Color::new(0.9, 0.9, 0.9, 1.0);
+    mtl_material.specular_exponent = 200.0;
+    mtl_material.dissolve = 0.1;
+    mtl_material.optical_density = 1.52;
+    mtl_material.illumination = 2;
+
+    let material = mtl_material.to_material();
+
+    assert_eq!(material.ambient, 0.1);
+    assert_eq!(material.diffuse, 1.0);
+    assert!((material.specular - 0.9).abs() < 0.0001);
+    assert_eq!(material.shininess, 200.0);
+    assert_eq!(material.reflectiveness, 0.0);
+    assert!((material.transparency - 0.9).abs() < 0.0001);
+    assert_eq!(material.refractive_index, 1.52);
+    assert!(material.color_1 == Color::new(0.2, 0.4, 0.6, 1.0));
+    assert!(material.texture.is_none());
+  }
+
+  #[test]
+  fn illumination_model_controls_highlights_and_reflections() {
+    let mut mtl_material = MtlMaterial::new("mirror");
+    mtl_material.specular_color = Color::new(0.5, 0.5, 0.5, 1.0);
+
+    mtl_material.illumination = 0;
+    let material = mtl_material.to_material();
+    assert_eq!(material.ambient, 1.0);
+    assert_eq!(material.diffuse, 0.0);
+    assert_eq!(material.specular, 0.0);
+
+    mtl_material.illumination = 1;
+    let material = mtl_material.to_material();
+    assert_eq!(material.specular, 0.0);
+    assert_eq!(material.reflectiveness, 0.0);
+
+    mtl_material.illumination = 3;
+    let material = mtl_material.to_material();
+    assert_eq!(material.specular, 0.5);
+    assert_eq!(material.reflectiveness, 0.5);
+  }
+
+  #[test]
+  fn roughness_and_metallic_select_metallic_roughness_shading() {
+    let lines = mtl_lines(vec![
+      "newmtl gold",
+      "Kd 1.0 0.8 0.3",
+      "Pr 0.25",
+      "Pm 1.0",
+      "newmtl clay",
+      "Kd 0.6 0.3 0.2",
+      "Pr 0.9",
+      "newmtl plastic",
+      "Kd 0.2 0.2 0.8"
+    ]);
+
+    let mtl_materials = MtlFileParser::parse_materials(&lines, "test.mtl").unwrap();
+
+    let gold = mtl_materials[0].to_material();
+    assert_eq!(gold.shading_model.name(), "metallic_roughness");
+    assert_eq!(gold.roughness, 0.25);
+    assert_eq!(gold.metallic, 1.0);
+
+    let clay = mtl_materials[1].to_material();
+    assert_eq!(clay.shading_model.name(), "metallic_roughness");
+    assert_eq!(clay.roughness, 0.9);
+    assert_eq!(clay.metallic, 0.0);
+
+    let plastic = mtl_materials[2].to_material();
+    assert_eq!(plastic.shading_model.name(), "phong");
+
+    // Exported materials keep values of physically based extension
+    let exported_lines = MtlMaterial::from_material("gold", &gold).mtl_lines();
+    assert!(exported_lines.contains(&"Pr 0.25".to_string()));
+    assert!(exported_lines.contains(&"Pm 1".to_string()));
+  }
+
+  #[test]
+  fn loads_material_library_with_diffuse_texture() {
+    let directory = env::temp_dir().join("jb_tracer_mtl_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    fs::write(directory.join("checker.ppm"), "P3\n2 1\n255\n255 0 0 0 0 255\n").unwrap();
+    fs::write(directory.join("textured.mtl"), "newmtl checker\nKd 1.0 1.0 1.0\nmap_Kd checker.ppm\n").unwrap();
+
+    let materials = MtlFileParser::load_file(&directory.join("textured.mtl").to_string_lossy()).unwrap();
+
+    let material = materials.get("checker").unwrap();
+    assert!(material.texture.is_some());
+
+    let sphere = Sphere::new(Matrix4x4::identity(), Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity()));
+    assert!(material.color_at_with_uv(&sphere, &Point::empty(), 0.0, 0.5) == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(material.color_at_with_uv(&sphere, &Point::empty(), 1.0, 0.5) == Color::new(0.0, 0.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn loads_material_library_with_normal_texture() {
+    let directory = env::temp_dir().join("jb_tracer_mtl_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    fs::write(directory.join("flat_normal.ppm"), "P3\n1 1\n255\n128 128 255\n").unwrap();
+    fs::write(directory.join("normal_mapped.mtl"), "newmtl bumpy\nKd 1.0 1.0 1.0\nnorm flat_normal.ppm\n").unwrap();
+
+    let materials = MtlFileParser::load_file(&directory.join("normal_mapped.mtl").to_string_lossy()).unwrap();
+
+    let material = materials.get("bumpy").unwrap();
+    assert!(material.texture.is_none());
+    assert!(material.normal_map.is_some());
+    assert!(material.perturbs_normal());
+  }
+
+  #[test]
+  fn missing_texture_is_reported() {
+    let lines = mtl_lines(vec![
+      "newmtl missing",
+      "map_Kd does_not_exist.ppm"
+    ]);
+
+    assert!(MtlFileParser::parse_materials(&lines, "test.mtl").is_err());
+  }
+
+  #[test]
+  fn emissive_color_sets_emission_of_material() {
+    let lines = mtl_lines(vec![
+      "newmtl bulb",
+      "Kd 1.0 1.0 1.0",
+      "Ke 4.0 3.5 3.0",
+      "newmtl wall",
+      "Kd 0.8 0.8 0.8"
+    ]);
+
+    let mtl_materials = MtlFileParser::parse_materials(&lines, "test.mtl").unwrap();
+
+    let bulb = mtl_materials[0].to_material();
+    assert!(bulb.emits_light());
+    assert!(bulb.emission == Color::new(4.0, 3.5, 3.0, 1.0));
+
+    assert!(!mtl_materials[1].to_material().emits_light());
+
+    let exported_lines = MtlMaterial::from_material("bulb", &bulb).mtl_lines();
+    assert!(exported_lines.contains(&"Ke 4 3.5 3".to_string()));
+  }
+
+  #[test]
+  fn converts_material_fields_into_mtl_statements() {
+    let material = Material::solid(0.1, 0.9, 0.5, 200.0, 0.3, 0.25, 1.52, Color::new(0.2, 0.4, 0.6, 1.0), Matrix4x4::identity());
+    let mtl_material = MtlMaterial::from_material("glass", &material);
+
+    assert_eq!(mtl_material.mtl_lines(), vec![
+      "newmtl glass",
+      "Ka 1 1 1",
+      "Kd 0.2 0.4 0.6",
+      "Ks 0.5 0.5 0.5",
+      "Ns 200",
+      "d 0.75",
+      "Ni 1.52",
+      "illum 3"
+    ]);
+
+    let material = mtl_material.to_material();
+
+    assert_eq!(material.ambient, 0.1);
+    assert_eq!(material.specular, 0.5);
+    assert_eq!(material.reflectiveness, 0.5);
+    assert_eq!(material.transparency, 0.25);
+    assert!(material.color_1 == Color::new(0.2, 0.4, 0.6, 1.0));
+  }
+
+  #[test]
+  fn physically_based_values_are_exported_only_for_metallic_roughness_model() {
+    let mut material = Material::metallic_roughness(0.05, 1.0, 0.25, 0.0, 1.5, Color::new(1.0, 0.8, 0.3, 1.0), Matrix4x4::identity());
+
+    let exported_lines = MtlMaterial::from_material("gold", &material).mtl_lines();
+    assert!(exported_lines.contains(&"Pr 0.25".to_string()));
+
+    // Models are matched by type, so a name alone does not make one physically based
+    material.shading_model = Rc::new(Imitation);
+
+    let exported_lines = MtlMaterial::from_material("gold", &material).mtl_lines();
+    assert!(!exported_lines.iter().any(|line| line.starts_with("Pr") || line.starts_with("Pm")));
+  }
+}
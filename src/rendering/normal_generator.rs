@@ -0,0 +1,119 @@
+use std::f64;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+// Crease angle used when normals are generated for faces that have none, sharp enough to keep cube edges
+pub const DEFAULT_CREASE_ANGLE: f64 = f64::consts::PI / 3.0;
+
+// Allows faces lying in the same plane to share normals despite rounding
+const CREASE_EPSILON: f64 = 0.000001;
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum NormalWeighting {
+  Area,
+  Angle
+}
+
+// Computes vertex normals for meshes, with faces meeting at more than crease angle kept sharp
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct NormalGenerator {
+  pub weighting: NormalWeighting,
+  pub crease_angle: f64
+}
+
+impl NormalGenerator {
+  pub fn new(weighting: NormalWeighting, crease_angle: f64) -> NormalGenerator {
+    NormalGenerator { weighting, crease_angle }
+  }
+
+  // Every face contributes to each of its vertices, leaving no edge sharp
+  pub fn smooth(weighting: NormalWeighting) -> NormalGenerator {
+    NormalGenerator::new(weighting, f64::consts::PI)
+  }
+
+  pub fn face_normal(vertices: &[Point], face: &[usize; 3]) -> Vector {
+    let edge_1 = vertices[face[1]].subtract_point(&vertices[face[0]]);
+    let edge_2 = vertices[face[2]].subtract_point(&vertices[face[0]]);
+
+    edge_1.cross(&edge_2)
+  }
+
+  // Weight of face at one of its corners, area or interior angle of face
+  pub fn corner_weight(&self, vertices: &[Point], face: &[usize; 3], corner: usize) -> f64 {
+    match self.weighting {
+      NormalWeighting::Area => NormalGenerator::face_normal(vertices, face).magnitude() / 2.0,
+      NormalWeighting::Angle => {
+        let edge_1 = vertices[face[(corner + 1) % 3]].subtract_point(&vertices[face[corner]]);
+        let edge_2 = vertices[face[(corner + 2) % 3]].subtract_point(&vertices[face[corner]]);
+
+        if edge_1.magnitude() == 0.0 || edge_2.magnitude() == 0.0 {
+          return 0.0;
+        }
+
+        edge_1.normalize().dot(&edge_2.normalize()).clamp(-1.0, 1.0).acos()
+      }
+    }
+  }
+
+  // Returns normals for each corner of each face, as corners sharing a vertex differ across creases
+  pub fn generate(&self, vertices: &[Point], faces: &[[usize; 3]]) -> Vec<[Vector; 3]> {
+    let mut face_normals = Vec::new();
+    let mut corner_weights = Vec::new();
+
+    for face in faces {
+      let face_normal = NormalGenerator::face_normal(vertices, face);
+
+      // Degenerate faces have no direction to contribute
+      if face_normal.magnitude() > 0.0 {
+        face_normals.push(face_normal.normalize());
+      } else {
+        face_normals.push(Vector::new(0.0, 0.0, 0.0));
+      }
+
+      corner_weights.push([
+        self.corner_weight(vertices, face, 0),
+        self.corner_weight(vertices, face, 1),
+        self.corner_weight(vertices, face, 2)
+      ]);
+    }
+
+    // Faces meeting at each vertex, with corner of face at that vertex
+    let mut vertex_faces: Vec<Vec<(usize, usize)>> = vec![Vec::new(); vertices.len()];
+    for (face_index, face) in faces.iter().enumerate() {
+      for (corner, &vertex) in face.iter().enumerate() {
+        vertex_faces[vertex].push((face_index, corner));
+      }
+    }
+
+    let crease_cosine = self.crease_angle.cos() - CREASE_EPSILON;
+
+    let mut normals = Vec::new();
+    for (face_index, face) in faces.iter().enumerate() {
+      let face_normal = face_normals[face_index];
+      let mut corner_normals = [face_normal; 3];
+
+      for (corner, &vertex) in face.iter().enumerate() {
+        let mut normal = Vector::new(0.0, 0.0, 0.0);
+
+        for &(other_face, other_corner) in &vertex_faces[vertex] {
+          let other_normal = face_normals[other_face];
+
+          if other_face == face_index || face_normal.dot(&other_normal) >= crease_cosine {
+            normal = normal.add_vector(&other_normal.multiply(corner_weights[other_face][other_corner]));
+          }
+        }
+
+        // Opposing faces can cancel out, leaving face normal as best estimate
+        if normal.magnitude() > 0.0 {
+          corner_normals[corner] = normal.normalize();
+        }
+      }
+
+      normals.push(corner_normals);
+    }
+
+    normals
+  }
+}
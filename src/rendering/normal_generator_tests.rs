@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::NormalWeighting;
+  use crate::rendering::NormalGenerator;
+
+  // Two faces meeting along the x axis at a right angle
+  fn folded_vertices() -> Vec<Point> {
+    vec![
+      Point::new(-1.0, 0.0, 0.0),
+      Point::new(1.0, 0.0, 0.0),
+      Point::new(0.0, 0.0, 1.0),
+      Point::new(0.0, 1.0, 0.0)
+    ]
+  }
+
+  fn is_near(normal: &Vector, expected: &Vector) -> bool {
+    (normal.x - expected.x).abs() < 0.0001 && (normal.y - expected.y).abs() < 0.0001 && (normal.z - expected.z).abs() < 0.0001
+  }
+
+  #[test]
+  fn normal_generator_created_with_weighting_and_crease_angle() {
+    let normal_generator = NormalGenerator::new(NormalWeighting::Angle, 0.5);
+
+    assert_eq!(normal_generator.weighting, NormalWeighting::Angle);
+    assert_eq!(normal_generator.crease_angle, 0.5);
+
+    let normal_generator = NormalGenerator::smooth(NormalWeighting::Area);
+
+    assert_eq!(normal_generator.weighting, NormalWeighting::Area);
+    assert_eq!(normal_generator.crease_angle, f64::consts::PI);
+  }
+
+  #[test]
+  fn face_normal_follows_winding_order() {
+    let vertices = folded_vertices();
+
+    assert!(NormalGenerator::face_normal(&vertices, &[0, 1, 2]) == Vector::new(0.0, -2.0, 0.0));
+    assert!(NormalGenerator::face_normal(&vertices, &[1, 0, 3]) == Vector::new(0.0, 0.0, -2.0));
+  }
+
+  #[test]
+  fn corner_weights_use_face_area_or_corner_angle() {
+    let vertices = vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(0.0, 2.0, 0.0)];
+
+    let area_generator = NormalGenerator::smooth(NormalWeighting::Area);
+    for corner in 0..3 {
+      assert!((area_generator.corner_weight(&vertices, &[0, 1, 2], corner) - 2.0).abs() < 0.0001);
+    }
+
+    let angle_generator = NormalGenerator::smooth(NormalWeighting::Angle);
+    assert!((angle_generator.corner_weight(&vertices, &[0, 1, 2], 0) - f64::consts::PI / 2.0).abs() < 0.0001);
+    assert!((angle_generator.corner_weight(&vertices, &[0, 1, 2], 1) - f64::consts::PI / 4.0).abs() < 0.0001);
+    assert!((angle_generator.corner_weight(&vertices, &[0, 1, 2], 2) - f64::consts::PI / 4.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn smooth_normals_average_faces_sharing_vertex() {
+    let vertices = folded_vertices();
+    let faces = vec![[0, 1, 2], [1, 0, 3]];
+
+    let normals = NormalGenerator::smooth(NormalWeighting::Area).generate(&vertices, &faces);
+    let shared = Vector::new(0.0, -1.0, -1.0).normalize();
+
+    assert_eq!(normals.len(), 2);
+    assert!(is_near(&normals[0][0], &shared));
+    assert!(is_near(&normals[0][1], &shared));
+    assert!(is_near(&normals[0][2], &Vector::new(0.0, -1.0, 0.0)));
+    assert!(is_near(&normals[1][0], &shared));
+    assert!(is_near(&normals[1][2], &Vector::new(0.0, 0.0, -1.0)));
+  }
+
+  #[test]
+  fn faces_meeting_beyond_crease_angle_stay_sharp() {
+    let vertices = folded_vertices();
+    let faces = vec![[0, 1, 2], [1, 0, 3]];
+
+    let normals = NormalGenerator::new(NormalWeighting::Angle, f64::consts::PI / 3.0).generate(&vertices, &faces);
+
+    for (normal_1, normal_2) in normals[0].iter().zip(normals[1].iter()) {
+      assert!(is_near(normal_1, &Vector::new(0.0, -1.0, 0.0)));
+      assert!(is_near(normal_2, &Vector::new(0.0, 0.0, -1.0)));
+    }
+
+    // Crease wider than fold angle smooths edge
+    let normals = NormalGenerator::new(NormalWeighting::Angle, f64::consts::PI * 2.0 / 3.0).generate(&vertices, &faces);
+
+    assert!(is_near(&normals[0][0], &Vector::new(0.0, -1.0, -1.0).normalize()));
+  }
+
+  #[test]
+  fn coplanar_faces_share_normals_with_zero_crease_angle() {
+    let vertices = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+    let faces = vec![[0, 1, 2], [0, 2, 3]];
+
+    let normals = NormalGenerator::new(NormalWeighting::Area, 0.0).generate(&vertices, &faces);
+
+    for face_normals in normals {
+      for normal in face_normals.iter() {
+        assert!(is_near(normal, &Vector::new(0.0, 0.0, 1.0)));
+      }
+    }
+  }
+
+  #[test]
+  fn angle_weighting_ignores_how_faces_are_split() {
+    // Second face split into two thin triangles, which together still cover a right angle at shared vertex
+    let vertices = vec![
+      Point::new(0.0, 0.0, 0.0),
+      Point::new(1.0, 0.0, 0.0),
+      Point::new(0.0, 1.0, 0.0),
+      Point::new(0.0, 0.0, 1.0),
+      Point::new(0.0, 1.0, 1.0)
+    ];
+    let faces = vec![[0, 2, 1], [0, 3, 4], [0, 4, 2]];
+
+    let normals = NormalGenerator::smooth(NormalWeighting::Angle).generate(&vertices, &faces);
+
+    assert!(is_near(&normals[0][0], &Vector::new(-1.0, 0.0, -1.0).normalize()));
+
+    let normals = NormalGenerator::smooth(NormalWeighting::Area).generate(&vertices, &faces);
+
+    assert!(is_near(&normals[0][0], &Vector::new(-2.0, 0.0, -1.0).normalize()));
+  }
+
+  #[test]
+  fn degenerate_faces_do_not_contribute_normals() {
+    let vertices = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0), Point::new(2.0, 0.0, 0.0)];
+    let faces = vec![[0, 1, 2], [0, 1, 3]];
+
+    let normals = NormalGenerator::smooth(NormalWeighting::Angle).generate(&vertices, &faces);
+
+    assert!(is_near(&normals[0][0], &Vector::new(0.0, 0.0, 1.0)));
+    assert!(is_near(&normals[0][1], &Vector::new(0.0, 0.0, 1.0)));
+    assert!(!normals[1][0].x.is_nan());
+  }
+}
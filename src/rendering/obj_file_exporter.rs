@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use std::fs;
+use std::path::Path;
+
+use crate::rendering::Scene;
+
+use crate::rendering::Container;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::ObjError;
+use crate::rendering::MtlMaterial;
+
+pub struct ObjFileExporter {
+  pub resolution: usize,
+  pub extent: f64
+}
+
+impl ObjFileExporter {
+  // Resolution is number of segments around curved shapes, extent bounds infinite planes, cylinders and cones
+  pub fn new(resolution: usize, extent: f64) -> ObjFileExporter {
+    ObjFileExporter { resolution, extent }
+  }
+
+  pub fn export_scene(&self, scene: &Scene, file_name: &str) -> Result<(), ObjError> {
+    self.export_containers(&scene.containers, file_name)
+  }
+
+  // Writes geometry to obj file, and its materials to mtl file with same name beside it
+  pub fn export_containers(&self, containers: &[Container], file_name: &str) -> Result<(), ObjError> {
+    let mut tessellations = Vec::new();
+    for container in containers {
+      tessellations.extend(container.tessellate(self.resolution, self.extent));
+    }
+
+    let mtl_path = Path::new(file_name).with_extension("mtl");
+    let mtl_file_name = match mtl_path.file_name() {
+      Some(mtl_file_name) => mtl_file_name.to_string_lossy().to_string(),
+      None => String::from("materials.mtl")
+    };
+
+    let (obj_lines, mtl_lines) = ObjFileExporter::export_lines(&tessellations, &mtl_file_name);
+
+    ObjFileExporter::write_lines(file_name, &obj_lines)?;
+    ObjFileExporter::write_lines(&mtl_path.to_string_lossy(), &mtl_lines)
+  }
+
+  // Returns lines of obj file and of mtl file it references
+  pub fn export_lines(tessellations: &[Tessellation], mtl_file_name: &str) -> (Vec<String>, Vec<String>) {
+    let mut obj_lines = vec![format!("mtllib {}", mtl_file_name)];
+    let mut mtl_lines = Vec::new();
+
+    // Materials with identical statements are written once
+    let mut material_names: HashMap<Vec<String>, String> = HashMap::new();
+
+    let mut current_group = String::new();
+    let mut current_material = String::new();
+
+    // Indices in obj files start at one, and count vertices of every earlier shape
+    let mut first_vertex = 1;
+
+    for tessellation in tessellations {
+      if tessellation.group != current_group {
+        current_group = tessellation.group.clone();
+        obj_lines.push(format!("g {}", current_group).trim_end().to_string());
+      }
+
+      let statements = MtlMaterial::from_material("", tessellation.get_material()).mtl_lines().split_off(1);
+      let material_name = match material_names.get(&statements) {
+        Some(material_name) => material_name.clone(),
+        None => {
+          let material_name = format!("material_{}", material_names.len() + 1);
+
+          mtl_lines.push(format!("newmtl {}", material_name));
+          mtl_lines.extend(statements.iter().cloned());
+          mtl_lines.push(String::new());
+
+          material_names.insert(statements, material_name.clone());
+          material_name
+        }
+      };
+
+      if material_name != current_material {
+        obj_lines.push(format!("usemtl {}", material_name));
+        current_material = material_name;
+      }
+
+      for vertex in &tessellation.vertices {
+        obj_lines.push(format!("v {} {} {}", vertex.x, vertex.y, vertex.z));
+      }
+
+      for normal in &tessellation.normals {
+        obj_lines.push(format!("vn {} {} {}", normal.x, normal.y, normal.z));
+      }
+
+      for face in &tessellation.faces {
+        let [index_1, index_2, index_3] = [face[0] + first_vertex, face[1] + first_vertex, face[2] + first_vertex];
+
+        obj_lines.push(format!("f {}//{} {}//{} {}//{}", index_1, index_1, index_2, index_2, index_3, index_3));
+      }
+
+      first_vertex += tessellation.vertices.len();
+    }
+
+    (obj_lines, mtl_lines)
+  }
+
+  pub fn write_lines(file_name: &str, lines: &[String]) -> Result<(), ObjError> {
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+
+    fs::write(file_name, contents).map_err(|error| ObjError::Io { file_name: file_name.to_string(), error })
+  }
+}
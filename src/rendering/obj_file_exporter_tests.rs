@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use std::fs;
+  use std::env;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Sphere;
+  use crate::rendering::shapes::Cube;
+  use crate::rendering::shapes::SmoothTriangle;
+
+  use crate::rendering::Scene;
+  use crate::rendering::Camera;
+  use crate::rendering::PointLight;
+
+  use crate::rendering::Container;
+
+  use crate::rendering::Tessellation;
+
+  use crate::rendering::Material;
+
+  use crate::rendering::ObjFileExporter;
+  use crate::rendering::ObjFileParser;
+
+  fn material(color: Color) -> Material {
+    Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, color, Matrix4x4::identity())
+  }
+
+  fn triangle_tessellation(group: &str, color: Color) -> Tessellation {
+    let mut tessellation = Tessellation::new(material(color));
+    tessellation.group = group.to_string();
+
+    tessellation.add_vertex(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_vertex(Point::new(-1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_vertex(Point::new(1.0, 0.0, 0.5), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_face([0, 1, 2]);
+
+    tessellation
+  }
+
+  #[test]
+  fn exporter_created_with_resolution_and_extent() {
+    let exporter = ObjFileExporter::new(24, 500.0);
+
+    assert_eq!(exporter.resolution, 24);
+    assert_eq!(exporter.extent, 500.0);
+  }
+
+  #[test]
+  fn exports_vertices_normals_and_faces() {
+    let tessellations = vec![triangle_tessellation("", Color::new(1.0, 0.0, 0.0, 1.0))];
+
+    let (obj_lines, mtl_lines) = ObjFileExporter::export_lines(&tessellations, "scene.mtl");
+
+    assert_eq!(obj_lines, vec![
+      "mtllib scene.mtl",
+      "usemtl material_1",
+      "v 0 1 0",
+      "v -1 0 0",
+      "v 1 0 0.5",
+      "vn 0 0 1",
+      "vn 0 0 1",
+      "vn 0 0 1",
+      "f 1//1 2//2 3//3"
+    ]);
+
+    assert_eq!(mtl_lines[0], "newmtl material_1");
+    assert_eq!(mtl_lines[2], "Kd 1 0 0");
+  }
+
+  #[test]
+  fn exports_groups_and_shared_materials_once() {
+    let tessellations = vec![
+      triangle_tessellation("", Color::new(1.0, 0.0, 0.0, 1.0)),
+      triangle_tessellation("wheel", Color::new(0.0, 1.0, 0.0, 1.0)),
+      triangle_tessellation("wheel", Color::new(1.0, 0.0, 0.0, 1.0))
+    ];
+
+    let (obj_lines, mtl_lines) = ObjFileExporter::export_lines(&tessellations, "scene.mtl");
+
+    assert_eq!(obj_lines[9], "g wheel");
+    assert_eq!(obj_lines[10], "usemtl material_2");
+    assert_eq!(obj_lines[17], "f 4//4 5//5 6//6");
+    assert_eq!(obj_lines[18], "usemtl material_1");
+    assert_eq!(obj_lines[25], "f 7//7 8//8 9//9");
+
+    assert_eq!(mtl_lines.iter().filter(|line| line.starts_with("newmtl")).count(), 2);
+  }
+
+  #[test]
+  fn exported_scene_can_be_loaded() {
+    let directory = env::temp_dir().join("jb_tracer_export_tests");
+    fs::create_dir_all(&directory).unwrap();
+    let file_name = directory.join("scene.obj").to_string_lossy().to_string();
+
+    let sphere = Sphere::new(Matrix4x4::translate(0.0, 2.0, 0.0), material(Color::new(1.0, 0.0, 0.0, 1.0)));
+    let cube = Cube::new(Matrix4x4::identity(), material(Color::new(0.0, 0.0, 1.0, 1.0)));
+
+    let mut wheel = Container::named("wheel", Matrix4x4::identity(), vec![&cube as &dyn Shape]);
+    wheel.set_transform(Matrix4x4::translate(5.0, 0.0, 0.0));
+
+    let mut container = Container::new(Matrix4x4::scale(2.0, 2.0, 2.0), vec![&sphere as &dyn Shape]);
+    container.add_child(wheel);
+
+    let camera = Camera::new(200, 100, f64::consts::PI / 2.0, Matrix4x4::identity());
+    let scene = Scene::new(camera, vec![PointLight::default()], vec![container]);
+
+    ObjFileExporter::new(8, 100.0).export_scene(&scene, &file_name).unwrap();
+
+    assert!(directory.join("scene.mtl").exists());
+
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    let loaded = ObjFileParser::load_file_with_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut smooth_triangles).unwrap();
+
+    assert_eq!(loaded.shapes.len(), 48);
+    assert!(loaded.shapes[0].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+
+    // Cube keeps container transforms, and its own group and material
+    let loaded_wheel = loaded.child("wheel").unwrap();
+
+    assert_eq!(loaded_wheel.shapes.len(), 12);
+    assert!(loaded_wheel.shapes[0].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+
+    for shape in &loaded_wheel.shapes {
+      assert!(shape.tessellate(8, 100.0)[0].vertices.iter().all(|vertex| vertex.x == 8.0 || vertex.x == 12.0));
+    }
+  }
+}
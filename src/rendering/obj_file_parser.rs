@@ -1,8 +1,14 @@
 use std::f64;
 
+use std::collections::HashMap;
+
+use std::fmt;
+use std::error::Error;
+
 use std::io::prelude::*;
 use std::fs::File;
 use std::io::{BufReader};
+use std::path::Path;
 
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
@@ -14,40 +20,157 @@ use crate::rendering::math::Matrix4x4;
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::Triangle;
 use crate::rendering::shapes::SmoothTriangle;
+use crate::rendering::shapes::MeshIndices;
+use crate::rendering::shapes::Mesh;
 
 use crate::rendering::Container;
 
 use crate::rendering::Material;
 
-#[derive(PartialEq)]
+use crate::rendering::MtlFileParser;
+
+use crate::rendering::NormalWeighting;
+use crate::rendering::NormalGenerator;
+use crate::rendering::normal_generator::DEFAULT_CREASE_ANGLE;
+
+#[derive(PartialEq, Debug)]
 pub enum ObjLineType {
   VertexNormal,
+  TextureVertex,
   Vertex,
   Face,
+  Group,
+  MaterialLibrary,
+  UseMaterial,
   Invalid
 }
 
+#[derive(Debug)]
+pub enum ObjError {
+  Io { file_name: String, error: std::io::Error },
+  Parse { file_name: String, line_number: usize, message: String }
+}
+
+impl ObjError {
+  pub fn parse(file_name: &str, line_number: usize, message: String) -> ObjError {
+    ObjError::Parse { file_name: file_name.to_string(), line_number, message }
+  }
+}
+
+impl fmt::Display for ObjError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ObjError::Io { file_name, error } => write!(f, "{}: {}", file_name, error),
+      ObjError::Parse { file_name, line_number, message } => write!(f, "{}:{}: {}", file_name, line_number, message)
+    }
+  }
+}
+
+impl Error for ObjError {}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct ObjFaceVertex {
+  pub vertex: usize,
+  pub texture_vertex: Option<usize>,
+  pub normal: Option<usize>
+}
+
+pub struct ObjData {
+  pub vertices: Vec<Point>,
+  pub texture_vertices: Vec<(f64, f64)>,
+  pub normals: Vec<Vector>,
+  pub materials: HashMap<String, Material>,
+  pub color: Color,
+  pub current_material: Material,
+  pub groups: Vec<String>,
+  pub current_group: Option<usize>,
+  pub shape_groups: Vec<Option<usize>>
+}
+
+impl ObjData {
+  pub fn new(color: Color) -> ObjData {
+    ObjData {
+      vertices: Vec::new(),
+      texture_vertices: Vec::new(),
+      normals: Vec::new(),
+      materials: HashMap::new(),
+      color,
+      current_material: ObjFileParser::default_material(color),
+      groups: Vec::new(),
+      current_group: None,
+      shape_groups: Vec::new()
+    }
+  }
+
+  pub fn start_group(&mut self, obj_line: &str) {
+    // Keyword alone returns to default group, shapes of which stay in top level container
+    let (keyword, group_name) = match obj_line.trim().split_once(char::is_whitespace) {
+      Some((keyword, group_name)) => (keyword, group_name.trim()),
+      None => {
+        self.current_group = None;
+        return;
+      }
+    };
+
+    // Shapes belong to a single container, so of several group names only the first is kept
+    let group_name = match keyword {
+      "g" => group_name.split_whitespace().next().unwrap_or(group_name),
+      _ => group_name
+    };
+
+    // Repeated names continue existing group
+    match self.groups.iter().position(|name| name == group_name) {
+      Some(group_index) => self.current_group = Some(group_index),
+      None => {
+        self.groups.push(group_name.to_string());
+        self.current_group = Some(self.groups.len() - 1);
+      }
+    }
+  }
+
+  // Assigns newly parsed shapes to current group
+  pub fn assign_shapes(&mut self, shape_count: usize) {
+    self.shape_groups.resize(shape_count, self.current_group);
+  }
+
+  pub fn build_container<'a>(&self, transform: Matrix4x4, shapes: Vec<&'a dyn Shape>) -> Container<'a> {
+    let mut group_shapes: Vec<Vec<&'a dyn Shape>> = vec![Vec::new(); self.groups.len()];
+    let mut ungrouped_shapes = Vec::new();
+
+    for (shape_index, shape) in shapes.into_iter().enumerate() {
+      match self.shape_groups[shape_index] {
+        Some(group_index) => group_shapes[group_index].push(shape),
+        None => ungrouped_shapes.push(shape)
+      }
+    }
+
+    let mut container = Container::new(transform, ungrouped_shapes);
+    for (group_name, shapes) in self.groups.iter().zip(group_shapes) {
+      container.add_child(Container::named(group_name, Matrix4x4::identity(), shapes));
+    }
+
+    container
+  }
+}
+
 pub struct ObjFileParser {
   pub vertex_count: u64
 }
 
 impl ObjFileParser {
-  pub fn load_file<'a>(file_name: String, transform: Matrix4x4, color: Color, triangles: &'a mut Vec<Triangle>) -> Container<'a> {
-    let mut vertices = Vec::new();
+  pub fn load_file<'a>(file_name: String, transform: Matrix4x4, color: Color, triangles: &'a mut Vec<Triangle>) -> Result<Container<'a>, ObjError> {
+    let mut obj_data = ObjData::new(color);
 
-    let obj_file = File::open(file_name).unwrap();
-    let reader = BufReader::new(obj_file);
+    for (line_index, line) in ObjFileParser::read_lines(&file_name)?.iter().enumerate() {
+      let obj_line = ObjFileParser::strip_comment(line);
 
-    for line in reader.lines() {    
-      let obj_line = line.unwrap();
-      
-      if ObjFileParser::obj_line_type(&obj_line) == ObjLineType::Vertex {
-        vertices.push(ObjFileParser::parse_vertex(&obj_line));
+      let result = match ObjFileParser::obj_line_type(obj_line) {
+        ObjLineType::Face => ObjFileParser::parse_face_with_material(obj_line, &obj_data.current_material, &obj_data.vertices, &obj_data.texture_vertices, triangles),
+        line_type => ObjFileParser::parse_statement(line_type, obj_line, &file_name, &mut obj_data)
+      };
 
-      } else if ObjFileParser::obj_line_type(&obj_line) == ObjLineType::Face {
-        ObjFileParser::parse_face(&obj_line, color, &mut vertices, triangles);
-      
-      }
+      result.map_err(|message| ObjError::parse(&file_name, line_index + 1, message))?;
+      obj_data.assign_shapes(triangles.len());
     }
 
     let mut container_shape_references = Vec::new();
@@ -55,144 +178,408 @@ impl ObjFileParser {
       container_shape_references.push(triangle as &dyn Shape);
     }
 
-    Container::new(transform, container_shape_references)
+    Ok(obj_data.build_container(transform, container_shape_references))
   }
 
-  pub fn load_file_with_normals<'a>(file_name: String, transform: Matrix4x4, color: Color, smooth_triangles: &'a mut Vec<SmoothTriangle>) -> Container<'a> {
-    let mut vertices = Vec::new();
-    let mut normals = Vec::new();
+  // Faces without normals in file are given generated normals
+  pub fn load_file_with_normals<'a>(file_name: String, transform: Matrix4x4, color: Color, smooth_triangles: &'a mut Vec<SmoothTriangle>) -> Result<Container<'a>, ObjError> {
+    let normal_generator = NormalGenerator::new(NormalWeighting::Angle, DEFAULT_CREASE_ANGLE);
 
-    let obj_file = File::open(file_name).unwrap();
-    let reader = BufReader::new(obj_file);
+    ObjFileParser::load_smooth_triangles(file_name, transform, color, &normal_generator, true, smooth_triangles)
+  }
 
-    for line in reader.lines() {    
-      let obj_line = line.unwrap();
-      
-      if ObjFileParser::obj_line_type(&obj_line) == ObjLineType::VertexNormal {
-        normals.push(ObjFileParser::parse_vertex_normal(&obj_line));
+  // Ignores normals in file, so low polygon models without them can be shaded smoothly
+  pub fn load_file_with_generated_normals<'a>(file_name: String, transform: Matrix4x4, color: Color, normal_generator: &NormalGenerator, smooth_triangles: &'a mut Vec<SmoothTriangle>) -> Result<Container<'a>, ObjError> {
+    ObjFileParser::load_smooth_triangles(file_name, transform, color, normal_generator, false, smooth_triangles)
+  }
 
-      } else if ObjFileParser::obj_line_type(&obj_line) == ObjLineType::Vertex {
-        vertices.push(ObjFileParser::parse_vertex(&obj_line));
+  pub fn load_smooth_triangles<'a>(file_name: String, transform: Matrix4x4, color: Color, normal_generator: &NormalGenerator, use_file_normals: bool, smooth_triangles: &'a mut Vec<SmoothTriangle>) -> Result<Container<'a>, ObjError> {
+    let mut obj_data = ObjData::new(color);
 
-      } else if ObjFileParser::obj_line_type(&obj_line) == ObjLineType::Face {
-        ObjFileParser::parse_vertex_normal_face(&obj_line, color, &mut vertices, &mut normals, smooth_triangles);
-      
+    // Triangles are kept until whole file is read, as generated normals depend on every face
+    let mut face_triangles: Vec<[ObjFaceVertex; 3]> = Vec::new();
+    let mut face_materials: Vec<Material> = Vec::new();
+
+    for (line_index, line) in ObjFileParser::read_lines(&file_name)?.iter().enumerate() {
+      let obj_line = ObjFileParser::strip_comment(line);
+
+      let result = match ObjFileParser::obj_line_type(obj_line) {
+        ObjLineType::Face => ObjFileParser::parse_face_triangles(obj_line, &obj_data, &mut face_triangles),
+        line_type => ObjFileParser::parse_statement(line_type, obj_line, &file_name, &mut obj_data)
+      };
+
+      result.map_err(|message| ObjError::parse(&file_name, line_index + 1, message))?;
+      face_materials.resize(face_triangles.len(), obj_data.current_material.clone());
+      obj_data.assign_shapes(face_triangles.len());
+    }
+
+    let faces = face_triangles.iter().map(|corners| [corners[0].vertex, corners[1].vertex, corners[2].vertex]).collect::<Vec<[usize; 3]>>();
+    let generated_normals = normal_generator.generate(&obj_data.vertices, &faces);
+
+    for ((corners, material), generated_normal) in face_triangles.iter().zip(face_materials).zip(generated_normals) {
+      let [face_vertex_1, face_vertex_2, face_vertex_3] = *corners;
+
+      let mut normals = generated_normal;
+      if let (true, Some(normal_1), Some(normal_2), Some(normal_3)) = (use_file_normals, face_vertex_1.normal, face_vertex_2.normal, face_vertex_3.normal) {
+        normals = [obj_data.normals[normal_1], obj_data.normals[normal_2], obj_data.normals[normal_3]];
       }
+
+      let point_1 = obj_data.vertices[face_vertex_1.vertex];
+      let point_2 = obj_data.vertices[face_vertex_2.vertex];
+      let point_3 = obj_data.vertices[face_vertex_3.vertex];
+
+      let mut smooth_triangle = SmoothTriangle::new(point_1, point_2, point_3, normals[0], normals[1], normals[2], Matrix4x4::identity(), material);
+
+      // Texture coordinates only used when every vertex references one
+      if let (Some(uv_1), Some(uv_2), Some(uv_3)) = (face_vertex_1.texture_vertex, face_vertex_2.texture_vertex, face_vertex_3.texture_vertex) {
+        smooth_triangle.set_texture_coordinates(obj_data.texture_vertices[uv_1], obj_data.texture_vertices[uv_2], obj_data.texture_vertices[uv_3]);
+      }
+
+      smooth_triangles.push(smooth_triangle);
     }
 
     let mut container_shape_references = Vec::new();
-    for smooth_triangle in smooth_triangles {    
+    for smooth_triangle in smooth_triangles {
       container_shape_references.push(smooth_triangle as &dyn Shape);
     }
 
-    Container::new(transform, container_shape_references)
+    Ok(obj_data.build_container(transform, container_shape_references))
   }
 
-  pub fn obj_line_type(obj_line: &String) -> ObjLineType {
-    let chars = obj_line.chars();
-    
-    // Must have at least two characters
-    if chars.count() < 3 {
-      return ObjLineType::Invalid;
+  // Loads whole file as a single mesh with shared buffers, groups are not kept
+  pub fn load_mesh(file_name: String, transform: Matrix4x4, color: Color) -> Result<Mesh, ObjError> {
+    let mut obj_data = ObjData::new(color);
+
+    let mut triangles: Vec<MeshIndices> = Vec::new();
+    let mut materials = vec![obj_data.current_material.clone()];
+    let mut material_indices: HashMap<String, usize> = HashMap::new();
+    let mut current_material_index = 0;
+
+    for (line_index, line) in ObjFileParser::read_lines(&file_name)?.iter().enumerate() {
+      let obj_line = ObjFileParser::strip_comment(line);
+
+      let result = match ObjFileParser::obj_line_type(obj_line) {
+        ObjLineType::Face => ObjFileParser::parse_mesh_face(obj_line, current_material_index, &obj_data, &mut triangles),
+        ObjLineType::UseMaterial => {
+          let result = ObjFileParser::parse_statement(ObjLineType::UseMaterial, obj_line, &file_name, &mut obj_data);
+
+          // Each material is stored once, however many times it is used
+          let material_name = obj_line.trim()["usemtl".len()..].trim().to_string();
+          current_material_index = *material_indices.entry(material_name).or_insert_with(|| {
+            materials.push(obj_data.current_material.clone());
+            materials.len() - 1
+          });
+
+          result
+        },
+        line_type => ObjFileParser::parse_statement(line_type, obj_line, &file_name, &mut obj_data)
+      };
+
+      result.map_err(|message| ObjError::parse(&file_name, line_index + 1, message))?;
+    }
+
+    Ok(Mesh::new(obj_data.vertices, obj_data.normals, obj_data.texture_vertices, triangles, transform, materials))
+  }
+
+  pub fn parse_mesh_face(obj_line: &str, material: usize, obj_data: &ObjData, triangles: &mut Vec<MeshIndices>) -> Result<(), String> {
+    let mut face_triangles = Vec::new();
+    ObjFileParser::parse_face_triangles(obj_line, obj_data, &mut face_triangles)?;
+
+    for corners in face_triangles {
+      let mut triangle = MeshIndices::new([corners[0].vertex, corners[1].vertex, corners[2].vertex]);
+      triangle.material = material;
+
+      // Normals and texture coordinates only used when every vertex references one
+      if let (Some(normal_1), Some(normal_2), Some(normal_3)) = (corners[0].normal, corners[1].normal, corners[2].normal) {
+        triangle.normals = Some([normal_1, normal_2, normal_3]);
+      }
+
+      if let (Some(uv_1), Some(uv_2), Some(uv_3)) = (corners[0].texture_vertex, corners[1].texture_vertex, corners[2].texture_vertex) {
+        triangle.texture_vertices = Some([uv_1, uv_2, uv_3]);
+      }
+
+      triangles.push(triangle);
     }
-    
-    let bytes = obj_line.as_bytes();
 
-    if (bytes[0] as char) == 'v' && (bytes[1] as char) == 'n' {
-      return ObjLineType::VertexNormal;
+    Ok(())
+  }
+
+  pub fn parse_face_triangles(obj_line: &str, obj_data: &ObjData, face_triangles: &mut Vec<[ObjFaceVertex; 3]>) -> Result<(), String> {
+    let face_vertices = ObjFileParser::parse_face_vertices(obj_line, obj_data.vertices.len(), Some(obj_data.texture_vertices.len()), Some(obj_data.normals.len()))?;
 
-    } else if (bytes[0] as char) == 'v' {
-      return ObjLineType::Vertex;
+    // Split into triangles if face contains more than three vertices
+    for i in 1..(face_vertices.len() - 1) {
+      face_triangles.push([face_vertices[0], face_vertices[i], face_vertices[i + 1]]);
+    }
 
-    } else if (bytes[0] as char) == 'f' {
-      return ObjLineType::Face;
+    Ok(())
+  }
+
+  pub fn read_lines(file_name: &str) -> Result<Vec<String>, ObjError> {
+    let obj_file = File::open(file_name).map_err(|error| ObjError::Io { file_name: file_name.to_string(), error })?;
+    let reader = BufReader::new(obj_file);
 
+    let mut obj_lines = Vec::new();
+    for line in reader.lines() {
+      obj_lines.push(line.map_err(|error| ObjError::Io { file_name: file_name.to_string(), error })?);
+    }
+
+    Ok(obj_lines)
+  }
+
+  pub fn strip_comment(obj_line: &str) -> &str {
+    match obj_line.find('#') {
+      Some(comment_start) => &obj_line[..comment_start],
+      None => obj_line
+    }
+  }
+
+  pub fn parse_statement(line_type: ObjLineType, obj_line: &str, file_name: &str, obj_data: &mut ObjData) -> Result<(), String> {
+    match line_type {
+      ObjLineType::Vertex => obj_data.vertices.push(ObjFileParser::parse_vertex(obj_line)?),
+      ObjLineType::TextureVertex => obj_data.texture_vertices.push(ObjFileParser::parse_texture_vertex(obj_line)?),
+      ObjLineType::VertexNormal => obj_data.normals.push(ObjFileParser::parse_vertex_normal(obj_line)?),
+      ObjLineType::MaterialLibrary => ObjFileParser::load_material_libraries(obj_line, file_name, &mut obj_data.materials)?,
+      ObjLineType::UseMaterial => obj_data.current_material = ObjFileParser::use_material(obj_line, obj_data.color, &obj_data.materials),
+      ObjLineType::Group => obj_data.start_group(obj_line),
+      // Unsupported statements are skipped
+      _ => {}
+    }
+
+    Ok(())
+  }
+
+  pub fn obj_line_type(obj_line: &str) -> ObjLineType {
+    let keyword = match obj_line.split_whitespace().next() {
+      Some(keyword) => keyword,
+      None => return ObjLineType::Invalid
+    };
+
+    match keyword {
+      "v" => ObjLineType::Vertex,
+      "vt" => ObjLineType::TextureVertex,
+      "vn" => ObjLineType::VertexNormal,
+      "f" => ObjLineType::Face,
+      "g" | "o" => ObjLineType::Group,
+      "mtllib" => ObjLineType::MaterialLibrary,
+      "usemtl" => ObjLineType::UseMaterial,
+      _ => ObjLineType::Invalid
+    }
+  }
+
+  pub fn parse_values(obj_line: &str, minimum_count: usize) -> Result<Vec<f64>, String> {
+    let mut values = Vec::new();
+
+    for value_text in obj_line.split_whitespace().skip(1) {
+      match value_text.parse::<f64>() {
+        Ok(value) if value.is_finite() => values.push(value),
+        Ok(_) => return Err(format!("'{}' is not a finite number", value_text)),
+        Err(_) => return Err(format!("invalid number '{}'", value_text))
+      }
+    }
+
+    if values.len() < minimum_count {
+      return Err(format!("expected at least {} values, found {}", minimum_count, values.len()));
+    }
+
+    Ok(values)
+  }
+
+  pub fn parse_vertex(obj_line: &str) -> Result<Point, String> {
+    // Optional w and vertex color components are ignored
+    let values = ObjFileParser::parse_values(obj_line, 3)?;
+
+    Ok(Point::new(values[0], values[1], values[2]))
+  }
+
+  pub fn parse_vertex_normal(obj_line: &str) -> Result<Vector, String> {
+    let values = ObjFileParser::parse_values(obj_line, 3)?;
+
+    Ok(Vector::new(values[0], values[1], values[2]))
+  }
+
+  pub fn parse_texture_vertex(obj_line: &str) -> Result<(f64, f64), String> {
+    let values = ObjFileParser::parse_values(obj_line, 1)?;
+
+    // Optional third coordinate is ignored, v defaults to zero
+    if values.len() < 2 {
+      return Ok((values[0], 0.0));
+    }
+
+    Ok((values[0], values[1]))
+  }
+
+  pub fn default_material(color: Color) -> Material {
+    Material::solid(0.1, 0.3, 0.3, 4.0, 0.0, 0.0, 1.0, color, Matrix4x4::identity())
+  }
+
+  pub fn load_material_libraries(obj_line: &str, obj_file_name: &str, materials: &mut HashMap<String, Material>) -> Result<(), String> {
+    // Material libraries are relative to obj file
+    let library_directory = Path::new(obj_file_name).parent().unwrap_or_else(|| Path::new(""));
+
+    for library_name in obj_line.split_whitespace().skip(1) {
+      let library_path = library_directory.join(library_name);
+
+      match MtlFileParser::load_file(&library_path.to_string_lossy()) {
+        Ok(library_materials) => materials.extend(library_materials),
+        Err(error) => return Err(format!("could not load material library ({})", error))
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn use_material(obj_line: &str, color: Color, materials: &HashMap<String, Material>) -> Material {
+    let material_name = obj_line.trim()["usemtl".len()..].trim();
+
+    // Unknown materials fall back to default material
+    match materials.get(material_name) {
+      Some(material) => material.clone(),
+      None => ObjFileParser::default_material(color)
+    }
+  }
+
+  pub fn resolve_index(index_text: &str, count: usize, element_name: &str) -> Result<usize, String> {
+    let index = match index_text.parse::<i64>() {
+      Ok(index) => index,
+      Err(_) => return Err(format!("invalid {} index '{}'", element_name, index_text))
+    };
+
+    // Positive indices start at one, negative indices count back from most recent element
+    let resolved_index = if index > 0 {
+      index - 1
     } else {
-      return ObjLineType::Invalid;
+      count as i64 + index
+    };
 
+    if index == 0 || resolved_index < 0 || resolved_index >= count as i64 {
+      return Err(format!("{} index {} out of range, {} defined", element_name, index, count));
     }
+
+    Ok(resolved_index as usize)
   }
 
-  pub fn parse_vertex(obj_line: &String) -> Point {
-    let vertex_line = obj_line.clone();
+  pub fn parse_face_vertices(obj_line: &str, vertex_count: usize, texture_vertex_count: Option<usize>, normal_count: Option<usize>) -> Result<Vec<ObjFaceVertex>, String> {
+    let mut face_vertices = Vec::new();
+
+    // Each reference takes the form v, v/vt, v//vn or v/vt/vn, unused indices are not resolved
+    for reference in obj_line.split_whitespace().skip(1) {
+      let index_collection = reference.split('/').collect::<Vec<&str>>();
+
+      if index_collection.len() > 3 || index_collection[0].is_empty() {
+        return Err(format!("invalid face vertex '{}'", reference));
+      }
+
+      let vertex = ObjFileParser::resolve_index(index_collection[0], vertex_count, "vertex")?;
 
-    let vertex_parts = vertex_line.split(" ").collect::<Vec<&str>>();
-    
-    // Must have exactly four parts
-    Point::new(
-      vertex_parts[1].parse::<f64>().unwrap(),
-      vertex_parts[2].parse::<f64>().unwrap(),
-      vertex_parts[3].parse::<f64>().unwrap()
-    )
+      let mut texture_vertex = None;
+      if let Some(count) = texture_vertex_count {
+        if index_collection.len() > 1 && !index_collection[1].is_empty() {
+          texture_vertex = Some(ObjFileParser::resolve_index(index_collection[1], count, "texture vertex")?);
+        }
+      }
+
+      let mut normal = None;
+      if let Some(count) = normal_count {
+        if index_collection.len() > 2 && !index_collection[2].is_empty() {
+          normal = Some(ObjFileParser::resolve_index(index_collection[2], count, "normal")?);
+        }
+      }
+
+      face_vertices.push(ObjFaceVertex { vertex, texture_vertex, normal });
+    }
+
+    if face_vertices.len() < 3 {
+      return Err(format!("face requires at least 3 vertices, found {}", face_vertices.len()));
+    }
+
+    Ok(face_vertices)
   }
 
-  pub fn parse_vertex_normal(obj_line: &String) -> Vector {
-    let vertex_normal_line = obj_line.clone();
+  pub fn parse_face(obj_line: &str, color: Color, vertices: &[Point], triangles: &mut Vec<Triangle>) -> Result<(), String> {
+    let face_vertices = ObjFileParser::parse_face_vertices(obj_line, vertices.len(), None, None)?;
 
-    let vertex_normal_parts = vertex_normal_line.split(" ").collect::<Vec<&str>>();
-    
-    // Must have exactly four parts
-    Vector::new(
-      vertex_normal_parts[1].parse::<f64>().unwrap(),
-      vertex_normal_parts[2].parse::<f64>().unwrap(),
-      vertex_normal_parts[3].parse::<f64>().unwrap()
-    )
+    ObjFileParser::triangulate_face(&face_vertices, &ObjFileParser::default_material(color), vertices, &[], triangles);
+
+    Ok(())
   }
 
-  pub fn parse_face(obj_line: &String, color: Color, vertices: &mut Vec::<Point>, triangles: &mut Vec::<Triangle>) {
-    let face_line = obj_line.clone();
+  pub fn parse_face_with_material(obj_line: &str, material: &Material, vertices: &[Point], texture_vertices: &[(f64, f64)], triangles: &mut Vec<Triangle>) -> Result<(), String> {
+    let face_vertices = ObjFileParser::parse_face_vertices(obj_line, vertices.len(), Some(texture_vertices.len()), None)?;
+
+    ObjFileParser::triangulate_face(&face_vertices, material, vertices, texture_vertices, triangles);
+
+    Ok(())
+  }
+
+  pub fn triangulate_face(face_vertices: &[ObjFaceVertex], material: &Material, vertices: &[Point], texture_vertices: &[(f64, f64)], triangles: &mut Vec<Triangle>) {
 
-    let face_indices = face_line.split(" ").collect::<Vec<&str>>();
-    
     // Split into triangles if face contains more than three vertices
-    for i in 2..(face_indices.len() - 1) {
-      let point_1 = vertices[face_indices[1].parse::<usize>().unwrap() - 1];
-      let point_2 = vertices[face_indices[i].parse::<usize>().unwrap() - 1];
-      let point_3 = vertices[face_indices[i + 1].parse::<usize>().unwrap() - 1];
-      
-      let triangle = Triangle::new(
-        point_1,
-        point_2,
-        point_3,
-        Matrix4x4::identity(), 
-        Material::solid(0.1, 0.3, 0.3, 4.0, 0.0, 0.0, 1.0, color, Matrix4x4::identity())
-      );
+    for i in 1..(face_vertices.len() - 1) {
+      let face_vertex_1 = face_vertices[0];
+      let face_vertex_2 = face_vertices[i];
+      let face_vertex_3 = face_vertices[i + 1];
+
+      let point_1 = vertices[face_vertex_1.vertex];
+      let point_2 = vertices[face_vertex_2.vertex];
+      let point_3 = vertices[face_vertex_3.vertex];
+
+      let mut triangle = Triangle::new(point_1, point_2, point_3, Matrix4x4::identity(), material.clone());
+
+      // Texture coordinates only used when every vertex references one
+      if let (Some(uv_1), Some(uv_2), Some(uv_3)) = (face_vertex_1.texture_vertex, face_vertex_2.texture_vertex, face_vertex_3.texture_vertex) {
+        triangle.set_texture_coordinates(texture_vertices[uv_1], texture_vertices[uv_2], texture_vertices[uv_3]);
+      }
 
       triangles.push(triangle);
     }
   }
 
-  pub fn parse_vertex_normal_face(obj_line: &String, color: Color, vertices: &mut Vec::<Point>, normals: &mut Vec::<Vector>, smooth_triangles: &mut Vec::<SmoothTriangle>) {
-    let face_line = obj_line.clone();
+  pub fn parse_vertex_normal_face(obj_line: &str, color: Color, vertices: &[Point], normals: &[Vector], smooth_triangles: &mut Vec<SmoothTriangle>) -> Result<(), String> {
+    let face_vertices = ObjFileParser::parse_face_vertices(obj_line, vertices.len(), None, Some(normals.len()))?;
+
+    ObjFileParser::triangulate_vertex_normal_face(&face_vertices, &ObjFileParser::default_material(color), vertices, &[], normals, smooth_triangles)
+  }
+
+  pub fn parse_vertex_normal_face_with_material(obj_line: &str, material: &Material, vertices: &[Point], texture_vertices: &[(f64, f64)], normals: &[Vector], smooth_triangles: &mut Vec<SmoothTriangle>) -> Result<(), String> {
+    let face_vertices = ObjFileParser::parse_face_vertices(obj_line, vertices.len(), Some(texture_vertices.len()), Some(normals.len()))?;
+
+    ObjFileParser::triangulate_vertex_normal_face(&face_vertices, material, vertices, texture_vertices, normals, smooth_triangles)
+  }
+
+  pub fn triangulate_vertex_normal_face(face_vertices: &[ObjFaceVertex], material: &Material, vertices: &[Point], texture_vertices: &[(f64, f64)], normals: &[Vector], smooth_triangles: &mut Vec<SmoothTriangle>) -> Result<(), String> {
+    let mut face_normals = Vec::new();
+    for face_vertex in face_vertices {
+      match face_vertex.normal {
+        Some(normal) => face_normals.push(normals[normal]),
+        None => return Err(String::from("face vertex has no normal index"))
+      }
+    }
 
-    let face_indices = face_line.split(" ").collect::<Vec<&str>>();
-    
     // Split into triangles if face contains more than three vertices
-    for i in 2..(face_indices.len() - 1) {
-      let index_collection_1 = face_indices[1].split("/").collect::<Vec<&str>>();
-      let index_collection_2 = face_indices[i].split("/").collect::<Vec<&str>>();
-      let index_collection_3 = face_indices[i + 1].split("/").collect::<Vec<&str>>();
-
-      let point_1 = vertices[index_collection_1[0].parse::<usize>().unwrap() - 1];
-      let point_2 = vertices[index_collection_2[0].parse::<usize>().unwrap() - 1];
-      let point_3 = vertices[index_collection_3[0].parse::<usize>().unwrap() - 1];
+    for i in 1..(face_vertices.len() - 1) {
+      let face_vertex_1 = face_vertices[0];
+      let face_vertex_2 = face_vertices[i];
+      let face_vertex_3 = face_vertices[i + 1];
+
+      let point_1 = vertices[face_vertex_1.vertex];
+      let point_2 = vertices[face_vertex_2.vertex];
+      let point_3 = vertices[face_vertex_3.vertex];
       
-      let normal_1 = normals[index_collection_1[2].parse::<usize>().unwrap() - 1];
-      let normal_2 = normals[index_collection_2[2].parse::<usize>().unwrap() - 1];
-      let normal_3 = normals[index_collection_3[2].parse::<usize>().unwrap() - 1];
-
-      let smooth_triangle = SmoothTriangle::new(
-        point_1,
-        point_2,
-        point_3,
-        normal_1,
-        normal_2,
-        normal_3,
-        Matrix4x4::identity(), 
-        Material::solid(0.1, 0.3, 0.3, 4.0, 0.0, 0.0, 1.0, color, Matrix4x4::identity())
-      );
+      let normal_1 = face_normals[0];
+      let normal_2 = face_normals[i];
+      let normal_3 = face_normals[i + 1];
+
+      let mut smooth_triangle = SmoothTriangle::new(point_1, point_2, point_3, normal_1, normal_2, normal_3, Matrix4x4::identity(), material.clone());
+
+      // Texture coordinates only used when every vertex references one
+      if let (Some(uv_1), Some(uv_2), Some(uv_3)) = (face_vertex_1.texture_vertex, face_vertex_2.texture_vertex, face_vertex_3.texture_vertex) {
+        smooth_triangle.set_texture_coordinates(texture_vertices[uv_1], texture_vertices[uv_2], texture_vertices[uv_3]);
+      }
 
       smooth_triangles.push(smooth_triangle);
     }
+
+    Ok(())
   }
 }
@@ -1,16 +1,29 @@
 #[cfg(test)]
 mod tests {
+  use std::f64;
+
+  use std::fs;
+  use std::env;
+
   use crate::rendering::math::Point;
   use crate::rendering::math::Vector;
   
   use crate::rendering::math::Color;
 
+  use crate::rendering::math::Matrix4x4;
+
   use crate::rendering::shapes::Triangle;
   use crate::rendering::shapes::SmoothTriangle;
 
   use crate::rendering::ObjLineType;
+  use crate::rendering::ObjError;
+  use crate::rendering::ObjFaceVertex;
+  use crate::rendering::ObjData;
   use crate::rendering::ObjFileParser;
 
+  use crate::rendering::NormalWeighting;
+  use crate::rendering::NormalGenerator;
+
   #[test]
   fn ignores_lines_from_obj_file_that_do_not_allowed_pattern() {
     let gibberish_lines = vec![
@@ -37,13 +50,13 @@ mod tests {
     ];
 
     assert!(ObjFileParser::obj_line_type(&vertex_lines[0]) == ObjLineType::Vertex);
-    assert!(ObjFileParser::parse_vertex(&vertex_lines[0]) == Point::new(0.1, 0.2, 0.3));
+    assert!(ObjFileParser::parse_vertex(&vertex_lines[0]).unwrap() == Point::new(0.1, 0.2, 0.3));
 
     assert!(ObjFileParser::obj_line_type(&vertex_lines[1]) == ObjLineType::Vertex);
-    assert!(ObjFileParser::parse_vertex(&vertex_lines[1]) == Point::new(1.1, -1.2, 1.3));
+    assert!(ObjFileParser::parse_vertex(&vertex_lines[1]).unwrap() == Point::new(1.1, -1.2, 1.3));
 
     assert!(ObjFileParser::obj_line_type(&vertex_lines[2]) == ObjLineType::Vertex);
-    assert!(ObjFileParser::parse_vertex(&vertex_lines[2]) == Point::new(-2.1, 2.2, -2.3));
+    assert!(ObjFileParser::parse_vertex(&vertex_lines[2]).unwrap() == Point::new(-2.1, 2.2, -2.3));
   }
 
   #[test]
@@ -55,13 +68,13 @@ mod tests {
     ];
 
     assert!(ObjFileParser::obj_line_type(&vertex_normal_lines[0]) == ObjLineType::VertexNormal);
-    assert!(ObjFileParser::parse_vertex_normal(&vertex_normal_lines[0]) == Vector::new(3.1, 3.2, 3.3));
+    assert!(ObjFileParser::parse_vertex_normal(&vertex_normal_lines[0]).unwrap() == Vector::new(3.1, 3.2, 3.3));
 
     assert!(ObjFileParser::obj_line_type(&vertex_normal_lines[1]) == ObjLineType::VertexNormal);
-    assert!(ObjFileParser::parse_vertex_normal(&vertex_normal_lines[1]) == Vector::new(4.1, -4.2, 4.3));
+    assert!(ObjFileParser::parse_vertex_normal(&vertex_normal_lines[1]).unwrap() == Vector::new(4.1, -4.2, 4.3));
 
     assert!(ObjFileParser::obj_line_type(&vertex_normal_lines[2]) == ObjLineType::VertexNormal);
-    assert!(ObjFileParser::parse_vertex_normal(&vertex_normal_lines[2]) == Vector::new(-5.1, 5.2, -5.3));
+    assert!(ObjFileParser::parse_vertex_normal(&vertex_normal_lines[2]).unwrap() == Vector::new(-5.1, 5.2, -5.3));
   }
 
   #[test]
@@ -77,11 +90,11 @@ mod tests {
       "f 2 1 3".to_string()
     ];
 
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[0]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[1]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[2]));
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[0]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[1]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[2]).unwrap());
 
-    ObjFileParser::parse_face(&obj_lines[4], Color::new(1.0, 1.0, 1.0, 1.0), &mut vertices, &mut triangles);
+    ObjFileParser::parse_face(&obj_lines[4], Color::new(1.0, 1.0, 1.0, 1.0), &vertices, &mut triangles).unwrap();
   
     let triangle = &triangles[0];
 
@@ -105,13 +118,13 @@ mod tests {
       "f 1 2 3 4 5".to_string()
     ];
 
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[0]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[1]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[2]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[3]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[4]));
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[0]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[1]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[2]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[3]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[4]).unwrap());
 
-    ObjFileParser::parse_face(&obj_lines[6], Color::new(1.0, 1.0, 1.0, 1.0), &mut vertices, &mut triangles);
+    ObjFileParser::parse_face(&obj_lines[6], Color::new(1.0, 1.0, 1.0, 1.0), &vertices, &mut triangles).unwrap();
   
     assert_eq!(triangles.len(), 3);
 
@@ -153,17 +166,17 @@ mod tests {
       "f 1/999/3 2/999/2 3/999/1 4/999/2 5/999/3".to_string()
     ];
 
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[0]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[1]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[2]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[3]));
-    vertices.push(ObjFileParser::parse_vertex(&obj_lines[4]));
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[0]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[1]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[2]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[3]).unwrap());
+    vertices.push(ObjFileParser::parse_vertex(&obj_lines[4]).unwrap());
 
-    normals.push(ObjFileParser::parse_vertex_normal(&obj_lines[6]));
-    normals.push(ObjFileParser::parse_vertex_normal(&obj_lines[7]));
-    normals.push(ObjFileParser::parse_vertex_normal(&obj_lines[8]));
+    normals.push(ObjFileParser::parse_vertex_normal(&obj_lines[6]).unwrap());
+    normals.push(ObjFileParser::parse_vertex_normal(&obj_lines[7]).unwrap());
+    normals.push(ObjFileParser::parse_vertex_normal(&obj_lines[8]).unwrap());
 
-    ObjFileParser::parse_vertex_normal_face(&obj_lines[9], Color::new(1.0, 1.0, 1.0, 1.0), &mut vertices, &mut normals, &mut smooth_triangles);
+    ObjFileParser::parse_vertex_normal_face(&obj_lines[9], Color::new(1.0, 1.0, 1.0, 1.0), &vertices, &normals, &mut smooth_triangles).unwrap();
   
     assert_eq!(smooth_triangles.len(), 3);
 
@@ -194,4 +207,394 @@ mod tests {
     assert!(smooth_triangle_3.normal_2 == Vector::new(1.0, 0.0, 0.0));
     assert!(smooth_triangle_3.normal_3 == Vector::new(0.0, 1.0, 0.0));
   }
+
+  #[test]
+  fn identifies_texture_vertex_and_material_lines() {
+    assert!(ObjFileParser::obj_line_type("vt 0.5 0.25") == ObjLineType::TextureVertex);
+    assert!(ObjFileParser::obj_line_type("mtllib scene.mtl") == ObjLineType::MaterialLibrary);
+    assert!(ObjFileParser::obj_line_type("usemtl red") == ObjLineType::UseMaterial);
+  }
+
+  #[test]
+  fn parses_texture_vertex_lines_into_coordinates() {
+    assert!(ObjFileParser::parse_texture_vertex("vt 0.5 0.25").unwrap() == (0.5, 0.25));
+    assert!(ObjFileParser::parse_texture_vertex("vt 0.5 0.25 0.0").unwrap() == (0.5, 0.25));
+    assert!(ObjFileParser::parse_texture_vertex("vt 0.75").unwrap() == (0.75, 0.0));
+  }
+
+  #[test]
+  fn creates_textured_triangles_from_face_lines_with_texture_vertices() {
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let vertices = vec![Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)];
+    let texture_vertices = vec![(0.5, 1.0), (0.0, 0.0), (1.0, 0.0)];
+
+    let material = ObjFileParser::default_material(Color::new(1.0, 0.0, 0.0, 1.0));
+
+    ObjFileParser::parse_face_with_material("f 1/1 2/2 3/3", &material, &vertices, &texture_vertices, &mut triangles).unwrap();
+
+    let triangle = &triangles[0];
+
+    assert!(triangle.uv_1 == (0.5, 1.0));
+    assert!(triangle.uv_2 == (0.0, 0.0));
+    assert!(triangle.uv_3 == (1.0, 0.0));
+    assert!(triangle.material.color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn faces_use_materials_from_material_library() {
+    let directory = env::temp_dir().join("jb_tracer_obj_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    fs::write(directory.join("colors.mtl"), "newmtl red\nKd 1.0 0.0 0.0\nNs 50.0\n\nnewmtl blue\nKd 0.0 0.0 1.0\n").unwrap();
+    fs::write(directory.join("colors.obj"), [
+      "mtllib colors.mtl",
+      "v 0.0 1.0 0.0",
+      "v -1.0 0.0 0.0",
+      "v 1.0 0.0 0.0",
+      "f 1 2 3",
+      "usemtl red",
+      "f 1 2 3",
+      "usemtl blue",
+      "f 1 2 3",
+      "usemtl unknown",
+      "f 1 2 3",
+      ""
+    ].join("\n")).unwrap();
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let file_name = directory.join("colors.obj").to_string_lossy().to_string();
+    let container = ObjFileParser::load_file(file_name, Matrix4x4::identity(), Color::new(0.5, 0.5, 0.5, 1.0), &mut triangles).unwrap();
+
+    assert_eq!(container.shapes.len(), 4);
+    assert!(container.shapes[0].get_material().color_1 == Color::new(0.5, 0.5, 0.5, 1.0));
+    assert!(container.shapes[1].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(container.shapes[1].get_material().shininess, 50.0);
+    assert!(container.shapes[2].get_material().color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+    assert!(container.shapes[3].get_material().color_1 == Color::new(0.5, 0.5, 0.5, 1.0));
+  }
+
+  fn write_obj_file(file_name: &str, obj_lines: Vec<&str>) -> String {
+    let directory = env::temp_dir().join("jb_tracer_obj_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    let file_path = directory.join(file_name);
+    fs::write(&file_path, obj_lines.join("\n")).unwrap();
+
+    file_path.to_string_lossy().to_string()
+  }
+
+  #[test]
+  fn identifies_statements_separated_by_any_whitespace() {
+    assert!(ObjFileParser::obj_line_type("v\t0.1  0.2 0.3") == ObjLineType::Vertex);
+    assert!(ObjFileParser::obj_line_type("  vn 0 1 0") == ObjLineType::VertexNormal);
+    assert!(ObjFileParser::obj_line_type("f\t1 2 3") == ObjLineType::Face);
+    assert!(ObjFileParser::obj_line_type("g wheels") == ObjLineType::Group);
+    assert!(ObjFileParser::obj_line_type("o car") == ObjLineType::Group);
+    assert!(ObjFileParser::obj_line_type("vp 0.1 0.2") == ObjLineType::Invalid);
+    assert!(ObjFileParser::obj_line_type("s off") == ObjLineType::Invalid);
+    assert!(ObjFileParser::obj_line_type("# v 1 2 3") == ObjLineType::Invalid);
+
+    assert!(ObjFileParser::parse_vertex("v\t0.1   0.2\t 0.3 1.0").unwrap() == Point::new(0.1, 0.2, 0.3));
+  }
+
+  #[test]
+  fn strips_trailing_comments() {
+    assert_eq!(ObjFileParser::strip_comment("v 1 2 3 # corner"), "v 1 2 3 ");
+    assert_eq!(ObjFileParser::strip_comment("# whole line"), "");
+    assert_eq!(ObjFileParser::strip_comment("f 1 2 3"), "f 1 2 3");
+  }
+
+  #[test]
+  fn reports_malformed_statements() {
+    assert!(ObjFileParser::parse_vertex("v 1.0 2.0").is_err());
+    assert!(ObjFileParser::parse_vertex("v 1.0 a 2.0").is_err());
+    assert!(ObjFileParser::parse_vertex("v 1.0 NaN 2.0").is_err());
+    assert!(ObjFileParser::parse_vertex_normal("vn inf 0.0 0.0").is_err());
+    assert!(ObjFileParser::parse_vertex_normal("vn").is_err());
+    assert!(ObjFileParser::parse_face_vertices("f 1 2", 3, None, None).is_err());
+    assert!(ObjFileParser::parse_face_vertices("f 1 2 4", 3, None, None).is_err());
+    assert!(ObjFileParser::parse_face_vertices("f 0 1 2", 3, None, None).is_err());
+    assert!(ObjFileParser::parse_face_vertices("f 1 2 -4", 3, None, None).is_err());
+    assert!(ObjFileParser::parse_face_vertices("f 1/1 2/1 3/a", 3, Some(1), None).is_err());
+    assert!(ObjFileParser::parse_face_vertices("f 1/1/1/1 2 3", 3, None, None).is_err());
+  }
+
+  #[test]
+  fn parses_every_face_vertex_form() {
+    let face_vertices = ObjFileParser::parse_face_vertices("f 1 2/1 3//2 4/2/1", 4, Some(2), Some(2)).unwrap();
+
+    assert!(face_vertices[0] == ObjFaceVertex { vertex: 0, texture_vertex: None, normal: None });
+    assert!(face_vertices[1] == ObjFaceVertex { vertex: 1, texture_vertex: Some(0), normal: None });
+    assert!(face_vertices[2] == ObjFaceVertex { vertex: 2, texture_vertex: None, normal: Some(1) });
+    assert!(face_vertices[3] == ObjFaceVertex { vertex: 3, texture_vertex: Some(1), normal: Some(0) });
+  }
+
+  #[test]
+  fn resolves_negative_indices_relative_to_defined_elements() {
+    let face_vertices = ObjFileParser::parse_face_vertices("f -3/-1/-2 -2/-1/-2 -1/-1/-1", 5, Some(1), Some(2)).unwrap();
+
+    assert_eq!(face_vertices[0].vertex, 2);
+    assert_eq!(face_vertices[1].vertex, 3);
+    assert_eq!(face_vertices[2].vertex, 4);
+    assert_eq!(face_vertices[0].texture_vertex, Some(0));
+    assert_eq!(face_vertices[0].normal, Some(0));
+    assert_eq!(face_vertices[2].normal, Some(1));
+  }
+
+  #[test]
+  fn ignores_indices_not_used_by_face_type() {
+    let face_vertices = ObjFileParser::parse_face_vertices("f 1/7/9 2/7/9 3/7/9", 3, None, None).unwrap();
+
+    assert_eq!(face_vertices[0].texture_vertex, None);
+    assert_eq!(face_vertices[0].normal, None);
+  }
+
+  #[test]
+  fn loads_file_with_irregular_formatting() {
+    let file_name = write_obj_file("irregular.obj", vec![
+      "# exported model",
+      "o model",
+      "v  -1.0\t1.0 0.0",
+      "v -1.0 0.0 0.0   # second",
+      "v\t1.0 0.0 0.0",
+      "v 1.0 1.0 0.0 1.0",
+      "vt 0.0 1.0",
+      "vt 0.0 0.0",
+      "vn 0.0 0.0 1.0",
+      "vp 0.5 0.5",
+      "s 1",
+      "",
+      "g front",
+      "f 1/1/1 2/2/1 3//1",
+      "f -4//-1\t-2//-1  -1//-1",
+      "l 1 2"
+    ]);
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let container = ObjFileParser::load_file(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles).unwrap();
+
+    assert_eq!(container.child("front").unwrap().shapes.len(), 2);
+    assert!(triangles[1].point_1 == Point::new(-1.0, 1.0, 0.0));
+    assert!(triangles[1].point_2 == Point::new(1.0, 0.0, 0.0));
+    assert!(triangles[1].point_3 == Point::new(1.0, 1.0, 0.0));
+  }
+
+  #[test]
+  fn loads_smooth_triangles_from_vertex_normal_face_forms() {
+    let file_name = write_obj_file("normals.obj", vec![
+      "v 0.0 1.0 0.0",
+      "v -1.0 0.0 0.0",
+      "v 1.0 0.0 0.0",
+      "vt 0.5 1.0",
+      "vn 0.0 0.0 -1.0",
+      "vn 0.0 1.0 0.0",
+      "f 1//2 2//1 3//1",
+      "f 1/1/2 2/1/1 3/1/1"
+    ]);
+
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    ObjFileParser::load_file_with_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut smooth_triangles).unwrap();
+
+    assert_eq!(smooth_triangles.len(), 2);
+    assert!(smooth_triangles[0].normal_1 == Vector::new(0.0, 1.0, 0.0));
+    assert!(smooth_triangles[0].normal_2 == Vector::new(0.0, 0.0, -1.0));
+    assert!(smooth_triangles[1].uv_1 == (0.5, 1.0));
+  }
+
+  #[test]
+  fn reports_errors_with_file_name_and_line_number() {
+    let file_name = write_obj_file("broken.obj", vec![
+      "v 0.0 1.0 0.0",
+      "v -1.0 0.0 0.0",
+      "v 1.0 0.0",
+      "f 1 2 3"
+    ]);
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let result = ObjFileParser::load_file(file_name.clone(), Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles);
+
+    match result {
+      Err(ObjError::Parse { file_name: error_file_name, line_number, message: _ }) => {
+        assert_eq!(error_file_name, file_name);
+        assert_eq!(line_number, 3);
+      },
+      _ => panic!("Expected parse error")
+    }
+  }
+
+  #[test]
+  fn generates_normals_for_faces_without_normals_when_loading_normals() {
+    let file_name = write_obj_file("missing_normals.obj", vec![
+      "v 0.0 1.0 0.0",
+      "v -1.0 0.0 0.0",
+      "v 1.0 0.0 0.0",
+      "vn 1.0 0.0 0.0",
+      "f 1 2 3",
+      "f 1//1 3//1 2//1"
+    ]);
+
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    ObjFileParser::load_file_with_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut smooth_triangles).unwrap();
+
+    assert_eq!(smooth_triangles.len(), 2);
+    assert!(smooth_triangles[0].normal_1 == Vector::new(0.0, 0.0, 1.0));
+    assert!(smooth_triangles[0].normal_2 == Vector::new(0.0, 0.0, 1.0));
+    assert!(smooth_triangles[0].normal_3 == Vector::new(0.0, 0.0, 1.0));
+    assert!(smooth_triangles[1].normal_1 == Vector::new(1.0, 0.0, 0.0));
+  }
+
+  #[test]
+  fn loads_smooth_triangles_with_generated_normals() {
+    let file_name = write_obj_file("generated_normals.obj", vec![
+      "v -1.0 0.0 0.0",
+      "v 1.0 0.0 0.0",
+      "v 0.0 0.0 1.0",
+      "v 0.0 1.0 0.0",
+      "vt 0.0 0.0",
+      "vn 0.0 1.0 0.0",
+      "f 1/1/1 2/1/1 3/1/1",
+      "f 2 1 4"
+    ]);
+
+    // File normals are replaced
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    let normal_generator = NormalGenerator::smooth(NormalWeighting::Area);
+    ObjFileParser::load_file_with_generated_normals(file_name.clone(), Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &normal_generator, &mut smooth_triangles).unwrap();
+
+    let shared = Vector::new(0.0, -1.0, -1.0).normalize();
+
+    assert_eq!(smooth_triangles.len(), 2);
+    assert!((smooth_triangles[0].normal_1.y - shared.y).abs() < 0.0001);
+    assert!((smooth_triangles[0].normal_1.z - shared.z).abs() < 0.0001);
+    assert!(smooth_triangles[0].normal_3 == Vector::new(0.0, -1.0, 0.0));
+    assert!(smooth_triangles[1].normal_3 == Vector::new(0.0, 0.0, -1.0));
+
+    // Faces folded at right angle stay sharp with smaller crease angle
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    let normal_generator = NormalGenerator::new(NormalWeighting::Angle, f64::consts::PI / 4.0);
+    ObjFileParser::load_file_with_generated_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &normal_generator, &mut smooth_triangles).unwrap();
+
+    assert!(smooth_triangles[0].normal_1 == Vector::new(0.0, -1.0, 0.0));
+    assert!(smooth_triangles[1].normal_1 == Vector::new(0.0, 0.0, -1.0));
+  }
+
+  #[test]
+  fn reports_missing_files() {
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let result = ObjFileParser::load_file("does_not_exist.obj".to_string(), Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles);
+
+    match result {
+      Err(ObjError::Io { file_name, error: _ }) => assert_eq!(file_name, "does_not_exist.obj"),
+      _ => panic!("Expected io error")
+    }
+  }
+
+  #[test]
+  fn starts_named_groups() {
+    let mut obj_data = ObjData::new(Color::new(1.0, 1.0, 1.0, 1.0));
+
+    obj_data.start_group("g wheels");
+    obj_data.start_group("o\tbody  ");
+    assert!(obj_data.groups == vec!["wheels".to_string(), "body".to_string()]);
+    assert_eq!(obj_data.current_group, Some(1));
+
+    obj_data.start_group("g wheels");
+    assert_eq!(obj_data.groups.len(), 2);
+    assert_eq!(obj_data.current_group, Some(0));
+
+    obj_data.start_group("g");
+    assert_eq!(obj_data.current_group, None);
+  }
+
+  #[test]
+  fn keeps_first_of_several_group_names() {
+    let mut obj_data = ObjData::new(Color::new(1.0, 1.0, 1.0, 1.0));
+
+    obj_data.start_group("g wheels  front left");
+    obj_data.start_group("g front");
+    assert!(obj_data.groups == vec!["wheels".to_string(), "front".to_string()]);
+
+    obj_data.start_group("g wheels rear");
+    assert_eq!(obj_data.groups.len(), 2);
+    assert_eq!(obj_data.current_group, Some(0));
+
+    // Object has one name, which may hold spaces
+    obj_data.start_group("o front bumper");
+    assert_eq!(obj_data.groups[2], "front bumper");
+  }
+
+  #[test]
+  fn loads_named_groups_as_child_containers() {
+    let file_name = write_obj_file("groups.obj", vec![
+      "v -1 1 0",
+      "v -1 0 0",
+      "v 1 0 0",
+      "v 1 1 0",
+      "f 1 2 3",
+      "g first_group",
+      "f 1 2 3",
+      "o second_group",
+      "f 1 3 4",
+      "f 1 2 4",
+      "g first_group",
+      "f 2 3 4"
+    ]);
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let container = ObjFileParser::load_file(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles).unwrap();
+
+    assert_eq!(container.shapes.len(), 1);
+    assert_eq!(container.children.len(), 2);
+
+    assert!(container.child("third_group").is_none());
+
+    let first_group_ids: Vec<u64> = container.child("first_group").unwrap().shapes.iter().map(|shape| shape.get_id()).collect();
+    let second_group_ids: Vec<u64> = container.child("second_group").unwrap().shapes.iter().map(|shape| shape.get_id()).collect();
+
+    assert!(first_group_ids == vec![triangles[1].id, triangles[4].id]);
+    assert!(second_group_ids == vec![triangles[2].id, triangles[3].id]);
+  }
+
+  #[test]
+  fn loads_file_as_mesh_with_shared_buffers() {
+    let directory = env::temp_dir().join("jb_tracer_obj_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    fs::write(directory.join("mesh_colors.mtl"), "newmtl red\nKd 1.0 0.0 0.0\n\nnewmtl blue\nKd 0.0 0.0 1.0\n").unwrap();
+
+    let file_name = write_obj_file("mesh.obj", vec![
+      "mtllib mesh_colors.mtl",
+      "v -1 1 0",
+      "v -1 0 0",
+      "v 1 0 0",
+      "v 1 1 0",
+      "vt 0 1",
+      "vn 0 0 -1",
+      "f 1 2 3",
+      "usemtl red",
+      "f 1/1/1 2/1/1 3/1/1 4/1/1",
+      "usemtl blue",
+      "f 1//1 2//1 3//1",
+      "usemtl red",
+      "f 2 3 4"
+    ]);
+
+    let mesh = ObjFileParser::load_mesh(file_name, Matrix4x4::identity(), Color::new(0.5, 0.5, 0.5, 1.0)).unwrap();
+
+    assert_eq!(mesh.faces.len(), 5);
+    assert_eq!(mesh.data.vertices.len(), 4);
+    assert_eq!(mesh.data.materials.len(), 3);
+    assert!(mesh.data.materials[0].color_1 == Color::new(0.5, 0.5, 0.5, 1.0));
+    assert!(mesh.data.materials[1].color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert!(mesh.data.materials[2].color_1 == Color::new(0.0, 0.0, 1.0, 1.0));
+
+    // Faces are reordered by BVH, so count rather than index them
+    let red_faces = mesh.faces.iter().filter(|face| face.material == 1).count();
+    let smooth_faces = mesh.faces.iter().filter(|face| face.normals.is_some()).count();
+    let textured_faces = mesh.faces.iter().filter(|face| face.texture_vertices.is_some()).count();
+
+    assert_eq!(red_faces, 3);
+    assert_eq!(smooth_faces, 3);
+    assert_eq!(textured_faces, 2);
+  }
 }
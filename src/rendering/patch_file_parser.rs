@@ -0,0 +1,184 @@
+use std::fmt;
+use std::error::Error;
+
+use std::fs;
+
+use crate::rendering::math::Point;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::Mesh;
+
+use crate::rendering::BezierPatch;
+
+use crate::rendering::ObjFileParser;
+
+#[derive(Debug)]
+pub enum PatchError {
+  Io { file_name: String, error: std::io::Error },
+  Parse { file_name: String, message: String }
+}
+
+impl PatchError {
+  pub fn parse(file_name: &str, message: String) -> PatchError {
+    PatchError::Parse { file_name: file_name.to_string(), message }
+  }
+}
+
+impl fmt::Display for PatchError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      PatchError::Io { file_name, error } => write!(f, "{}: {}", file_name, error),
+      PatchError::Parse { file_name, message } => write!(f, "{}: {}", file_name, message)
+    }
+  }
+}
+
+impl Error for PatchError {}
+
+// Reads classic Bezier patch files in two layouts, values may be separated by commas or whitespace:
+//   indexed, as used for the Utah teapot: patch count, one line of 16 vertex indices per patch starting from one, vertex count, one vertex per line
+//   bpt: patch count, then for each patch a "3 3" degree line followed by 16 control points
+pub struct PatchFileParser {}
+
+impl PatchFileParser {
+  pub fn load_mesh(file_name: String, tolerance: f64, transform: Matrix4x4, color: Color) -> Result<Mesh, PatchError> {
+    let patches = PatchFileParser::read_patches(&file_name)?;
+
+    Ok(BezierPatch::mesh(&patches, tolerance, transform, ObjFileParser::default_material(color)))
+  }
+
+  pub fn read_patches(file_name: &str) -> Result<Vec<BezierPatch>, PatchError> {
+    let patch_data = fs::read_to_string(file_name).map_err(|error| PatchError::Io { file_name: file_name.to_string(), error })?;
+
+    PatchFileParser::parse_patches(&patch_data).map_err(|message| PatchError::parse(file_name, message))
+  }
+
+  pub fn parse_patches(patch_data: &str) -> Result<Vec<BezierPatch>, String> {
+    let mut lines = patch_data.lines()
+      .enumerate()
+      .map(|(index, line)| (index + 1, PatchFileParser::split_values(line)))
+      .filter(|(_, values)| !values.is_empty());
+
+    let patch_count = match lines.next() {
+      Some((line_number, values)) => PatchFileParser::parse_count(&values, line_number)?,
+      None => return Err(String::from("File is empty"))
+    };
+
+    let mut lines = lines.peekable();
+
+    // Degree lines have two values where indexed patches have sixteen
+    let is_bpt = match lines.peek() {
+      Some((_, values)) => values.len() == 2,
+      None => return Err(String::from("File ends before its patches"))
+    };
+
+    if is_bpt {
+      PatchFileParser::parse_bpt(patch_count, &mut lines)
+    } else {
+      PatchFileParser::parse_indexed(patch_count, &mut lines)
+    }
+  }
+
+  pub fn parse_indexed(patch_count: usize, lines: &mut dyn Iterator<Item = (usize, Vec<&str>)>) -> Result<Vec<BezierPatch>, String> {
+    let mut patch_indices = Vec::with_capacity(patch_count);
+
+    for _ in 0..patch_count {
+      let (line_number, values) = lines.next().ok_or_else(|| String::from("File ends before its patches"))?;
+
+      if values.len() != 16 {
+        return Err(format!("Line {}: patch needs 16 vertex indices, found {}", line_number, values.len()));
+      }
+
+      let mut indices = [0; 16];
+      for (index, value) in values.iter().enumerate() {
+        indices[index] = value.parse::<usize>().map_err(|_| format!("Line {}: invalid vertex index {}", line_number, value))?;
+      }
+
+      patch_indices.push((line_number, indices));
+    }
+
+    let vertex_count = match lines.next() {
+      Some((line_number, values)) => PatchFileParser::parse_count(&values, line_number)?,
+      None => return Err(String::from("File ends before its vertex count"))
+    };
+
+    let mut vertices = Vec::with_capacity(vertex_count);
+    for _ in 0..vertex_count {
+      let (line_number, values) = lines.next().ok_or_else(|| String::from("File ends before its vertices"))?;
+
+      vertices.push(PatchFileParser::parse_point(&values, line_number)?);
+    }
+
+    let mut patches = Vec::with_capacity(patch_count);
+    for (line_number, indices) in patch_indices {
+      let mut control_points = [Point::empty(); 16];
+
+      for (index, vertex_index) in indices.iter().enumerate() {
+        if *vertex_index == 0 || *vertex_index > vertices.len() {
+          return Err(format!("Line {}: vertex index {} is out of range", line_number, vertex_index));
+        }
+
+        control_points[index] = vertices[vertex_index - 1];
+      }
+
+      patches.push(BezierPatch::new(control_points));
+    }
+
+    Ok(patches)
+  }
+
+  pub fn parse_bpt(patch_count: usize, lines: &mut dyn Iterator<Item = (usize, Vec<&str>)>) -> Result<Vec<BezierPatch>, String> {
+    let mut patches = Vec::with_capacity(patch_count);
+
+    for _ in 0..patch_count {
+      let (line_number, values) = lines.next().ok_or_else(|| String::from("File ends before its patches"))?;
+
+      if values != ["3", "3"] {
+        return Err(format!("Line {}: only bicubic patches are supported, found degree {}", line_number, values.join(" ")));
+      }
+
+      let mut control_points = [Point::empty(); 16];
+      for control_point in control_points.iter_mut() {
+        let (line_number, values) = lines.next().ok_or_else(|| String::from("File ends before its control points"))?;
+
+        *control_point = PatchFileParser::parse_point(&values, line_number)?;
+      }
+
+      patches.push(BezierPatch::new(control_points));
+    }
+
+    Ok(patches)
+  }
+
+  pub fn split_values(line: &str) -> Vec<&str> {
+    line.split(|character: char| character == ',' || character.is_whitespace()).filter(|value| !value.is_empty()).collect()
+  }
+
+  pub fn parse_count(values: &[&str], line_number: usize) -> Result<usize, String> {
+    if values.len() != 1 {
+      return Err(format!("Line {}: expected a single count", line_number));
+    }
+
+    values[0].parse::<usize>().map_err(|_| format!("Line {}: invalid count {}", line_number, values[0]))
+  }
+
+  pub fn parse_point(values: &[&str], line_number: usize) -> Result<Point, String> {
+    if values.len() != 3 {
+      return Err(format!("Line {}: point needs 3 values, found {}", line_number, values.len()));
+    }
+
+    let mut coordinates = [0.0; 3];
+    for (index, value) in values.iter().enumerate() {
+      coordinates[index] = value.parse::<f64>().map_err(|_| format!("Line {}: invalid number {}", line_number, value))?;
+
+      if !coordinates[index].is_finite() {
+        return Err(format!("Line {}: {} is not a finite number", line_number, value));
+      }
+    }
+
+    Ok(Point::new(coordinates[0], coordinates[1], coordinates[2]))
+  }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::env;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+
+  use crate::rendering::PatchError;
+  use crate::rendering::PatchFileParser;
+
+  use crate::rendering::Ray;
+
+  // Flat patch over unit square in xz plane, raised in its middle
+  fn control_point_lines(separator: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for row in 0..4 {
+      for column in 0..4 {
+        let height = if (1..3).contains(&row) && (1..3).contains(&column) { 1.0 } else { 0.0 };
+
+        lines.push(format!("{}{}{}{}{}", column as f64 / 3.0, separator, height, separator, row as f64 / 3.0));
+      }
+    }
+
+    lines
+  }
+
+  fn indexed_patch_file() -> String {
+    let indices: Vec<String> = (1..=16).map(|index| index.to_string()).collect();
+
+    format!("1\n{}\n16\n{}\n", indices.join(","), control_point_lines(",").join("\n"))
+  }
+
+  fn write_patch_file(file_name: &str, patch_data: &str) -> String {
+    let directory = env::temp_dir().join("jb_tracer_patch_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    let file_path = directory.join(file_name);
+    fs::write(&file_path, patch_data).unwrap();
+
+    file_path.to_string_lossy().to_string()
+  }
+
+  #[test]
+  fn parses_indexed_patches() {
+    let patches = PatchFileParser::parse_patches(&indexed_patch_file()).unwrap();
+
+    assert_eq!(patches.len(), 1);
+    assert!(patches[0].control_point(0, 0) == Point::new(0.0, 0.0, 0.0));
+    assert!(patches[0].control_point(1, 2) == Point::new(2.0 / 3.0, 1.0, 1.0 / 3.0));
+    assert!(patches[0].control_point(3, 3) == Point::new(1.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn indexed_patches_share_vertices() {
+    // Second patch reuses vertices in reverse order
+    let forward: Vec<String> = (1..=16).map(|index| index.to_string()).collect();
+    let backward: Vec<String> = (1..=16).rev().map(|index| index.to_string()).collect();
+
+    let patch_data = format!("2\n{}\n{}\n16\n{}\n", forward.join(", "), backward.join(" "), control_point_lines(" ").join("\n"));
+    let patches = PatchFileParser::parse_patches(&patch_data).unwrap();
+
+    assert_eq!(patches.len(), 2);
+    assert!(patches[0].control_point(0, 0) == patches[1].control_point(3, 3));
+  }
+
+  #[test]
+  fn parses_bpt_patches() {
+    let patch_data = format!("1\n3 3\n{}\n", control_point_lines(" ").join("\n"));
+    let patches = PatchFileParser::parse_patches(&patch_data).unwrap();
+
+    assert_eq!(patches.len(), 1);
+    assert!(patches[0].control_point(2, 1) == Point::new(1.0 / 3.0, 1.0, 2.0 / 3.0));
+  }
+
+  #[test]
+  fn reports_malformed_patch_files() {
+    assert!(PatchFileParser::parse_patches("").is_err());
+    assert!(PatchFileParser::parse_patches("1\n").is_err());
+
+    // Vertex index past end of vertex list
+    let indices: Vec<String> = (2..=17).map(|index| index.to_string()).collect();
+    let patch_data = format!("1\n{}\n16\n{}\n", indices.join(","), control_point_lines(",").join("\n"));
+
+    assert!(PatchFileParser::parse_patches(&patch_data).unwrap_err().contains("out of range"));
+
+    // Only bicubic patches are supported
+    let patch_data = format!("1\n2 3\n{}\n", control_point_lines(" ").join("\n"));
+
+    assert!(PatchFileParser::parse_patches(&patch_data).unwrap_err().contains("bicubic"));
+
+    // Too few control points
+    let patch_data = format!("1\n3 3\n{}\n", control_point_lines(" ")[..10].join("\n"));
+
+    assert!(PatchFileParser::parse_patches(&patch_data).is_err());
+
+    assert!(PatchFileParser::parse_point(&["1.0", "nan", "0.0"], 3).is_err());
+  }
+
+  #[test]
+  fn reports_missing_files() {
+    let result = PatchFileParser::load_mesh("does_not_exist.bpt".to_string(), 0.01, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0));
+
+    match result {
+      Err(PatchError::Io { file_name, error: _ }) => assert_eq!(file_name, "does_not_exist.bpt"),
+      _ => panic!("Expected io error")
+    }
+  }
+
+  #[test]
+  fn loads_file_as_mesh() {
+    let file_name = write_patch_file("bump.patch", &indexed_patch_file());
+
+    let mesh = PatchFileParser::load_mesh(file_name, 0.01, Matrix4x4::translate(0.0, 1.0, 0.0), Color::new(1.0, 0.0, 0.0, 1.0)).unwrap();
+
+    assert!(mesh.faces.len() > 8);
+    assert!(mesh.get_material().color_1.r == 1.0);
+
+    let ray = Ray::new(&Point::new(0.5, 5.0, 0.5), &Vector::new(0.0, -1.0, 0.0));
+
+    assert_eq!(mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 1);
+  }
+}
@@ -0,0 +1,272 @@
+use std::f64;
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::shapes::shape::perpendicular_tangents;
+
+use crate::rendering::Canvas;
+
+use crate::rendering::Scene;
+
+use crate::rendering::GeometryLight;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+use crate::rendering::Computations;
+
+use crate::rendering::shading_model::schlick;
+
+// Paths always survive this many bounces, before russian roulette may end them
+const ROULETTE_BOUNCES: u32 = 3;
+
+// Dim paths keep a small chance of surviving russian roulette, so their light is not lost entirely
+const MIN_SURVIVAL: f64 = 0.05;
+
+// Shadow rays stop just short of points sampled on lights, so are not blocked by the light itself
+const SHADOW_EPSILON: f64 = 0.0001;
+
+// Unbiased Monte Carlo estimate of light bouncing between surfaces, in place of ambient terms of materials
+pub struct PathTracer {
+  pub samples_per_pixel: u32,
+  pub max_bounces: u32,
+  pub seed: u64
+}
+
+impl PathTracer {
+  pub fn new(samples_per_pixel: u32, max_bounces: u32) -> PathTracer {
+    PathTracer { samples_per_pixel, max_bounces, seed: 0 }
+  }
+
+  pub fn render(&self, scene: &Scene) -> Canvas {
+    let camera = &scene.camera;
+    let lights = scene.geometry_lights();
+
+    // Same seed renders same image
+    let mut rng = StdRng::seed_from_u64(self.seed);
+
+    let mut canvas = Canvas::new(camera.horizontal_size, camera.vertical_size);
+
+    // Samples split each pixel between them, so textures are filtered over a smaller footprint
+    let spacing = 1.0 / (self.samples_per_pixel.max(1) as f64).sqrt();
+
+    for y in 0..camera.vertical_size {
+      for x in 0..camera.horizontal_size {
+        let mut color = Color::new(0.0, 0.0, 0.0, 1.0);
+
+        for _ in 0..self.samples_per_pixel {
+          let ray = camera.with_pixel_differentials(camera.ray_through_pixel(x, y, (rng.gen(), rng.gen())), spacing);
+
+          color = color.add_color(&self.color_at(scene, &lights, ray, &mut rng));
+        }
+
+        canvas.color_pixel(y, x, color.mult_scalar(1.0 / (self.samples_per_pixel.max(1) as f64)));
+      }
+    }
+
+    canvas
+  }
+
+  // Light arriving back along ray, lights are geometry lights of scene
+  pub fn color_at(&self, scene: &Scene, lights: &[GeometryLight], ray: Ray, rng: &mut StdRng) -> Color {
+    let mut color = Color::new(0.0, 0.0, 0.0, 1.0);
+
+    // Share of light at current hit that reaches camera
+    let mut throughput = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    // Pdf of scattered ray that reached current hit, none for camera rays and mirror bounces which lights cannot sample
+    let mut scatter_pdf: Option<f64> = None;
+    let mut scatter_origin = ray.origin;
+
+    let mut ray = ray;
+
+    for bounce in 0..self.max_bounces {
+      let intersections = scene.intersect(&ray);
+
+      let hit = match Intersection::get_hit(&intersections) {
+        Some(hit) => hit,
+        None => break
+      };
+
+      let computations = Computations::new(hit, &ray, &intersections);
+      let material = computations.get_material();
+
+      if material.emits_light() {
+        let weight = match scatter_pdf {
+          Some(pdf) => emission_weight(lights, &computations, pdf, &scatter_origin),
+          None => 1.0
+        };
+
+        color = color.add_color(&throughput.mult_color(&material.emission).mult_scalar(weight));
+      }
+
+      // Reflectiveness and transparency take their share of light first, and shading model scatters the rest
+      let mut reflectiveness = material.reflectiveness.max(0.0);
+      let mut transparency = material.transparency.max(0.0);
+
+      let specular_share = reflectiveness + transparency;
+      if specular_share > 1.0 {
+        reflectiveness /= specular_share;
+        transparency /= specular_share;
+      }
+
+      // Each event is picked with chance equal to its share, so throughput is only changed by scattering
+      let event = rng.gen::<f64>();
+
+      if event < transparency {
+        // Transparent surfaces split light between reflection and refraction by fresnel reflectance
+        let refracted_direction = computations.refracted_direction();
+
+        ray = match refracted_direction {
+          Some(direction) if rng.gen::<f64>() >= schlick(&computations) => Ray::new(&computations.under_point, &direction),
+          _ => Ray::new(&computations.over_point, &computations.reflect_v)
+        };
+        scatter_pdf = None;
+      } else if event < transparency + reflectiveness {
+        ray = Ray::new(&computations.over_point, &computations.reflect_v);
+        scatter_pdf = None;
+      } else {
+        let surface_color = computations.surface_color();
+
+        color = color.add_color(&throughput.mult_color(&direct_light(scene, lights, &computations, &surface_color, rng)));
+
+        let direction = cosine_weighted_direction(&computations.normal, (rng.gen(), rng.gen()));
+        let pdf = direction.dot(&computations.normal) / f64::consts::PI;
+
+        if pdf <= 0.0 {
+          break;
+        }
+
+        let reflectance = material.shading_model.reflectance(material, &surface_color, &computations.eye_v, &computations.normal, &direction);
+
+        throughput = throughput.mult_color(&reflectance).mult_scalar(1.0 / pdf);
+
+        ray = Ray::new(&computations.over_point, &direction);
+        scatter_pdf = Some(pdf);
+        scatter_origin = computations.over_point;
+      }
+
+      // Russian roulette ends paths carrying little light, and scales up those that go on so none is lost on average
+      if bounce + 1 >= ROULETTE_BOUNCES {
+        let survival = throughput.r.max(throughput.g).max(throughput.b).clamp(MIN_SURVIVAL, 1.0);
+
+        if rng.gen::<f64>() >= survival {
+          break;
+        }
+
+        throughput = throughput.mult_scalar(1.0 / survival);
+      }
+    }
+
+    color
+  }
+}
+
+// Light reaching hit straight from point lights, and from a point sampled on one geometry light
+fn direct_light(scene: &Scene, lights: &[GeometryLight], computations: &Computations, surface_color: &Color, rng: &mut StdRng) -> Color {
+  let material = computations.get_material();
+  let shading_model = &material.shading_model;
+
+  let mut direct = Color::new(0.0, 0.0, 0.0, 1.0);
+
+  // Point lights cannot be hit by scattered rays, so need no weighting, and as in shading models they do not fall off
+  for light in &scene.lights {
+    if scene.is_shadowed(&computations.over_point, &light.position) {
+      continue;
+    }
+
+    let light_v = light.position.subtract_point(&computations.point).normalize();
+    let reflectance = shading_model.reflectance(material, surface_color, &computations.eye_v, &computations.normal, &light_v);
+
+    direct = direct.add_color(&reflectance.mult_color(&light.intensity).mult_scalar(f64::consts::PI));
+  }
+
+  if lights.is_empty() {
+    return direct;
+  }
+
+  // A single light is picked, so its light is scaled up by number of lights
+  let light = &lights[rng.gen_range(0, lights.len())];
+
+  let surface_sample = match light.sample((rng.gen(), rng.gen())) {
+    Some(surface_sample) => surface_sample,
+    None => return direct
+  };
+
+  let light_pdf = light.solid_angle_pdf(&surface_sample, &computations.over_point) / (lights.len() as f64);
+  if light_pdf <= 0.0 || !is_visible(scene, &computations.over_point, &surface_sample.point) {
+    return direct;
+  }
+
+  let light_v = surface_sample.point.subtract_point(&computations.over_point).normalize();
+  let reflectance = shading_model.reflectance(material, surface_color, &computations.eye_v, &computations.normal, &light_v);
+
+  let scatter_pdf = light_v.dot(&computations.normal).max(0.0) / f64::consts::PI;
+  let weight = power_heuristic(light_pdf, scatter_pdf);
+
+  direct.add_color(&reflectance.mult_color(&light.sample_emission(&surface_sample)).mult_scalar(weight / light_pdf))
+}
+
+// Weight of emission found by a scattered ray, against chance of a light having sampled the same point
+fn emission_weight(lights: &[GeometryLight], computations: &Computations, scatter_pdf: f64, scatter_origin: &Point) -> f64 {
+  let light = match lights.iter().find(|light| light.is_same_object(computations.object, &computations.world_to_container)) {
+    Some(light) => light,
+    None => return 1.0
+  };
+
+  let towards_hit = computations.point.subtract_point(scatter_origin);
+  let distance_squared = towards_hit.dot(&towards_hit);
+  let cos = computations.normal.dot(&computations.eye_v).abs();
+
+  if cos <= 0.0 {
+    return 1.0;
+  }
+
+  let light_pdf = light.pdf(&computations.normal) * distance_squared / cos / (lights.len() as f64);
+
+  power_heuristic(scatter_pdf, light_pdf)
+}
+
+// Multiple importance sampling weight of a strategy, favouring whichever was more likely to find a path
+pub fn power_heuristic(pdf: f64, other_pdf: f64) -> f64 {
+  let pdf_squared = pdf * pdf;
+  let other_pdf_squared = other_pdf * other_pdf;
+
+  if pdf_squared + other_pdf_squared <= 0.0 {
+    return 0.0;
+  }
+
+  pdf_squared / (pdf_squared + other_pdf_squared)
+}
+
+// Direction above surface, more likely near normal in proportion to cosine of angle with it
+pub fn cosine_weighted_direction(normal: &Vector, sample: (f64, f64)) -> Vector {
+  let (tangent, bitangent) = perpendicular_tangents(normal);
+
+  // Points spread evenly over a disk, projected up onto hemisphere
+  let radius = sample.0.sqrt();
+  let azimuth = 2.0 * f64::consts::PI * sample.1;
+  let height = (1.0 - sample.0).max(0.0).sqrt();
+
+  tangent.multiply(radius * azimuth.cos()).add_vector(&bitangent.multiply(radius * azimuth.sin())).add_vector(&normal.multiply(height)).normalize()
+}
+
+fn is_visible(scene: &Scene, from: &Point, to: &Point) -> bool {
+  let towards = to.subtract_point(from);
+  let distance = towards.magnitude();
+
+  let ray = Ray::new(from, &towards.normalize());
+  let intersections = scene.intersect(&ray);
+
+  match Intersection::get_hit(&intersections) {
+    Some(hit) => hit.t >= distance * (1.0 - SHADOW_EPSILON),
+    None => true
+  }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use rand::SeedableRng;
+  use rand::rngs::StdRng;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Sphere;
+  use crate::rendering::shapes::Plane;
+
+  use crate::rendering::Scene;
+  use crate::rendering::Integrator;
+  use crate::rendering::Camera;
+  use crate::rendering::PointLight;
+  use crate::rendering::Container;
+  use crate::rendering::Instance;
+
+  use crate::rendering::Material;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::PathTracer;
+  use crate::rendering::path_tracer::power_heuristic;
+  use crate::rendering::path_tracer::cosine_weighted_direction;
+
+  fn matte_material(diffuse: f64) -> Material {
+    Material::solid(0.0, diffuse, 0.0, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity())
+  }
+
+  fn glowing_material(emission: f64) -> Material {
+    let mut material = Material::emissive(Color::new(emission, emission, emission, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    material.diffuse = 0.0;
+
+    material
+  }
+
+  fn camera() -> Camera {
+    Camera::new(4, 2, f64::consts::PI / 2.0, Matrix4x4::identity())
+  }
+
+  #[test]
+  fn power_heuristic_favours_more_likely_strategy() {
+    assert_eq!(power_heuristic(1.0, 1.0), 0.5);
+    assert_eq!(power_heuristic(2.0, 0.0), 1.0);
+    assert_eq!(power_heuristic(0.0, 2.0), 0.0);
+    assert_eq!(power_heuristic(0.0, 0.0), 0.0);
+
+    assert!((power_heuristic(3.0, 1.0) + power_heuristic(1.0, 3.0) - 1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn cosine_weighted_directions_lie_above_surface() {
+    let normal = Vector::new(0.0, 1.0, 1.0).normalize();
+
+    assert!(cosine_weighted_direction(&normal, (0.0, 0.3)).subtract_vector(&normal).magnitude() < 0.0001);
+
+    for sample in [(0.1, 0.2), (0.5, 0.5), (0.99, 0.7)] {
+      let direction = cosine_weighted_direction(&normal, sample);
+
+      assert!((direction.magnitude() - 1.0).abs() < 0.0001);
+
+      // Cosine with normal is height above disk of sample
+      assert!((direction.dot(&normal) - (1.0 - sample.0).sqrt()).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn emission_is_seen_directly_by_camera_rays() {
+    let sphere = &Sphere::new(Matrix4x4::translate(0.0, 0.0, 5.0), glowing_material(3.0));
+
+    let container = Container::new(Matrix4x4::identity(), vec![sphere as &dyn Shape]);
+    let scene = Scene::new(camera(), Vec::new(), vec![container]);
+
+    let lights = scene.geometry_lights();
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let path_tracer = PathTracer::new(1, 1);
+    let color = path_tracer.color_at(&scene, &lights, Ray::new(&Point::new(0.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0)), &mut rng);
+
+    assert!(color == Color::new(3.0, 3.0, 3.0, 1.0));
+  }
+
+  #[test]
+  fn point_lights_match_whitted_shading_without_ambient() {
+    let plane = &Plane::new(Matrix4x4::identity(), matte_material(0.8));
+
+    let container = Container::new(Matrix4x4::identity(), vec![plane as &dyn Shape]);
+    let light = PointLight::new(Color::new(1.0, 1.0, 1.0, 1.0), Point::new(-10.0, 10.0, 0.0));
+    let scene = Scene::new(camera(), vec![light], vec![container]);
+
+    let ray = Ray::new(&Point::new(0.0, 1.0, -1.0), &Vector::new(0.0, -1.0, 1.0).normalize());
+    let whitted_color = scene.color_at(&Ray::new(&ray.origin, &ray.direction), 4);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let path_tracer = PathTracer::new(1, 1);
+    let color = path_tracer.color_at(&scene, &scene.geometry_lights(), ray, &mut rng);
+
+    assert!((color.r - whitted_color.r).abs() < 0.0001);
+    assert!((color.r - 0.8 * (2.0_f64.sqrt() / 2.0)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn surfaces_are_lit_by_emitting_spheres() {
+    let plane = &Plane::new(Matrix4x4::identity(), matte_material(0.5));
+    let sphere = &Sphere::new(Matrix4x4::translate(0.0, 3.0, 0.0), glowing_material(1.0));
+
+    let container = Container::new(Matrix4x4::identity(), vec![plane as &dyn Shape, sphere as &dyn Shape]);
+    let scene = Scene::new(camera(), Vec::new(), vec![container]);
+
+    let lights = scene.geometry_lights();
+    let mut rng = StdRng::seed_from_u64(7);
+
+    // Light sampling and scattered rays both find sphere, so two bounces are enough
+    let path_tracer = PathTracer::new(1, 2);
+
+    let samples = 4000;
+    let mut total = 0.0;
+    for _ in 0..samples {
+      let ray = Ray::new(&Point::new(0.0, 1.0, -1.0), &Vector::new(0.0, -1.0, 1.0).normalize());
+
+      total += path_tracer.color_at(&scene, &lights, ray, &mut rng).r;
+    }
+
+    // Sphere of radius r at distance d gives irradiance of pi (r / d)^2 times its emission
+    let expected = 0.5 * (1.0 / 9.0);
+
+    assert!((total / (samples as f64) - expected).abs() < expected * 0.05);
+  }
+
+  #[test]
+  fn surfaces_are_lit_by_instanced_copies_of_emitters() {
+    let plane = &Plane::new(Matrix4x4::identity(), matte_material(0.5));
+    let sphere = &Sphere::new(Matrix4x4::translate(10.0, 3.0, 0.0), glowing_material(1.0));
+    let instance = &Instance::new(sphere, Matrix4x4::translate(0.0, 3.0, 0.0).mult4x4(&Matrix4x4::scale(0.5, 0.5, 0.5)).mult4x4(&Matrix4x4::translate(-10.0, -3.0, 0.0)));
+
+    let container = Container::new(Matrix4x4::identity(), vec![plane as &dyn Shape, sphere as &dyn Shape, instance as &dyn Shape]);
+    let scene = Scene::new(camera(), Vec::new(), vec![container]);
+
+    let lights = scene.geometry_lights();
+    assert_eq!(lights.len(), 2);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let path_tracer = PathTracer::new(1, 2);
+
+    let samples = 4000;
+    let mut total = 0.0;
+    for _ in 0..samples {
+      let ray = Ray::new(&Point::new(0.0, 1.0, -1.0), &Vector::new(0.0, -1.0, 1.0).normalize());
+
+      total += path_tracer.color_at(&scene, &lights, ray, &mut rng).r;
+    }
+
+    // Smaller copy placed by instance straight above, and shared sphere off to the side, lit by cosine of its direction
+    let expected = 0.5 * (0.25 / 9.0 + 3.0 / 109.0_f64.powf(1.5));
+
+    assert!((total / (samples as f64) - expected).abs() < expected * 0.05);
+  }
+
+  #[test]
+  fn mirrors_reflect_emission() {
+    let mut material = matte_material(0.0);
+    material.reflectiveness = 1.0;
+    let plane = &Plane::new(Matrix4x4::translate(0.0, -1.0, 0.0), material);
+    let sphere = &Sphere::new(Matrix4x4::translate(0.0, 2.0, 1.0), glowing_material(2.0));
+
+    let container = Container::new(Matrix4x4::identity(), vec![sphere as &dyn Shape, plane as &dyn Shape]);
+    let scene = Scene::new(camera(), Vec::new(), vec![container]);
+
+    let lights = scene.geometry_lights();
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let path_tracer = PathTracer::new(1, 4);
+    let ray = Ray::new(&Point::new(0.0, 0.0, -3.0), &Vector::new(0.0, -1.0, 1.0).normalize());
+
+    assert!(path_tracer.color_at(&scene, &lights, ray, &mut rng) == Color::new(2.0, 2.0, 2.0, 1.0));
+  }
+
+  #[test]
+  fn integrator_is_chosen_for_each_render() {
+    let sphere = &Sphere::new(Matrix4x4::translate(0.0, 0.0, -5.0).mult4x4(&Matrix4x4::scale(3.0, 3.0, 3.0)), glowing_material(1.0));
+
+    let container = Container::new(Matrix4x4::identity(), vec![sphere as &dyn Shape]);
+    let scene = Scene::new(camera(), Vec::new(), vec![container]);
+
+    let whitted = scene.render_with(&Integrator::Whitted);
+    let path_traced = scene.render_with(&Integrator::PathTraced(PathTracer::new(4, 4)));
+
+    assert!(whitted.pixel_color(0, 1) == scene.render().pixel_color(0, 1));
+
+    // Emission of sphere covering middle of image is the same whichever integrator renders it
+    assert!(path_traced.pixel_color(1, 2) == whitted.pixel_color(1, 2));
+  }
+}
@@ -0,0 +1,573 @@
+use std::fmt;
+use std::error::Error;
+
+use std::fs;
+
+use std::collections::HashMap;
+
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::Triangle;
+use crate::rendering::shapes::SmoothTriangle;
+use crate::rendering::shapes::MeshIndices;
+use crate::rendering::shapes::Mesh;
+
+use crate::rendering::Container;
+
+use crate::rendering::Material;
+
+use crate::rendering::ObjFileParser;
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum PlyFormat {
+  Ascii,
+  BinaryLittleEndian,
+  BinaryBigEndian
+}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum PlyDataType {
+  Char,
+  UChar,
+  Short,
+  UShort,
+  Int,
+  UInt,
+  Float,
+  Double
+}
+
+impl PlyDataType {
+  pub fn from_name(type_name: &str) -> Result<PlyDataType, String> {
+    match type_name {
+      "char" | "int8" => Ok(PlyDataType::Char),
+      "uchar" | "uint8" => Ok(PlyDataType::UChar),
+      "short" | "int16" => Ok(PlyDataType::Short),
+      "ushort" | "uint16" => Ok(PlyDataType::UShort),
+      "int" | "int32" => Ok(PlyDataType::Int),
+      "uint" | "uint32" => Ok(PlyDataType::UInt),
+      "float" | "float32" => Ok(PlyDataType::Float),
+      "double" | "float64" => Ok(PlyDataType::Double),
+      _ => Err(format!("unknown property type '{}'", type_name))
+    }
+  }
+
+  pub fn size(&self) -> usize {
+    match self {
+      PlyDataType::Char | PlyDataType::UChar => 1,
+      PlyDataType::Short | PlyDataType::UShort => 2,
+      PlyDataType::Int | PlyDataType::UInt | PlyDataType::Float => 4,
+      PlyDataType::Double => 8
+    }
+  }
+
+  // Integer color components span full range of type, floating point components span 0 to 1
+  pub fn color_scale(&self) -> f64 {
+    match self {
+      PlyDataType::Float | PlyDataType::Double => 1.0,
+      PlyDataType::Short | PlyDataType::UShort => 65535.0,
+      _ => 255.0
+    }
+  }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct PlyProperty {
+  pub name: String,
+  pub data_type: PlyDataType,
+  pub count_type: Option<PlyDataType>
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct PlyElement {
+  pub name: String,
+  pub count: usize,
+  pub properties: Vec<PlyProperty>
+}
+
+impl PlyElement {
+  pub fn has_property(&self, property_name: &str) -> bool {
+    self.properties.iter().any(|property| property.name == property_name)
+  }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct PlyHeader {
+  pub format: PlyFormat,
+  pub elements: Vec<PlyElement>
+}
+
+pub struct PlyMesh {
+  pub vertices: Vec<Point>,
+  pub normals: Vec<Vector>,
+  pub colors: Vec<Color>,
+  pub faces: Vec<Vec<usize>>
+}
+
+#[derive(Debug)]
+pub enum PlyError {
+  Io { file_name: String, error: std::io::Error },
+  Parse { file_name: String, message: String }
+}
+
+impl PlyError {
+  pub fn parse(file_name: &str, message: String) -> PlyError {
+    PlyError::Parse { file_name: file_name.to_string(), message }
+  }
+}
+
+impl fmt::Display for PlyError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      PlyError::Io { file_name, error } => write!(f, "{}: {}", file_name, error),
+      PlyError::Parse { file_name, message } => write!(f, "{}: {}", file_name, message)
+    }
+  }
+}
+
+impl Error for PlyError {}
+
+// Reads successive property values from body of file, in whichever encoding header declares
+pub struct PlyValueReader<'b> {
+  pub format: PlyFormat,
+  pub data: &'b [u8],
+  pub tokens: Vec<&'b str>,
+  pub position: usize
+}
+
+impl<'b> PlyValueReader<'b> {
+  pub fn new(format: PlyFormat, data: &'b [u8]) -> Result<PlyValueReader<'b>, String> {
+    let mut tokens = Vec::new();
+
+    if format == PlyFormat::Ascii {
+      match std::str::from_utf8(data) {
+        Ok(text) => tokens = text.split_whitespace().collect(),
+        Err(_) => return Err(String::from("ascii body contains invalid characters"))
+      }
+    }
+
+    Ok(PlyValueReader { format, data, tokens, position: 0 })
+  }
+
+  pub fn read_value(&mut self, data_type: PlyDataType) -> Result<f64, String> {
+    match self.format {
+      PlyFormat::Ascii => self.read_ascii_value(),
+      _ => self.read_binary_value(data_type)
+    }
+  }
+
+  fn read_ascii_value(&mut self) -> Result<f64, String> {
+    let value_text = match self.tokens.get(self.position) {
+      Some(value_text) => value_text,
+      None => return Err(String::from("unexpected end of data"))
+    };
+
+    self.position += 1;
+
+    match value_text.parse::<f64>() {
+      Ok(value) if value.is_finite() => Ok(value),
+      Ok(_) => Err(format!("'{}' is not a finite number", value_text)),
+      Err(_) => Err(format!("invalid number '{}'", value_text))
+    }
+  }
+
+  fn read_binary_value(&mut self, data_type: PlyDataType) -> Result<f64, String> {
+    let size = data_type.size();
+
+    if self.position + size > self.data.len() {
+      return Err(String::from("unexpected end of data"));
+    }
+
+    let mut bytes = [0u8; 8];
+    bytes[..size].copy_from_slice(&self.data[self.position..(self.position + size)]);
+    self.position += size;
+
+    // Values are decoded as little endian, so big endian bytes are reversed first
+    if self.format == PlyFormat::BinaryBigEndian {
+      bytes[..size].reverse();
+    }
+
+    let value = match data_type {
+      PlyDataType::Char => i8::from_le_bytes([bytes[0]]) as f64,
+      PlyDataType::UChar => bytes[0] as f64,
+      PlyDataType::Short => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+      PlyDataType::UShort => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+      PlyDataType::Int => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+      PlyDataType::UInt => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+      PlyDataType::Float => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+      PlyDataType::Double => f64::from_le_bytes(bytes)
+    };
+
+    if !value.is_finite() {
+      return Err(String::from("value is not a finite number"));
+    }
+
+    Ok(value)
+  }
+}
+
+pub struct PlyFileParser {}
+
+impl PlyFileParser {
+  pub fn load_file<'a>(file_name: String, transform: Matrix4x4, color: Color, triangles: &'a mut Vec<Triangle>) -> Result<Container<'a>, PlyError> {
+    let ply_mesh = PlyFileParser::read_mesh(&file_name)?;
+
+    PlyFileParser::build_triangles(&ply_mesh, color, triangles);
+
+    let mut container_shape_references = Vec::new();
+    for triangle in triangles {
+      container_shape_references.push(triangle as &dyn Shape);
+    }
+
+    Ok(Container::new(transform, container_shape_references))
+  }
+
+  pub fn load_file_with_normals<'a>(file_name: String, transform: Matrix4x4, color: Color, smooth_triangles: &'a mut Vec<SmoothTriangle>) -> Result<Container<'a>, PlyError> {
+    let ply_mesh = PlyFileParser::read_mesh(&file_name)?;
+
+    if ply_mesh.normals.is_empty() {
+      return Err(PlyError::parse(&file_name, String::from("vertices have no nx, ny and nz properties")));
+    }
+
+    PlyFileParser::build_smooth_triangles(&ply_mesh, color, smooth_triangles);
+
+    let mut container_shape_references = Vec::new();
+    for smooth_triangle in smooth_triangles {
+      container_shape_references.push(smooth_triangle as &dyn Shape);
+    }
+
+    Ok(Container::new(transform, container_shape_references))
+  }
+
+  pub fn load_mesh(file_name: String, transform: Matrix4x4, color: Color) -> Result<Mesh, PlyError> {
+    let ply_mesh = PlyFileParser::read_mesh(&file_name)?;
+
+    let mut triangles = Vec::new();
+    let mut materials = vec![ObjFileParser::default_material(color)];
+
+    // Faces of one color share a material, rather than each holding its own copy
+    let mut color_materials: HashMap<[u64; 3], usize> = HashMap::new();
+
+    for face in &ply_mesh.faces {
+      // Faces only need their own material when vertices are colored
+      let material = if ply_mesh.colors.is_empty() {
+        0
+      } else {
+        let face_color = PlyFileParser::face_color(&ply_mesh, face);
+
+        *color_materials.entry([face_color.r.to_bits(), face_color.g.to_bits(), face_color.b.to_bits()]).or_insert_with(|| {
+          materials.push(ObjFileParser::default_material(face_color));
+          materials.len() - 1
+        })
+      };
+
+      // Split into triangles if face contains more than three vertices
+      for i in 1..(face.len() - 1) {
+        let mut triangle = MeshIndices::new([face[0], face[i], face[i + 1]]);
+        triangle.material = material;
+
+        if !ply_mesh.normals.is_empty() {
+          triangle.normals = Some(triangle.vertices);
+        }
+
+        triangles.push(triangle);
+      }
+    }
+
+    Ok(Mesh::new(ply_mesh.vertices, ply_mesh.normals, Vec::new(), triangles, transform, materials))
+  }
+
+  pub fn read_mesh(file_name: &str) -> Result<PlyMesh, PlyError> {
+    let ply_data = fs::read(file_name).map_err(|error| PlyError::Io { file_name: file_name.to_string(), error })?;
+
+    PlyFileParser::parse_mesh(&ply_data).map_err(|message| PlyError::parse(file_name, message))
+  }
+
+  pub fn parse_mesh(ply_data: &[u8]) -> Result<PlyMesh, String> {
+    let (header, body_start) = PlyFileParser::parse_header(ply_data)?;
+
+    let mut reader = PlyValueReader::new(header.format, &ply_data[body_start..])?;
+
+    let mut ply_mesh = PlyMesh { vertices: Vec::new(), normals: Vec::new(), colors: Vec::new(), faces: Vec::new() };
+
+    for element in &header.elements {
+      let element_result = match element.name.as_str() {
+        "vertex" => PlyFileParser::read_vertices(element, &mut reader, &mut ply_mesh),
+        "face" => PlyFileParser::read_faces(element, &mut reader, &mut ply_mesh),
+        // Other elements, such as edges, are read and discarded
+        _ => PlyFileParser::skip_element(element, &mut reader)
+      };
+
+      element_result.map_err(|message| format!("{} element: {}", element.name, message))?;
+    }
+
+    // Faces may precede vertices, so indices are only checked once every element is read
+    for face in &ply_mesh.faces {
+      if let Some(index) = face.iter().find(|&&index| index >= ply_mesh.vertices.len()) {
+        return Err(format!("face vertex index {} out of range, {} defined", index, ply_mesh.vertices.len()));
+      }
+    }
+
+    Ok(ply_mesh)
+  }
+
+  pub fn parse_header(ply_data: &[u8]) -> Result<(PlyHeader, usize), String> {
+    let mut format = None;
+    let mut elements: Vec<PlyElement> = Vec::new();
+
+    let mut position = 0;
+    let mut line_number = 0;
+
+    loop {
+      let line_end = match ply_data[position..].iter().position(|&byte| byte == b'\n') {
+        Some(line_length) => position + line_length,
+        None => return Err(String::from("header has no end_header line"))
+      };
+
+      let header_line = String::from_utf8_lossy(&ply_data[position..line_end]).to_string();
+      position = line_end + 1;
+      line_number += 1;
+
+      let tokens = header_line.split_whitespace().collect::<Vec<&str>>();
+
+      if line_number == 1 {
+        if tokens != vec!["ply"] {
+          return Err(String::from("file does not start with ply"));
+        }
+
+        continue;
+      }
+
+      if tokens.is_empty() {
+        continue;
+      }
+
+      let line_result = match tokens[0] {
+        "format" => PlyFileParser::parse_format(&tokens).map(|ply_format| format = Some(ply_format)),
+        "element" => PlyFileParser::parse_element(&tokens).map(|element| elements.push(element)),
+        "property" => match elements.last_mut() {
+          Some(element) => PlyFileParser::parse_property(&tokens).map(|property| element.properties.push(property)),
+          None => Err(String::from("property declared before any element"))
+        },
+        "end_header" => break,
+        // Comments and obj_info lines carry nothing needed for rendering
+        _ => Ok(())
+      };
+
+      line_result.map_err(|message| format!("header line {}: {}", line_number, message))?;
+    }
+
+    match format {
+      Some(format) => Ok((PlyHeader { format, elements }, position)),
+      None => Err(String::from("header has no format line"))
+    }
+  }
+
+  pub fn parse_format(tokens: &[&str]) -> Result<PlyFormat, String> {
+    match tokens.get(1) {
+      Some(&"ascii") => Ok(PlyFormat::Ascii),
+      Some(&"binary_little_endian") => Ok(PlyFormat::BinaryLittleEndian),
+      Some(&"binary_big_endian") => Ok(PlyFormat::BinaryBigEndian),
+      Some(format_name) => Err(format!("unsupported format '{}'", format_name)),
+      None => Err(String::from("format line has no format"))
+    }
+  }
+
+  pub fn parse_element(tokens: &[&str]) -> Result<PlyElement, String> {
+    if tokens.len() != 3 {
+      return Err(String::from("element line requires a name and count"));
+    }
+
+    match tokens[2].parse::<usize>() {
+      Ok(count) => Ok(PlyElement { name: tokens[1].to_string(), count, properties: Vec::new() }),
+      Err(_) => Err(format!("invalid element count '{}'", tokens[2]))
+    }
+  }
+
+  pub fn parse_property(tokens: &[&str]) -> Result<PlyProperty, String> {
+    // List properties take the form: property list <count type> <item type> <name>
+    if tokens.len() == 5 && tokens[1] == "list" {
+      return Ok(PlyProperty {
+        name: tokens[4].to_string(),
+        data_type: PlyDataType::from_name(tokens[3])?,
+        count_type: Some(PlyDataType::from_name(tokens[2])?)
+      });
+    }
+
+    if tokens.len() != 3 {
+      return Err(String::from("property line requires a type and name"));
+    }
+
+    Ok(PlyProperty { name: tokens[2].to_string(), data_type: PlyDataType::from_name(tokens[1])?, count_type: None })
+  }
+
+  pub fn read_vertices(element: &PlyElement, reader: &mut PlyValueReader, ply_mesh: &mut PlyMesh) -> Result<(), String> {
+    let has_normals = element.has_property("nx") && element.has_property("ny") && element.has_property("nz");
+    let has_colors = element.has_property("red") && element.has_property("green") && element.has_property("blue");
+
+    for _ in 0..element.count {
+      let mut position = [0.0; 3];
+      let mut normal = [0.0; 3];
+      let mut color = [0.0; 3];
+
+      for property in &element.properties {
+        if property.count_type.is_some() {
+          PlyFileParser::read_list(property, reader)?;
+          continue;
+        }
+
+        let value = reader.read_value(property.data_type)?;
+
+        match property.name.as_str() {
+          "x" => position[0] = value,
+          "y" => position[1] = value,
+          "z" => position[2] = value,
+          "nx" => normal[0] = value,
+          "ny" => normal[1] = value,
+          "nz" => normal[2] = value,
+          "red" => color[0] = value / property.data_type.color_scale(),
+          "green" => color[1] = value / property.data_type.color_scale(),
+          "blue" => color[2] = value / property.data_type.color_scale(),
+          _ => {}
+        }
+      }
+
+      ply_mesh.vertices.push(Point::new(position[0], position[1], position[2]));
+
+      if has_normals {
+        ply_mesh.normals.push(Vector::new(normal[0], normal[1], normal[2]));
+      }
+
+      if has_colors {
+        ply_mesh.colors.push(Color::new(color[0], color[1], color[2], 1.0));
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn read_faces(element: &PlyElement, reader: &mut PlyValueReader, ply_mesh: &mut PlyMesh) -> Result<(), String> {
+    for _ in 0..element.count {
+      let mut face = Vec::new();
+
+      for property in &element.properties {
+        if property.count_type.is_none() {
+          reader.read_value(property.data_type)?;
+          continue;
+        }
+
+        let values = PlyFileParser::read_list(property, reader)?;
+
+        if property.name == "vertex_indices" || property.name == "vertex_index" {
+          for value in values {
+            if value < 0.0 {
+              return Err(format!("negative vertex index {}", value));
+            }
+
+            face.push(value as usize);
+          }
+        }
+      }
+
+      if face.len() < 3 {
+        return Err(format!("face requires at least 3 vertices, found {}", face.len()));
+      }
+
+      ply_mesh.faces.push(face);
+    }
+
+    Ok(())
+  }
+
+  pub fn skip_element(element: &PlyElement, reader: &mut PlyValueReader) -> Result<(), String> {
+    for _ in 0..element.count {
+      for property in &element.properties {
+        match property.count_type {
+          Some(_) => { PlyFileParser::read_list(property, reader)?; },
+          None => { reader.read_value(property.data_type)?; }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn read_list(property: &PlyProperty, reader: &mut PlyValueReader) -> Result<Vec<f64>, String> {
+    let count_type = property.count_type.unwrap_or(PlyDataType::UChar);
+    let count = reader.read_value(count_type)?;
+
+    if count < 0.0 {
+      return Err(format!("negative list length {}", count));
+    }
+
+    let mut values = Vec::new();
+    for _ in 0..(count as usize) {
+      values.push(reader.read_value(property.data_type)?);
+    }
+
+    Ok(values)
+  }
+
+  pub fn face_material(ply_mesh: &PlyMesh, face: &[usize], color: Color) -> Material {
+    if ply_mesh.colors.is_empty() {
+      return ObjFileParser::default_material(color);
+    }
+
+    ObjFileParser::default_material(PlyFileParser::face_color(ply_mesh, face))
+  }
+
+  // Triangles hold a single material, so vertex colors are averaged over each face
+  pub fn face_color(ply_mesh: &PlyMesh, face: &[usize]) -> Color {
+    let mut face_color = Color::new(0.0, 0.0, 0.0, 1.0);
+    for &index in face {
+      face_color = face_color.add_color(&ply_mesh.colors[index]);
+    }
+
+    let face_color = face_color.mult_scalar(1.0 / face.len() as f64);
+
+    Color::new(face_color.r, face_color.g, face_color.b, 1.0)
+  }
+
+  pub fn build_triangles(ply_mesh: &PlyMesh, color: Color, triangles: &mut Vec<Triangle>) {
+    for face in &ply_mesh.faces {
+      let material = PlyFileParser::face_material(ply_mesh, face, color);
+
+      // Split into triangles if face contains more than three vertices
+      for i in 1..(face.len() - 1) {
+        triangles.push(Triangle::new(
+          ply_mesh.vertices[face[0]],
+          ply_mesh.vertices[face[i]],
+          ply_mesh.vertices[face[i + 1]],
+          Matrix4x4::identity(),
+          material.clone()
+        ));
+      }
+    }
+  }
+
+  pub fn build_smooth_triangles(ply_mesh: &PlyMesh, color: Color, smooth_triangles: &mut Vec<SmoothTriangle>) {
+    for face in &ply_mesh.faces {
+      let material = PlyFileParser::face_material(ply_mesh, face, color);
+
+      // Split into triangles if face contains more than three vertices
+      for i in 1..(face.len() - 1) {
+        smooth_triangles.push(SmoothTriangle::new(
+          ply_mesh.vertices[face[0]],
+          ply_mesh.vertices[face[i]],
+          ply_mesh.vertices[face[i + 1]],
+          ply_mesh.normals[face[0]],
+          ply_mesh.normals[face[i]],
+          ply_mesh.normals[face[i + 1]],
+          Matrix4x4::identity(),
+          material.clone()
+        ));
+      }
+    }
+  }
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::env;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::Triangle;
+  use crate::rendering::shapes::SmoothTriangle;
+
+  use crate::rendering::PlyFormat;
+  use crate::rendering::PlyDataType;
+  use crate::rendering::PlyError;
+  use crate::rendering::PlyFileParser;
+
+  const TRIANGLE_HEADER: &str = "ply\nformat ascii 1.0\ncomment single triangle\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\nelement face 1\nproperty list uchar int vertex_indices\nend_header\n";
+
+  fn write_ply_file(file_name: &str, ply_data: &[u8]) -> String {
+    let directory = env::temp_dir().join("jb_tracer_ply_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    let file_path = directory.join(file_name);
+    fs::write(&file_path, ply_data).unwrap();
+
+    file_path.to_string_lossy().to_string()
+  }
+
+  fn binary_quad(format_name: &str, big_endian: bool) -> Vec<u8> {
+    let header = format!("ply\nformat {} 1.0\nelement vertex 4\nproperty float x\nproperty float y\nproperty float z\nproperty uchar red\nproperty uchar green\nproperty uchar blue\nelement face 1\nproperty list uchar int vertex_indices\nend_header\n", format_name);
+    let mut ply_data = header.into_bytes();
+
+    let positions = [(-1.0f32, 1.0f32, 0.0f32), (-1.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+    for (x, y, z) in positions.iter() {
+      for value in [x, y, z].iter() {
+        if big_endian {
+          ply_data.extend_from_slice(&value.to_be_bytes());
+        } else {
+          ply_data.extend_from_slice(&value.to_le_bytes());
+        }
+      }
+
+      ply_data.extend_from_slice(&[255, 0, 51]);
+    }
+
+    ply_data.push(4);
+    for index in [0i32, 1, 2, 3].iter() {
+      if big_endian {
+        ply_data.extend_from_slice(&index.to_be_bytes());
+      } else {
+        ply_data.extend_from_slice(&index.to_le_bytes());
+      }
+    }
+
+    ply_data
+  }
+
+  #[test]
+  fn identifies_property_types_by_name() {
+    assert!(PlyDataType::from_name("uchar").unwrap() == PlyDataType::UChar);
+    assert!(PlyDataType::from_name("uint8").unwrap() == PlyDataType::UChar);
+    assert!(PlyDataType::from_name("int32").unwrap() == PlyDataType::Int);
+    assert!(PlyDataType::from_name("float64").unwrap() == PlyDataType::Double);
+    assert!(PlyDataType::from_name("quad").is_err());
+
+    assert_eq!(PlyDataType::Short.size(), 2);
+    assert_eq!(PlyDataType::Float.size(), 4);
+    assert_eq!(PlyDataType::Double.size(), 8);
+  }
+
+  #[test]
+  fn parses_header_elements_and_properties() {
+    let (header, body_start) = PlyFileParser::parse_header(TRIANGLE_HEADER.as_bytes()).unwrap();
+
+    assert!(header.format == PlyFormat::Ascii);
+    assert_eq!(body_start, TRIANGLE_HEADER.len());
+    assert_eq!(header.elements.len(), 2);
+
+    assert!(header.elements[0].name == "vertex");
+    assert_eq!(header.elements[0].count, 3);
+    assert_eq!(header.elements[0].properties.len(), 3);
+    assert!(header.elements[0].properties[2].name == "z");
+    assert!(header.elements[0].properties[2].count_type.is_none());
+
+    assert!(header.elements[1].properties[0].name == "vertex_indices");
+    assert!(header.elements[1].properties[0].data_type == PlyDataType::Int);
+    assert!(header.elements[1].properties[0].count_type == Some(PlyDataType::UChar));
+  }
+
+  #[test]
+  fn rejects_invalid_headers() {
+    assert!(PlyFileParser::parse_header(b"obj\nformat ascii 1.0\nend_header\n").is_err());
+    assert!(PlyFileParser::parse_header(b"ply\nend_header\n").is_err());
+    assert!(PlyFileParser::parse_header(b"ply\nformat ascii 1.0\nelement vertex 3\n").is_err());
+    assert!(PlyFileParser::parse_header(b"ply\nformat binary_middle_endian 1.0\nend_header\n").is_err());
+    assert!(PlyFileParser::parse_header(b"ply\nformat ascii 1.0\nproperty float x\nend_header\n").is_err());
+  }
+
+  #[test]
+  fn parses_ascii_mesh() {
+    let ply_data = format!("{}0 1 0\n-1 0 0\n1 0 0\n3 0 1 2\n", TRIANGLE_HEADER);
+
+    let ply_mesh = PlyFileParser::parse_mesh(ply_data.as_bytes()).unwrap();
+
+    assert_eq!(ply_mesh.vertices.len(), 3);
+    assert!(ply_mesh.vertices[1] == Point::new(-1.0, 0.0, 0.0));
+    assert!(ply_mesh.faces == vec![vec![0, 1, 2]]);
+    assert!(ply_mesh.normals.is_empty());
+    assert!(ply_mesh.colors.is_empty());
+  }
+
+  #[test]
+  fn parses_ascii_mesh_with_normals_colors_and_extra_elements() {
+    let ply_data = "ply\nformat ascii 1.0\nelement vertex 3\nproperty double x\nproperty double y\nproperty double z\nproperty float nx\nproperty float ny\nproperty float nz\nproperty uchar red\nproperty uchar green\nproperty uchar blue\nproperty float confidence\nelement face 1\nproperty uchar flags\nproperty list uchar uint vertex_index\nelement edge 1\nproperty int vertex1\nproperty int vertex2\nend_header\n0 1 0 0 1 0 255 0 0 0.5\n-1 0 0 -1 0 0 0 255 0 0.5\n1 0 0 1 0 0 0 0 255 0.5\n7 3 0 1 2\n0 1\n";
+
+    let ply_mesh = PlyFileParser::parse_mesh(ply_data.as_bytes()).unwrap();
+
+    assert_eq!(ply_mesh.vertices.len(), 3);
+    assert!(ply_mesh.normals[0] == Vector::new(0.0, 1.0, 0.0));
+    assert!(ply_mesh.normals[2] == Vector::new(1.0, 0.0, 0.0));
+    assert!(ply_mesh.colors[1] == Color::new(0.0, 1.0, 0.0, 1.0));
+    assert!(ply_mesh.faces == vec![vec![0, 1, 2]]);
+  }
+
+  #[test]
+  fn parses_binary_little_and_big_endian_meshes() {
+    for (format_name, big_endian) in [("binary_little_endian", false), ("binary_big_endian", true)].iter() {
+      let ply_mesh = PlyFileParser::parse_mesh(&binary_quad(format_name, *big_endian)).unwrap();
+
+      assert_eq!(ply_mesh.vertices.len(), 4);
+      assert!(ply_mesh.vertices[0] == Point::new(-1.0, 1.0, 0.0));
+      assert!(ply_mesh.vertices[3] == Point::new(1.0, 1.0, 0.0));
+      assert!(ply_mesh.colors[2] == Color::new(1.0, 0.0, 0.2, 1.0));
+      assert!(ply_mesh.faces == vec![vec![0, 1, 2, 3]]);
+    }
+  }
+
+  #[test]
+  fn reports_truncated_and_malformed_data() {
+    let mut ply_data = binary_quad("binary_little_endian", false);
+    ply_data.truncate(ply_data.len() - 2);
+    assert!(PlyFileParser::parse_mesh(&ply_data).is_err());
+
+    let ply_data = format!("{}0 1 0\n-1 0 0\n1 0 0\n", TRIANGLE_HEADER);
+    assert!(PlyFileParser::parse_mesh(ply_data.as_bytes()).is_err());
+
+    let ply_data = format!("{}0 1 0\n-1 zero 0\n1 0 0\n3 0 1 2\n", TRIANGLE_HEADER);
+    assert!(PlyFileParser::parse_mesh(ply_data.as_bytes()).is_err());
+
+    let ply_data = format!("{}0 1 0\n-1 nan 0\n1 0 0\n3 0 1 2\n", TRIANGLE_HEADER);
+    assert!(PlyFileParser::parse_mesh(ply_data.as_bytes()).is_err());
+
+    // First coordinate of first vertex follows header
+    let mut ply_data = binary_quad("binary_little_endian", false);
+    let header_size = ply_data.windows(11).position(|window| window == b"end_header\n").unwrap() + 11;
+    ply_data[header_size..(header_size + 4)].copy_from_slice(&f32::INFINITY.to_le_bytes());
+    assert!(PlyFileParser::parse_mesh(&ply_data).err().unwrap().contains("not a finite number"));
+
+    let ply_data = format!("{}0 1 0\n-1 0 0\n1 0 0\n3 0 1 3\n", TRIANGLE_HEADER);
+    assert!(PlyFileParser::parse_mesh(ply_data.as_bytes()).is_err());
+
+    let ply_data = format!("{}0 1 0\n-1 0 0\n1 0 0\n2 0 1\n", TRIANGLE_HEADER);
+    assert!(PlyFileParser::parse_mesh(ply_data.as_bytes()).is_err());
+  }
+
+  #[test]
+  fn loads_polygon_faces_as_fanned_triangles() {
+    let file_name = write_ply_file("quad.ply", &binary_quad("binary_little_endian", false));
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let container = PlyFileParser::load_file(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles).unwrap();
+
+    assert_eq!(container.shapes.len(), 2);
+
+    assert!(triangles[0].point_1 == Point::new(-1.0, 1.0, 0.0));
+    assert!(triangles[0].point_2 == Point::new(-1.0, 0.0, 0.0));
+    assert!(triangles[0].point_3 == Point::new(1.0, 0.0, 0.0));
+    assert!(triangles[1].point_1 == Point::new(-1.0, 1.0, 0.0));
+    assert!(triangles[1].point_2 == Point::new(1.0, 0.0, 0.0));
+    assert!(triangles[1].point_3 == Point::new(1.0, 1.0, 0.0));
+
+    assert!(triangles[1].material.color_1 == Color::new(1.0, 0.0, 0.2, 1.0));
+  }
+
+  #[test]
+  fn averages_vertex_colors_over_faces() {
+    let ply_data = "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\nproperty float red\nproperty float green\nproperty float blue\nelement face 1\nproperty list uchar int vertex_indices\nend_header\n0 1 0 0.3 0 0\n-1 0 0 0 0.6 0\n1 0 0 0 0 0.9\n3 0 1 2\n";
+
+    let ply_mesh = PlyFileParser::parse_mesh(ply_data.as_bytes()).unwrap();
+    let material = PlyFileParser::face_material(&ply_mesh, &ply_mesh.faces[0], Color::new(1.0, 1.0, 1.0, 1.0));
+
+    assert!((material.color_1.r - 0.1).abs() < 0.0001);
+    assert!((material.color_1.g - 0.2).abs() < 0.0001);
+    assert!((material.color_1.b - 0.3).abs() < 0.0001);
+  }
+
+  #[test]
+  fn loads_smooth_triangles_from_vertex_normals() {
+    let ply_data = "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\nproperty float z\nproperty float nx\nproperty float ny\nproperty float nz\nelement face 1\nproperty list uchar int vertex_indices\nend_header\n0 1 0 0 1 0\n-1 0 0 -1 0 0\n1 0 0 1 0 0\n3 0 1 2\n";
+    let file_name = write_ply_file("normals.ply", ply_data.as_bytes());
+
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    let container = PlyFileParser::load_file_with_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut smooth_triangles).unwrap();
+
+    assert_eq!(container.shapes.len(), 1);
+    assert!(smooth_triangles[0].normal_1 == Vector::new(0.0, 1.0, 0.0));
+    assert!(smooth_triangles[0].normal_2 == Vector::new(-1.0, 0.0, 0.0));
+    assert!(smooth_triangles[0].normal_3 == Vector::new(1.0, 0.0, 0.0));
+  }
+
+  #[test]
+  fn reports_missing_normals_and_files() {
+    let ply_data = format!("{}0 1 0\n-1 0 0\n1 0 0\n3 0 1 2\n", TRIANGLE_HEADER);
+    let file_name = write_ply_file("no_normals.ply", ply_data.as_bytes());
+
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    let result = PlyFileParser::load_file_with_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut smooth_triangles);
+
+    match result {
+      Err(PlyError::Parse { file_name: _, message: _ }) => {},
+      _ => panic!("Expected parse error")
+    }
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let result = PlyFileParser::load_file("does_not_exist.ply".to_string(), Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles);
+
+    match result {
+      Err(PlyError::Io { file_name, error: _ }) => assert_eq!(file_name, "does_not_exist.ply"),
+      _ => panic!("Expected io error")
+    }
+  }
+
+  #[test]
+  fn loads_file_as_mesh() {
+    let file_name = write_ply_file("mesh_quad.ply", &binary_quad("binary_big_endian", true));
+
+    let mesh = PlyFileParser::load_mesh(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0)).unwrap();
+
+    assert_eq!(mesh.faces.len(), 2);
+    assert_eq!(mesh.data.vertices.len(), 4);
+    assert!(mesh.faces.iter().all(|face| face.material == 1 && face.normals.is_none()));
+    assert!(mesh.data.materials[1].color_1 == Color::new(1.0, 0.0, 0.2, 1.0));
+  }
+
+  #[test]
+  fn mesh_faces_of_one_color_share_a_material() {
+    let ply_data = "ply\nformat ascii 1.0\nelement vertex 5\nproperty float x\nproperty float y\nproperty float z\nproperty uchar red\nproperty uchar green\nproperty uchar blue\nelement face 3\nproperty list uchar int vertex_indices\nend_header\n0 0 0 255 0 0\n1 0 0 255 0 0\n1 1 0 255 0 0\n0 1 0 255 0 0\n2 0 0 0 0 255\n3 0 1 2\n3 0 2 3\n3 1 4 2\n";
+    let file_name = write_ply_file("mesh_colors.ply", ply_data.as_bytes());
+
+    let mesh = PlyFileParser::load_mesh(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0)).unwrap();
+
+    assert_eq!(mesh.data.materials.len(), 3);
+    assert_eq!(mesh.faces[0].material, 1);
+    assert_eq!(mesh.faces[1].material, 1);
+    assert_eq!(mesh.faces[2].material, 2);
+    assert!(mesh.data.materials[1].color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+  }
+}
@@ -1,10 +1,13 @@
-use crate::rendering::math::tuple::Tuple;
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
 use crate::rendering::math::Color;
 
 use crate::rendering::shapes::shape::Shape;
 
+use crate::rendering::LightSample;
+
+use crate::rendering::Computations;
+
 #[derive(PartialEq)]
 pub struct PointLight {
   pub intensity: Color,
@@ -22,32 +25,22 @@ impl PointLight {
 
   pub fn lighting(&self, object: &dyn Shape, position: &Point, eye_v: &Vector, normal: &Vector, in_shadow: bool) -> Color {
     let material = object.get_material();
-    
-    let effective_color = material.color_at(object, position).mult_color(&self.intensity);
-
-    // direction to light source
-    let light_v = self.position.subtract_point(position).normalize();
+    let surface_color = material.color_at(object, position);
 
-    let ambient = effective_color.mult_scalar(material.ambient);
-    let mut diffuse = Color::new(0.0, 0.0, 0.0, 0.0);
-    let mut specular = Color::new(0.0, 0.0, 0.0, 0.0);
+    material.shading_model.shade(material, &surface_color, &self.sample(position, in_shadow), eye_v, normal)
+  }
 
-    // check if light is on other side of surface
-    let light_dot_normal = light_v.dot(normal);
-    if !in_shadow && light_dot_normal >= 0.0 {
-      diffuse = effective_color.mult_scalar(material.diffuse).mult_scalar(light_dot_normal);
+  pub fn lighting_at(&self, computations: &Computations, in_shadow: bool) -> Color {
+    let material = computations.get_material();
+    let surface_color = computations.surface_color();
 
-      // check if light reflects away from eye
-      let negative_light_direction = light_v.multiply(-1.0);
-      let reflect_v = negative_light_direction.reflect(&normal);
-      let reflect_dot_eye = reflect_v.dot(eye_v);
+    material.shading_model.shade(material, &surface_color, &self.sample(&computations.point, in_shadow), &computations.eye_v, &computations.normal)
+  }
 
-      if reflect_dot_eye > 0.0 {
-        let factor = reflect_dot_eye.powf(material.shininess);
-        specular = self.intensity.mult_scalar(material.specular).mult_scalar(factor);
-      }
-    }
+  // Light arriving at position from light
+  pub fn sample(&self, position: &Point, in_shadow: bool) -> LightSample {
+    let light_v = self.position.subtract_point(position).normalize();
 
-    ambient.add_color(&diffuse).add_color(&specular)
+    LightSample::new(self.intensity, light_v, in_shadow)
   }
 }
@@ -229,4 +229,46 @@ mod tests {
     assert_eq!(pattern_point.y, 0.5);
     assert_eq!(pattern_point.z, 0.25);
   }
+
+  #[test]
+  fn lighting_rough_dielectric_matches_full_diffuse_phong_when_lit_along_normal() {
+    let point_light = PointLight::new(Color::new(1.0, 1.0, 1.0, 1.0), Point::new(0.0, 0.0, -10.0));
+
+    let material = Material::metallic_roughness(0.1, 0.0, 1.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let position = Point::empty();
+    let eye_v = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+
+    let lighting_color = point_light.lighting(sphere, &position, &eye_v, &normal, false);
+
+    // Ambient, scattered light and a dim highlight from dielectric reflectance
+    assert!((lighting_color.r - (0.1 + 0.96 + 0.01)).abs() < 0.0001);
+    assert!((lighting_color.g - (0.1 + 0.96 + 0.01)).abs() < 0.0001);
+    assert!((lighting_color.b - (0.1 + 0.96 + 0.01)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn lighting_metal_in_shadow_leaves_only_ambient() {
+    let point_light = PointLight::new(Color::new(1.0, 1.0, 1.0, 1.0), Point::new(0.0, 0.0, -10.0));
+
+    let material = Material::metallic_roughness(0.1, 1.0, 0.3, 0.0, 1.0, Color::new(1.0, 0.5, 0.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let position = Point::empty();
+    let eye_v = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+
+    let lit_color = point_light.lighting(sphere, &position, &eye_v, &normal, false);
+    let shadowed_color = point_light.lighting(sphere, &position, &eye_v, &normal, true);
+
+    assert!((shadowed_color.r - 0.1).abs() < 0.0001);
+    assert!((shadowed_color.g - 0.05).abs() < 0.0001);
+    assert!(shadowed_color.b.abs() < 0.0001);
+
+    // Highlight is tinted by color of metal
+    assert!(lit_color.r > lit_color.g && lit_color.g > lit_color.b);
+    assert!(lit_color.b.abs() < 0.0001);
+  }
 }
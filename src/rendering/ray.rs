@@ -2,9 +2,12 @@ use crate::rendering::math::Matrix4x4;
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
 
+use crate::rendering::RayDifferentials;
+
 pub struct Ray {
   pub origin: Point,
-  pub direction: Vector
+  pub direction: Vector,
+  pub differentials: Option<RayDifferentials>
 }
 
 impl Ray {
@@ -12,7 +15,15 @@ impl Ray {
     let origin_point = Point::new(origin.x, origin.y, origin.z);
     let direction_vector = Vector::new(direction.x, direction.y, direction.z);
     
-    Ray { origin: origin_point, direction: direction_vector }
+    Ray { origin: origin_point, direction: direction_vector, differentials: None }
+  }
+
+  // Ray that tracks footprint of neighbouring rays, for filtering textures
+  pub fn new_with_differentials(origin: &Point, direction: &Vector, differentials: RayDifferentials) -> Ray {
+    let mut ray = Ray::new(origin, direction);
+    ray.differentials = Some(differentials);
+
+    ray
   }
 
   pub fn position(&self, t: f64) -> Point {
@@ -29,6 +40,15 @@ impl Ray {
     let new_origin = Point::new(new_position_x, new_position_y, new_position_z);
     let new_direction = Vector::new(new_direction_x, new_direction_y, new_direction_z);
 
-    Ray { origin: new_origin, direction: new_direction }
+    let new_differentials = self.differentials.map(|differentials| {
+      RayDifferentials::new(
+        transformation.mult_vector(&differentials.origin_dx),
+        transformation.mult_vector(&differentials.origin_dy),
+        transformation.mult_vector(&differentials.direction_dx),
+        transformation.mult_vector(&differentials.direction_dy)
+      )
+    });
+
+    Ray { origin: new_origin, direction: new_direction, differentials: new_differentials }
   }
 }
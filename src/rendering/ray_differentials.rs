@@ -0,0 +1,124 @@
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Vector;
+
+const DIFFERENTIAL_EPSILON: f64 = 0.000001;
+
+// Change in origin and direction of a ray for a step of one sample to the right, and one sample down
+#[derive(Copy, Clone, Debug)]
+pub struct RayDifferentials {
+  pub origin_dx: Vector,
+  pub origin_dy: Vector,
+  pub direction_dx: Vector,
+  pub direction_dy: Vector
+}
+
+// Change in position, normal and texture coordinates of hit, for the same steps
+#[derive(Copy, Clone, Debug)]
+pub struct SurfaceDifferentials {
+  pub point_dx: Vector,
+  pub point_dy: Vector,
+  pub normal_dx: Vector,
+  pub normal_dy: Vector,
+  pub texture_dx: (f64, f64),
+  pub texture_dy: (f64, f64)
+}
+
+impl RayDifferentials {
+  pub fn new(origin_dx: Vector, origin_dy: Vector, direction_dx: Vector, direction_dy: Vector) -> RayDifferentials {
+    RayDifferentials {
+      origin_dx,
+      origin_dy,
+      direction_dx,
+      direction_dy
+    }
+  }
+
+  // Change in normalized direction, for a change in direction before it was normalized
+  pub fn normalized_direction_offset(direction: &Vector, direction_offset: &Vector) -> Vector {
+    let length_squared = direction.dot(direction);
+    let length = length_squared.sqrt();
+
+    let offset = direction_offset.multiply(length_squared).subtract_vector(&direction.multiply(direction.dot(direction_offset)));
+
+    offset.multiply(1.0 / (length_squared * length))
+  }
+
+  // Offsets of hit point for neighbouring rays, where they meet plane tangent to surface at hit
+  pub fn point_offsets(&self, direction: &Vector, t: f64, normal: &Vector) -> (Vector, Vector) {
+    (
+      transfer_offset(&self.origin_dx, &self.direction_dx, direction, t, normal),
+      transfer_offset(&self.origin_dy, &self.direction_dy, direction, t, normal)
+    )
+  }
+
+  // Differentials of ray reflected about normal, eye vector points back along incoming ray
+  pub fn reflect(&self, eye_v: &Vector, normal: &Vector, surface: &SurfaceDifferentials) -> RayDifferentials {
+    RayDifferentials::new(
+      surface.point_dx,
+      surface.point_dy,
+      reflected_offset(eye_v, normal, &self.direction_dx, &surface.normal_dx),
+      reflected_offset(eye_v, normal, &self.direction_dy, &surface.normal_dy)
+    )
+  }
+
+  // Differentials of ray refracted through surface, n_ratio is ratio of refractive indices being left and entered
+  pub fn refract(&self, eye_v: &Vector, normal: &Vector, refracted_direction: &Vector, n_ratio: f64, surface: &SurfaceDifferentials) -> RayDifferentials {
+    RayDifferentials::new(
+      surface.point_dx,
+      surface.point_dy,
+      refracted_offset(eye_v, normal, refracted_direction, n_ratio, &self.direction_dx, &surface.normal_dx),
+      refracted_offset(eye_v, normal, refracted_direction, n_ratio, &self.direction_dy, &surface.normal_dy)
+    )
+  }
+}
+
+impl SurfaceDifferentials {
+  // Half widths of box around texture coordinates covered by footprint of ray
+  pub fn texture_widths(&self) -> (f64, f64) {
+    (
+      self.texture_dx.0.abs().max(self.texture_dy.0.abs()) / 2.0,
+      self.texture_dx.1.abs().max(self.texture_dy.1.abs()) / 2.0
+    )
+  }
+}
+
+fn transfer_offset(origin_offset: &Vector, direction_offset: &Vector, direction: &Vector, t: f64, normal: &Vector) -> Vector {
+  let offset = origin_offset.add_vector(&direction_offset.multiply(t));
+
+  // Ray parallel to surface has no sensible footprint, offset is left where neighbouring ray is at t
+  let direction_dot_normal = direction.dot(normal);
+  if direction_dot_normal.abs() < DIFFERENTIAL_EPSILON {
+    return offset;
+  }
+
+  let t_offset = -offset.dot(normal) / direction_dot_normal;
+
+  offset.add_vector(&direction.multiply(t_offset))
+}
+
+fn reflected_offset(eye_v: &Vector, normal: &Vector, direction_offset: &Vector, normal_offset: &Vector) -> Vector {
+  let eye_offset = direction_offset.multiply(-1.0);
+  let eye_dot_normal_offset = eye_offset.dot(normal) + eye_v.dot(normal_offset);
+
+  let normal_change = normal_offset.multiply(eye_v.dot(normal)).add_vector(&normal.multiply(eye_dot_normal_offset));
+
+  direction_offset.add_vector(&normal_change.multiply(2.0))
+}
+
+fn refracted_offset(eye_v: &Vector, normal: &Vector, refracted_direction: &Vector, n_ratio: f64, direction_offset: &Vector, normal_offset: &Vector) -> Vector {
+  let eye_offset = direction_offset.multiply(-1.0);
+  let eye_dot_normal_offset = eye_offset.dot(normal) + eye_v.dot(normal_offset);
+
+  let cos_i = eye_v.dot(normal);
+  let cos_t = refracted_direction.dot(normal).abs();
+
+  // Refracted ray grazing surface is left with direction offsets of a straight ray
+  if cos_t < DIFFERENTIAL_EPSILON {
+    return direction_offset.multiply(n_ratio);
+  }
+
+  let mu = n_ratio * cos_i - cos_t;
+  let mu_offset = (n_ratio - (n_ratio * n_ratio * cos_i) / cos_t) * eye_dot_normal_offset;
+
+  eye_offset.multiply(-n_ratio).add_vector(&normal_offset.multiply(mu)).add_vector(&normal.multiply(mu_offset))
+}
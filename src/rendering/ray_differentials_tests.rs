@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::RayDifferentials;
+  use crate::rendering::SurfaceDifferentials;
+
+  fn flat_surface(point_dx: Vector, point_dy: Vector) -> SurfaceDifferentials {
+    SurfaceDifferentials {
+      point_dx,
+      point_dy,
+      normal_dx: Vector::new(0.0, 0.0, 0.0),
+      normal_dy: Vector::new(0.0, 0.0, 0.0),
+      texture_dx: (0.0, 0.0),
+      texture_dy: (0.0, 0.0)
+    }
+  }
+
+  #[test]
+  fn differentials_created_with_origin_and_direction_offsets() {
+    let differentials = RayDifferentials::new(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+
+    assert!(differentials.origin_dx == Vector::new(1.0, 0.0, 0.0));
+    assert!(differentials.origin_dy == Vector::new(0.0, 1.0, 0.0));
+    assert!(differentials.direction_dx == Vector::new(0.1, 0.0, 0.0));
+    assert!(differentials.direction_dy == Vector::new(0.0, 0.1, 0.0));
+  }
+
+  #[test]
+  fn normalized_direction_offset_is_perpendicular_to_direction() {
+    let direction = Vector::new(1.0, 2.0, -2.0);
+    let offset = RayDifferentials::normalized_direction_offset(&direction, &Vector::new(0.01, 0.0, 0.0));
+
+    assert!(offset.dot(&direction).abs() < 0.0001);
+
+    // Matches difference of normalized directions for a small step
+    let expected = Vector::new(1.01, 2.0, -2.0).normalize().subtract_vector(&direction.normalize());
+
+    assert!((offset.x - expected.x).abs() < 0.0001);
+    assert!((offset.y - expected.y).abs() < 0.0001);
+    assert!((offset.z - expected.z).abs() < 0.0001);
+  }
+
+  #[test]
+  fn point_offsets_spread_with_distance() {
+    let differentials = RayDifferentials::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.01, 0.0, 0.0), Vector::new(0.0, 0.01, 0.0));
+
+    let (point_dx, point_dy) = differentials.point_offsets(&Vector::new(0.0, 0.0, 1.0), 10.0, &Vector::new(0.0, 0.0, -1.0));
+
+    assert!(point_dx == Vector::new(0.1, 0.0, 0.0));
+    assert!(point_dy == Vector::new(0.0, 0.1, 0.0));
+  }
+
+  #[test]
+  fn point_offsets_stay_on_tilted_surface() {
+    let differentials = RayDifferentials::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.01, 0.0, 0.0), Vector::new(0.0, 0.01, 0.0));
+    let normal = Vector::new(0.0, 1.0, -1.0).normalize();
+
+    let (point_dx, point_dy) = differentials.point_offsets(&Vector::new(0.0, 0.0, 1.0), 10.0, &normal);
+
+    assert!(point_dx.dot(&normal).abs() < 0.0001);
+    assert!(point_dy.dot(&normal).abs() < 0.0001);
+
+    // Surface tilted away from ray stretches footprint along tilt
+    assert!((point_dy.y - 0.1).abs() < 0.0001);
+    assert!((point_dy.z - 0.1).abs() < 0.0001);
+  }
+
+  #[test]
+  fn reflecting_off_flat_mirror_keeps_sideways_spread() {
+    let differentials = RayDifferentials::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.01, 0.0, 0.0), Vector::new(0.0, 0.01, 0.0));
+    let surface = flat_surface(Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+
+    let reflected = differentials.reflect(&Vector::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, -1.0), &surface);
+
+    assert!(reflected.origin_dx == Vector::new(0.1, 0.0, 0.0));
+    assert!(reflected.origin_dy == Vector::new(0.0, 0.1, 0.0));
+    assert!(reflected.direction_dx == Vector::new(0.01, 0.0, 0.0));
+    assert!(reflected.direction_dy == Vector::new(0.0, 0.01, 0.0));
+  }
+
+  #[test]
+  fn reflecting_off_curved_mirror_widens_spread() {
+    let differentials = RayDifferentials::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0));
+
+    // Convex mirror bends normal toward step
+    let mut surface = flat_surface(Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+    surface.normal_dx = Vector::new(0.1, 0.0, 0.0);
+
+    let reflected = differentials.reflect(&Vector::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, -1.0), &surface);
+
+    assert!(reflected.direction_dx == Vector::new(0.2, 0.0, 0.0));
+    assert!(reflected.direction_dy == Vector::new(0.0, 0.0, 0.0));
+  }
+
+  #[test]
+  fn refracting_between_matching_indices_keeps_spread() {
+    let differentials = RayDifferentials::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.01, 0.0, 0.0), Vector::new(0.0, 0.01, 0.0));
+    let surface = flat_surface(Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+
+    let refracted = differentials.refract(&Vector::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, 1.0), 1.0, &surface);
+
+    assert!(refracted.origin_dx == Vector::new(0.1, 0.0, 0.0));
+    assert!((refracted.direction_dx.x - 0.01).abs() < 0.0001);
+    assert!((refracted.direction_dy.y - 0.01).abs() < 0.0001);
+  }
+
+  #[test]
+  fn refracting_into_denser_material_narrows_spread() {
+    let differentials = RayDifferentials::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0), Vector::new(0.01, 0.0, 0.0), Vector::new(0.0, 0.01, 0.0));
+    let surface = flat_surface(Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+
+    let refracted = differentials.refract(&Vector::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, 1.0), 1.0 / 1.5, &surface);
+
+    assert!((refracted.direction_dx.x - 0.01 / 1.5).abs() < 0.0001);
+    assert!((refracted.direction_dy.y - 0.01 / 1.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn texture_widths_cover_half_of_larger_step() {
+    let mut surface = flat_surface(Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+    surface.texture_dx = (0.2, -0.1);
+    surface.texture_dy = (-0.05, 0.3);
+
+    let (u_width, v_width) = surface.texture_widths();
+
+    assert!((u_width - 0.1).abs() < 0.0001);
+    assert!((v_width - 0.15).abs() < 0.0001);
+  }
+}
@@ -7,6 +7,7 @@ mod tests {
   use crate::rendering::math::Matrix4x4;
   
   use crate::rendering::Ray;
+  use crate::rendering::RayDifferentials;
 
   #[test]
   fn new_sets_values() {
@@ -85,4 +86,24 @@ mod tests {
     assert!(transformed_ray.origin.get_quad() == (2.0, 6.0, 12.0, 1.0));
     assert!(transformed_ray.direction.get_quad() == (0.0, 3.0, 0.0, 0.0));
   }
+
+  #[test]
+  fn transforming_ray_transforms_differentials() {
+    let differentials = RayDifferentials::new(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), Vector::new(0.1, 0.0, 0.0), Vector::new(0.0, 0.1, 0.0));
+    let ray = Ray::new_with_differentials(&Point::new(1.0, 2.0, 3.0), &Vector::new(0.0, 1.0, 0.0), differentials);
+
+    let transformed_ray = ray.transform(&Matrix4x4::scale(2.0, 3.0, 4.0));
+    let transformed_differentials = transformed_ray.differentials.unwrap();
+
+    assert!(transformed_differentials.origin_dx == Vector::new(2.0, 0.0, 0.0));
+    assert!(transformed_differentials.origin_dy == Vector::new(0.0, 3.0, 0.0));
+    assert!(transformed_differentials.direction_dx == Vector::new(0.2, 0.0, 0.0));
+    assert!(transformed_differentials.direction_dy == Vector::new(0.0, 0.30000000000000004, 0.0));
+
+    // Plain rays carry no differentials
+    let ray = Ray::new(&Point::new(1.0, 2.0, 3.0), &Vector::new(0.0, 1.0, 0.0));
+
+    assert!(ray.differentials.is_none());
+    assert!(ray.transform(&Matrix4x4::scale(2.0, 3.0, 4.0)).differentials.is_none());
+  }
 }
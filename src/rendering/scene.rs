@@ -13,12 +13,25 @@ use crate::rendering::PointLight;
 
 use crate::rendering::Container;
 
+use crate::rendering::GeometryLight;
+
+use crate::rendering::PathTracer;
+
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 use crate::rendering::Computations;
 
+use crate::rendering::shading_model::schlick;
+
 const RAY_CAST_DEPTH: u32 = 128;
 
+// How colors of camera rays are found, chosen for each render
+pub enum Integrator {
+  // Reflections and refractions followed recursively, with ambient terms of materials standing in for indirect light
+  Whitted,
+  PathTraced(PathTracer)
+}
+
 pub struct Scene<'a> {
   pub camera: Camera,
   pub lights: Vec<PointLight>,
@@ -46,6 +59,11 @@ impl<'a> Scene<'a> {
     intersections 
   }
 
+  // Shapes whose materials emit light, to be sampled by integrators that light surfaces with them
+  pub fn geometry_lights(&self) -> Vec<GeometryLight<'a>> {
+    self.containers.iter().flat_map(|container| container.geometry_lights()).collect()
+  }
+
   pub fn is_shadowed(&self, world_position: &Point, light_position: &Point) -> bool {
     let towards_light = light_position.subtract_point(&world_position);
     let distance = towards_light.magnitude();
@@ -65,34 +83,25 @@ impl<'a> Scene<'a> {
   }
 
   pub fn shade_hit(&self, computations: &Computations, remaining_casts: u32) -> Color {
-    let mut shaded_color = Color::new(0.0, 0.0, 0.0, 1.0);
-
     // Always return black if maximum level of cast rays reached
     if remaining_casts <= 0 {
-      return shaded_color;
+      return Color::new(0.0, 0.0, 0.0, 1.0);
     }
 
+    // Emitted light is seen directly, and in reflections and refractions
+    let mut shaded_color = computations.get_material().emission;
+
     for light in &self.lights {
       let shadowed = self.is_shadowed(&computations.over_point, &light.position); 
 
-      let light_color = light.lighting(computations.object, &computations.point, &computations.eye_v, &computations.normal, shadowed);
+      let light_color = light.lighting_at(computations, shadowed);
       shaded_color = shaded_color.add_color(&light_color)
     }
 
     let reflected_color = self.reflected_color(computations, remaining_casts - 1);
     let refracted_color = self.refracted_color(computations, remaining_casts - 1);
 
-    let material = computations.object.get_material(); 
-    if material.reflectiveness > 0.0 && material.transparency > 0.0 {
-      let reflectance = self.schlick(computations);
-
-      let reflected_fresnel = reflected_color.mult_scalar(reflectance);
-      let refracted_fresnel = refracted_color.mult_scalar(1.0 - reflectance);
-
-      return shaded_color.add_color(&reflected_fresnel).add_color(&refracted_fresnel);
-    } else {
-      return shaded_color.add_color(&reflected_color).add_color(&refracted_color); 
-    }
+    computations.get_material().shading_model.combine(computations, &shaded_color, &reflected_color, &refracted_color)
   }
 
   pub fn color_at(&self, ray: &Ray, remaining_casts: u32) -> Color {
@@ -108,78 +117,59 @@ impl<'a> Scene<'a> {
   }
 
   pub fn reflected_color(&self, computations: &Computations, remaining_casts: u32) -> Color {
-    if computations.object.get_material().reflectiveness <= 0.0 {
+    if computations.get_material().reflectiveness <= 0.0 {
       return Color::new(0.0, 0.0, 0.0, 1.0);
     } else {
-      let reflection_ray = Ray::new(&computations.over_point, &computations.reflect_v);
+      let mut reflection_ray = Ray::new(&computations.over_point, &computations.reflect_v);
+
+      // Footprint of reflected ray grows with curvature of surface
+      if let (Some(ray_differentials), Some(differentials)) = (&computations.ray_differentials, &computations.differentials) {
+        reflection_ray.differentials = Some(ray_differentials.reflect(&computations.eye_v, &computations.normal, differentials));
+      }
 
       let reflection_color = self.color_at(&reflection_ray, remaining_casts);
 
-      return reflection_color.mult_scalar(computations.object.get_material().reflectiveness);
+      reflection_color.mult_scalar(computations.get_material().reflectiveness)
     }
   }
 
   pub fn refracted_color(&self, computations: &Computations, remaining_casts: u32) -> Color {
-    if computations.object.get_material().transparency <= 0.0 {
+    if computations.get_material().transparency <= 0.0 {
       return Color::new(0.0, 0.0, 0.0, 1.0);
     } else {
       let n_ratio = computations.n1 / computations.n2;
 
-      // Dot calculates cosine of eye and normal vectors
-      let cos_i = computations.eye_v.dot(&computations.normal);
+      // Total internal reflection leaves nothing to refract
+      let direction = match computations.refracted_direction() {
+        Some(direction) => direction,
+        None => return Color::new(0.0, 0.0, 0.0, 1.0)
+      };
 
-      // Sin(theta_t)^2
-      let sin2_t = (n_ratio * n_ratio) * (1.0 - (cos_i * cos_i));
+      let mut refraction_ray = Ray::new(&computations.under_point, &direction);
 
-      // Total internal reflection if sin2_t > 1
-      if sin2_t > 1.0 {
-        return Color::new(0.0, 0.0, 0.0, 1.0);
+      if let (Some(ray_differentials), Some(differentials)) = (&computations.ray_differentials, &computations.differentials) {
+        refraction_ray.differentials = Some(ray_differentials.refract(&computations.eye_v, &computations.normal, &direction, n_ratio, differentials));
       }
 
-      // Cos(theta_t)
-      let cos_t = (1.0 - sin2_t).sqrt();
-
-      // Direction of refracted ray
-      let direction = computations.normal.multiply(n_ratio * cos_i - cos_t).subtract_vector(&computations.eye_v.multiply(n_ratio));
-
-      let refraction_ray = Ray::new(&computations.under_point, &direction);
-
       let refracted_color = self.color_at(&refraction_ray, remaining_casts);
     
-      return refracted_color.mult_scalar(computations.object.get_material().transparency);
+      refracted_color.mult_scalar(computations.get_material().transparency)
     }
   }
 
   pub fn schlick(&self, computations: &Computations) -> f64 {
-    // Dot calculates cosine of eye and normal vectors
-    let mut cos = computations.eye_v.dot(&computations.normal);
-    
-    // Total internal reflection is only possible if n1 is larger than n2
-    if computations.n1 > computations.n2 {
-      let n_ratio = computations.n1 / computations.n2;
-
-      // Sin(theta_t)^2
-      let sin2_t = (n_ratio * n_ratio) * (1.0 - (cos * cos));
-
-      // Total internal reflection if sin2_t > 1
-      if sin2_t > 1.0 {
-        return 1.0;
-      }
+    schlick(computations)
+  }
 
-      // Cos(theta_t)
-      let cos_t = (1.0 - sin2_t).sqrt();
+  pub fn render(&self) -> Canvas {
+    self.render_with(&Integrator::Whitted)
+  }
 
-      cos = cos_t;
+  pub fn render_with(&self, integrator: &Integrator) -> Canvas {
+    if let Integrator::PathTraced(path_tracer) = integrator {
+      return path_tracer.render(self);
     }
 
-    let c_0 = (computations.n1 - computations.n2) / (computations.n1 + computations.n2);
-    let r_0 = c_0 * c_0;
-
-    let sub_cos = 1.0 - cos;
-    r_0 + (1.0 - r_0) * (sub_cos * sub_cos * sub_cos * sub_cos * sub_cos)
-  }
-
-  pub fn render(&self) -> Canvas {
     let mut canvas = Canvas::new(self.camera.horizontal_size, self.camera.vertical_size);
 
     for y in 0..self.camera.vertical_size {
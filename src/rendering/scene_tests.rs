@@ -809,4 +809,34 @@ mod tests {
     assert_eq!(shaded_color.b, 0.6924361306125347);
     assert_eq!(shaded_color.a, 1.0);
   }
+
+  #[test]
+  fn emission_is_seen_directly_and_in_reflections() {
+    let camera = Camera::new(200, 100, f64::consts::PI / 2.0, Matrix4x4::identity());
+
+    let material = Material::emissive(Color::new(2.0, 1.0, 0.5, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = &Sphere::new(Matrix4x4::translate(0.0, 2.0, 1.0), material);
+
+    let material = Material::solid(0.0, 0.0, 0.0, 200.0, 0.5, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let plane = &Plane::new(Matrix4x4::translate(0.0, -1.0, 0.0), material);
+
+    let container = Container::new(Matrix4x4::identity(), vec![sphere as &dyn Shape, plane as &dyn Shape]);
+
+    // Glowing sphere needs no other lights to be seen
+    let scene = Scene::new(camera, Vec::new(), vec![container]);
+
+    assert_eq!(scene.geometry_lights().len(), 1);
+
+    let ray = Ray::new(&Point::new(0.0, 2.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let direct_color = scene.color_at(&ray, 4);
+
+    assert!(direct_color == Color::new(2.0, 1.0, 0.5, 1.0));
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -3.0), &Vector::new(0.0, -2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0));
+    let reflected_color = scene.color_at(&ray, 4);
+
+    assert!((reflected_color.r - 1.0).abs() < 0.0001);
+    assert!((reflected_color.g - 0.5).abs() < 0.0001);
+    assert!((reflected_color.b - 0.25).abs() < 0.0001);
+  }
 }
@@ -0,0 +1,274 @@
+use std::f64;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Vector;
+use crate::rendering::math::Color;
+
+use crate::rendering::Material;
+
+use crate::rendering::Computations;
+
+use crate::rendering::microfacet::cook_torrance;
+
+// Light arriving at a point on a surface, light_v points from surface towards light
+pub struct LightSample {
+  pub intensity: Color,
+  pub light_v: Vector,
+  pub in_shadow: bool
+}
+
+impl LightSample {
+  pub fn new(intensity: Color, light_v: Vector, in_shadow: bool) -> LightSample {
+    LightSample { intensity, light_v, in_shadow }
+  }
+}
+
+// How a material turns light into color, referenced by materials so scenes never depend on a particular model
+#[derive(PartialEq, Debug)]
+pub enum ShadingModelType {
+  Phong,
+  BlinnPhong,
+  Lambert,
+  MetallicRoughness,
+  // Models defined outside of the tracer, which formats it writes have no way of describing
+  Custom
+}
+
+pub trait ShadingModel {
+  // Name used when materials are written out
+  fn name(&self) -> &str;
+
+  // Models are told apart by type rather than name, which any model is free to choose
+  fn get_base_type(&self) -> ShadingModelType;
+
+  // Color reflected towards eye from a single light, surface color already includes patterns and textures
+  fn shade(&self, material: &Material, surface_color: &Color, light: &LightSample, eye_v: &Vector, normal: &Vector) -> Color;
+
+  // Share of light arriving along light_v reflected towards eye, scaled by cosine of light and normal, for integrators
+  // that follow light between surfaces, defaults to diffuse scattering of surface color
+  fn reflectance(&self, material: &Material, surface_color: &Color, eye_v: &Vector, normal: &Vector, light_v: &Vector) -> Color {
+    diffuse_reflectance(material, surface_color, eye_v, normal, light_v)
+  }
+
+  // Lit color combined with colors of reflected and refracted rays, which are already scaled by material
+  fn combine(&self, computations: &Computations, lit_color: &Color, reflected_color: &Color, refracted_color: &Color) -> Color {
+    let material = computations.get_material();
+
+    // Surfaces that both reflect and refract split light between them by fresnel reflectance
+    if material.reflectiveness > 0.0 && material.transparency > 0.0 {
+      let reflectance = schlick(computations);
+
+      let reflected_fresnel = reflected_color.mult_scalar(reflectance);
+      let refracted_fresnel = refracted_color.mult_scalar(1.0 - reflectance);
+
+      lit_color.add_color(&reflected_fresnel).add_color(&refracted_fresnel)
+    } else {
+      lit_color.add_color(reflected_color).add_color(refracted_color)
+    }
+  }
+}
+
+// Ambient, diffuse and specular terms, with highlight from reflection of light about normal
+pub struct Phong;
+
+// Phong with highlight from half vector between light and eye, broader at grazing angles
+pub struct BlinnPhong;
+
+// Ambient and diffuse terms only, for matte surfaces
+pub struct Lambert;
+
+// Cook-Torrance microfacet reflection, using metallic and roughness of material
+pub struct MetallicRoughness;
+
+impl ShadingModel for Phong {
+  fn name(&self) -> &str {
+    "phong"
+  }
+
+  fn get_base_type(&self) -> ShadingModelType {
+    ShadingModelType::Phong
+  }
+
+  fn shade(&self, material: &Material, surface_color: &Color, light: &LightSample, eye_v: &Vector, normal: &Vector) -> Color {
+    let (ambient, diffuse) = lambertian(material, surface_color, light, normal);
+    let mut specular = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    // check if light is on other side of surface
+    if !light.in_shadow && light.light_v.dot(normal) >= 0.0 {
+      // check if light reflects away from eye
+      let negative_light_direction = light.light_v.multiply(-1.0);
+      let reflect_v = negative_light_direction.reflect(normal);
+      let reflect_dot_eye = reflect_v.dot(eye_v);
+
+      if reflect_dot_eye > 0.0 {
+        let factor = reflect_dot_eye.powf(material.shininess);
+        specular = light.intensity.mult_scalar(material.specular).mult_scalar(factor);
+      }
+    }
+
+    ambient.add_color(&diffuse).add_color(&specular)
+  }
+
+  // Highlight is scaled so it never reflects more light than arrives, however high shininess is
+  fn reflectance(&self, material: &Material, surface_color: &Color, eye_v: &Vector, normal: &Vector, light_v: &Vector) -> Color {
+    let diffuse = diffuse_reflectance(material, surface_color, eye_v, normal, light_v);
+
+    let light_dot_normal = light_v.dot(normal);
+    let reflect_dot_eye = light_v.multiply(-1.0).reflect(normal).dot(eye_v);
+
+    if light_dot_normal <= 0.0 || reflect_dot_eye <= 0.0 {
+      return diffuse;
+    }
+
+    let factor = (material.shininess + 2.0) / (2.0 * f64::consts::PI) * reflect_dot_eye.powf(material.shininess);
+
+    let specular = material.specular * factor * light_dot_normal;
+
+    diffuse.add_color(&Color::new(specular, specular, specular, 1.0))
+  }
+}
+
+impl ShadingModel for BlinnPhong {
+  fn name(&self) -> &str {
+    "blinn_phong"
+  }
+
+  fn get_base_type(&self) -> ShadingModelType {
+    ShadingModelType::BlinnPhong
+  }
+
+  fn shade(&self, material: &Material, surface_color: &Color, light: &LightSample, eye_v: &Vector, normal: &Vector) -> Color {
+    let (ambient, diffuse) = lambertian(material, surface_color, light, normal);
+    let mut specular = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    if !light.in_shadow && light.light_v.dot(normal) >= 0.0 {
+      let half_v = light.light_v.add_vector(eye_v).normalize();
+      let normal_dot_half = normal.dot(&half_v);
+
+      if normal_dot_half > 0.0 {
+        let factor = normal_dot_half.powf(material.shininess);
+        specular = light.intensity.mult_scalar(material.specular).mult_scalar(factor);
+      }
+    }
+
+    ambient.add_color(&diffuse).add_color(&specular)
+  }
+
+  fn reflectance(&self, material: &Material, surface_color: &Color, eye_v: &Vector, normal: &Vector, light_v: &Vector) -> Color {
+    let diffuse = diffuse_reflectance(material, surface_color, eye_v, normal, light_v);
+
+    let light_dot_normal = light_v.dot(normal);
+    let normal_dot_half = normal.dot(&light_v.add_vector(eye_v).normalize());
+
+    if light_dot_normal <= 0.0 || normal_dot_half <= 0.0 {
+      return diffuse;
+    }
+
+    let factor = (material.shininess + 8.0) / (8.0 * f64::consts::PI) * normal_dot_half.powf(material.shininess);
+
+    let specular = material.specular * factor * light_dot_normal;
+
+    diffuse.add_color(&Color::new(specular, specular, specular, 1.0))
+  }
+}
+
+impl ShadingModel for Lambert {
+  fn name(&self) -> &str {
+    "lambert"
+  }
+
+  fn get_base_type(&self) -> ShadingModelType {
+    ShadingModelType::Lambert
+  }
+
+  fn shade(&self, material: &Material, surface_color: &Color, light: &LightSample, _eye_v: &Vector, normal: &Vector) -> Color {
+    let (ambient, diffuse) = lambertian(material, surface_color, light, normal);
+
+    ambient.add_color(&diffuse)
+  }
+}
+
+impl ShadingModel for MetallicRoughness {
+  fn name(&self) -> &str {
+    "metallic_roughness"
+  }
+
+  fn get_base_type(&self) -> ShadingModelType {
+    ShadingModelType::MetallicRoughness
+  }
+
+  fn shade(&self, material: &Material, surface_color: &Color, light: &LightSample, eye_v: &Vector, normal: &Vector) -> Color {
+    // Ambient stands in for light bouncing between surfaces, as in phong
+    let ambient = surface_color.mult_color(&light.intensity).mult_scalar(material.ambient);
+
+    if light.in_shadow {
+      return ambient;
+    }
+
+    // Intensity is light falling on a surface facing it, so a rough white surface matches phong with full diffuse
+    let reflected = cook_torrance(surface_color, material.metallic, material.roughness, normal, eye_v, &light.light_v);
+
+    ambient.add_color(&reflected.mult_color(&light.intensity).mult_scalar(f64::consts::PI))
+  }
+
+  fn reflectance(&self, material: &Material, surface_color: &Color, eye_v: &Vector, normal: &Vector, light_v: &Vector) -> Color {
+    cook_torrance(surface_color, material.metallic, material.roughness, normal, eye_v, light_v)
+  }
+}
+
+// Ambient term, and diffuse term scaled by cosine of light and normal
+fn lambertian(material: &Material, surface_color: &Color, light: &LightSample, normal: &Vector) -> (Color, Color) {
+  let effective_color = surface_color.mult_color(&light.intensity);
+
+  let ambient = effective_color.mult_scalar(material.ambient);
+  let mut diffuse = Color::new(0.0, 0.0, 0.0, 0.0);
+
+  let light_dot_normal = light.light_v.dot(normal);
+  if !light.in_shadow && light_dot_normal >= 0.0 {
+    diffuse = effective_color.mult_scalar(material.diffuse).mult_scalar(light_dot_normal);
+  }
+
+  (ambient, diffuse)
+}
+
+// Lambertian scattering, diffuse of material is share of light scattered rather than absorbed
+fn diffuse_reflectance(material: &Material, surface_color: &Color, eye_v: &Vector, normal: &Vector, light_v: &Vector) -> Color {
+  let light_dot_normal = light_v.dot(normal);
+
+  // Light and eye must be on same side of surface
+  if light_dot_normal <= 0.0 || eye_v.dot(normal) <= 0.0 {
+    return Color::new(0.0, 0.0, 0.0, 1.0);
+  }
+
+  surface_color.mult_scalar(material.diffuse * light_dot_normal / f64::consts::PI)
+}
+
+// Share of light reflected rather than refracted, from Schlick approximation of fresnel equations
+pub fn schlick(computations: &Computations) -> f64 {
+  // Dot calculates cosine of eye and normal vectors
+  let mut cos = computations.eye_v.dot(&computations.normal);
+
+  // Total internal reflection is only possible if n1 is larger than n2
+  if computations.n1 > computations.n2 {
+    let n_ratio = computations.n1 / computations.n2;
+
+    // Sin(theta_t)^2
+    let sin2_t = (n_ratio * n_ratio) * (1.0 - (cos * cos));
+
+    // Total internal reflection if sin2_t > 1
+    if sin2_t > 1.0 {
+      return 1.0;
+    }
+
+    // Cos(theta_t)
+    let cos_t = (1.0 - sin2_t).sqrt();
+
+    cos = cos_t;
+  }
+
+  let c_0 = (computations.n1 - computations.n2) / (computations.n1 + computations.n2);
+  let r_0 = c_0 * c_0;
+
+  let sub_cos = 1.0 - cos;
+  r_0 + (1.0 - r_0) * (sub_cos * sub_cos * sub_cos * sub_cos * sub_cos)
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use std::rc::Rc;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Sphere;
+
+  use crate::rendering::Scene;
+  use crate::rendering::Camera;
+  use crate::rendering::PointLight;
+  use crate::rendering::Container;
+
+  use crate::rendering::Material;
+
+  use crate::rendering::LightSample;
+  use crate::rendering::ShadingModel;
+  use crate::rendering::ShadingModelType;
+  use crate::rendering::Phong;
+  use crate::rendering::BlinnPhong;
+  use crate::rendering::Lambert;
+
+  use crate::rendering::Ray;
+  use crate::rendering::Intersection;
+  use crate::rendering::Computations;
+
+  // Shows surface color whatever the light, and ignores reflections
+  struct Unlit;
+
+  impl ShadingModel for Unlit {
+    fn name(&self) -> &str {
+      "unlit"
+    }
+
+    fn get_base_type(&self) -> ShadingModelType {
+      ShadingModelType::Custom
+    }
+
+    fn shade(&self, _material: &Material, surface_color: &Color, _light: &LightSample, _eye_v: &Vector, _normal: &Vector) -> Color {
+      *surface_color
+    }
+
+    fn combine(&self, _computations: &Computations, lit_color: &Color, _reflected_color: &Color, _refracted_color: &Color) -> Color {
+      *lit_color
+    }
+  }
+
+  fn white_material(shading_model: Rc<dyn ShadingModel>) -> Material {
+    let mut material = Material::solid(0.1, 0.9, 0.9, 10.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    material.shading_model = shading_model;
+
+    material
+  }
+
+  #[test]
+  fn models_agree_with_eye_and_light_along_normal() {
+    let light = LightSample::new(Color::new(1.0, 1.0, 1.0, 1.0), Vector::new(0.0, 0.0, -1.0), false);
+    let eye_v = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    let phong = Phong.shade(&white_material(Rc::new(Phong)), &color, &light, &eye_v, &normal);
+    let blinn_phong = BlinnPhong.shade(&white_material(Rc::new(BlinnPhong)), &color, &light, &eye_v, &normal);
+    let lambert = Lambert.shade(&white_material(Rc::new(Lambert)), &color, &light, &eye_v, &normal);
+
+    assert!((phong.r - 1.9).abs() < 0.0001);
+    assert!((blinn_phong.r - 1.9).abs() < 0.0001);
+
+    // Lambert has no highlight
+    assert!((lambert.r - 1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn blinn_phong_highlight_is_broader_than_phong() {
+    let light = LightSample::new(Color::new(1.0, 1.0, 1.0, 1.0), Vector::new(0.0, 0.0, -1.0), false);
+    let eye_v = Vector::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    let phong = Phong.shade(&white_material(Rc::new(Phong)), &color, &light, &eye_v, &normal);
+    let blinn_phong = BlinnPhong.shade(&white_material(Rc::new(BlinnPhong)), &color, &light, &eye_v, &normal);
+
+    // Half vector is only half as far from normal as reflection is from eye
+    let half_angle_cos = (f64::consts::PI / 8.0).cos();
+    assert!((blinn_phong.r - (1.0 + 0.9 * half_angle_cos.powf(10.0))).abs() < 0.0001);
+    assert!((phong.r - (1.0 + 0.9 * (2.0_f64.sqrt() / 2.0).powf(10.0))).abs() < 0.0001);
+  }
+
+  #[test]
+  fn models_leave_only_ambient_in_shadow() {
+    let light = LightSample::new(Color::new(1.0, 1.0, 1.0, 1.0), Vector::new(0.0, 0.0, -1.0), true);
+    let eye_v = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    let models: Vec<Rc<dyn ShadingModel>> = vec![Rc::new(Phong), Rc::new(BlinnPhong), Rc::new(Lambert)];
+
+    for model in models {
+      let shaded = model.shade(&white_material(Rc::clone(&model)), &color, &light, &eye_v, &normal);
+
+      assert!((shaded.r - 0.1).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn scene_shades_hits_with_model_of_material() {
+    let camera = Camera::new(200, 100, f64::consts::PI / 2.0, Matrix4x4::identity());
+
+    let light = PointLight::new(Color::new(1.0, 1.0, 1.0, 1.0), Point::new(-10.0, 10.0, -10.0));
+
+    let mut material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.5, 0.0, 1.0, Color::new(0.2, 0.4, 0.6, 1.0), Matrix4x4::identity());
+    material.shading_model = Rc::new(Unlit);
+    let sphere = &Sphere::new(Matrix4x4::identity(), material);
+
+    let container = Container::new(Matrix4x4::identity(), vec![sphere as &dyn Shape]);
+
+    let scene = Scene::new(camera, vec![light], vec![container]);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = vec![Intersection::new(4.0, scene.containers[0].shapes[0], Matrix4x4::identity(), Matrix4x4::identity())];
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    let shaded_color = scene.shade_hit(&computations, 4);
+
+    assert!(shaded_color == Color::new(0.2, 0.4, 0.6, 1.0));
+  }
+
+  #[test]
+  fn reflectance_scatters_light_arriving_above_surface() {
+    let eye_v = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let color = Color::new(1.0, 1.0, 1.0, 1.0);
+
+    let material = white_material(Rc::new(Lambert));
+    let light_v = Vector::new(0.0, 0.5, -0.75_f64.sqrt());
+
+    // Diffuse share of light spread over hemisphere, scaled by cosine of light
+    let reflectance = Lambert.reflectance(&material, &color, &eye_v, &normal, &light_v);
+    assert!((reflectance.r - 0.9 * 0.75_f64.sqrt() / f64::consts::PI).abs() < 0.0001);
+
+    let below = Vector::new(0.0, 0.0, 1.0);
+    let models: Vec<Rc<dyn ShadingModel>> = vec![Rc::new(Phong), Rc::new(BlinnPhong), Rc::new(Lambert)];
+
+    for model in models {
+      let material = white_material(Rc::clone(&model));
+
+      assert!(model.reflectance(&material, &color, &eye_v, &normal, &below) == Color::new(0.0, 0.0, 0.0, 1.0));
+
+      // Highlights add to diffuse scattering when light is reflected towards eye
+      assert!(model.reflectance(&material, &color, &eye_v, &normal, &eye_v).r >= 0.9 / f64::consts::PI);
+    }
+  }
+}
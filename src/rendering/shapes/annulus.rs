@@ -0,0 +1,162 @@
+use std::f64;
+
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::azimuth_tangent;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Bounds;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
+// Flat ring in xz plane centered on origin, facing up like plane
+pub struct Annulus {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub inner_radius: f64,
+  pub outer_radius: f64,
+  pub material: Material
+}
+
+impl Annulus {
+  pub fn new(transform: Matrix4x4, inner_radius: f64, outer_radius: f64, material: Material) -> Annulus {
+    let tmp_inverse = transform.inverse();
+
+    Annulus {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      inner_radius,
+      outer_radius,
+      material
+    }
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    Bounds::new(Point::new(-self.outer_radius, 0.0, -self.outer_radius), Point::new(self.outer_radius, 0.0, self.outer_radius))
+  }
+}
+
+impl Shape for Annulus {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    // Ray parallel to annulus
+    if transformed_ray.direction.y.abs() < 0.0001 {
+      return intersections;
+    }
+
+    let t = -transformed_ray.origin.y / transformed_ray.direction.y;
+    let point = transformed_ray.position(t);
+
+    let distance = point.x * point.x + point.z * point.z;
+    if distance >= self.inner_radius * self.inner_radius && distance <= self.outer_radius * self.outer_radius {
+      intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, _point: &Point) -> Vector {
+    let object_normal = Vector::new(0.0, 1.0, 0.0);
+
+    self.transpose.mult_vector(&object_normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Polar mapping, azimuth around y axis and distance from inner to outer edge
+    let theta = object_point.x.atan2(object_point.z);
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    let distance = (object_point.x * object_point.x + object_point.z * object_point.z).sqrt();
+
+    (1.0 - (raw_u + 0.5), (distance - self.inner_radius) / (self.outer_radius - self.inner_radius))
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    let radial = Vector::new(object_point.x, 0.0, object_point.z);
+
+    (self.transform.mult_vector(&azimuth_tangent(&object_point)), self.transform.mult_vector(&radial))
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn surface_area(&self) -> Option<f64> {
+    Some(f64::consts::PI * (self.outer_radius * self.outer_radius - self.inner_radius * self.inner_radius))
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let inner_squared = self.inner_radius * self.inner_radius;
+    let outer_squared = self.outer_radius * self.outer_radius;
+
+    let radius = (inner_squared + sample.0 * (outer_squared - inner_squared)).sqrt();
+    let azimuth = 2.0 * f64::consts::PI * sample.1;
+
+    let point = Point::new(radius * azimuth.cos(), 0.0, radius * azimuth.sin());
+
+    Some(SurfaceSample::new(point, Vector::new(0.0, 1.0, 0.0), 1.0 / self.surface_area()?, self))
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Annulus
+  }
+
+  fn encloses_volume(&self) -> bool {
+    false
+  }
+
+  fn tessellate(&self, resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::annulus(self.inner_radius, self.outer_radius, resolution, self.material.clone())]
+  }
+}
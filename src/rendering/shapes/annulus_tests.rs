@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Annulus;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn annulus_created_with_transform_radii_and_material() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let annulus = Annulus::new(Matrix4x4::scale(2.0, 2.0, 2.0), 0.5, 1.0, material);
+
+    assert!(annulus.transform == Matrix4x4::scale(2.0, 2.0, 2.0));
+    assert!(annulus.inner_radius == 0.5);
+    assert!(annulus.outer_radius == 1.0);
+  }
+
+  #[test]
+  fn ray_intersects_annulus_between_radii() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let annulus = Annulus::new(Matrix4x4::identity(), 0.5, 1.0, material);
+
+    let examples = [
+      (Point::new(0.0, 1.0, 0.0), 0),
+      (Point::new(0.3, 1.0, 0.3), 0),
+      (Point::new(0.75, 1.0, 0.0), 1),
+      (Point::new(0.0, 1.0, -0.9), 1),
+      (Point::new(0.8, 1.0, 0.8), 0)
+    ];
+
+    for (origin, count) in examples.iter() {
+      let ray = Ray::new(origin, &Vector::new(0.0, -1.0, 0.0));
+
+      assert_eq!(annulus.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), *count);
+    }
+  }
+
+  #[test]
+  fn normal_of_annulus_is_constant() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let annulus = Annulus::new(Matrix4x4::identity(), 0.5, 1.0, material);
+
+    assert!(annulus.normal_at(&Point::new(0.75, 0.0, 0.0)) == Vector::new(0.0, 1.0, 0.0));
+  }
+
+  #[test]
+  fn annulus_texture_coordinates_run_around_and_across_ring() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let annulus = Annulus::new(Matrix4x4::identity(), 1.0, 2.0, material);
+
+    let (_, inner_v) = annulus.texture_coordinates(&Point::new(0.0, 0.0, 1.0), 0.0, 0.0);
+    let (_, outer_v) = annulus.texture_coordinates(&Point::new(0.0, 0.0, -2.0), 0.0, 0.0);
+    let (front_u, middle_v) = annulus.texture_coordinates(&Point::new(0.0, 0.0, -1.5), 0.0, 0.0);
+    let (side_u, _) = annulus.texture_coordinates(&Point::new(1.5, 0.0, 0.0), 0.0, 0.0);
+
+    assert!(inner_v.abs() < 0.0001);
+    assert!((outer_v - 1.0).abs() < 0.0001);
+    assert!((middle_v - 0.5).abs() < 0.0001);
+    assert!((front_u - 0.0).abs() < 0.0001 || (front_u - 1.0).abs() < 0.0001);
+    assert!((side_u - 0.25).abs() < 0.0001);
+  }
+
+  #[test]
+  fn annulus_bounds_cover_outer_radius() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let annulus = Annulus::new(Matrix4x4::identity(), 0.5, 1.5, material);
+
+    let bounds = annulus.bounds();
+
+    assert!(bounds.minimum == Point::new(-1.5, 0.0, -1.5));
+    assert!(bounds.maximum == Point::new(1.5, 0.0, 1.5));
+  }
+
+  #[test]
+  fn annulus_tessellates_to_upward_ring() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let annulus = Annulus::new(Matrix4x4::identity(), 0.5, 1.0, material);
+
+    let tessellations = annulus.tessellate(8, 10.0);
+    let tessellation = &tessellations[0];
+
+    assert_eq!(tessellation.faces.len(), 16);
+
+    // Faces wind counter clockwise seen from above
+    for face in &tessellation.faces {
+      let edge_1 = tessellation.vertices[face[1]].subtract_point(&tessellation.vertices[face[0]]);
+      let edge_2 = tessellation.vertices[face[2]].subtract_point(&tessellation.vertices[face[0]]);
+
+      assert!(edge_1.cross(&edge_2).y > 0.0);
+    }
+  }
+}
@@ -1,3 +1,5 @@
+use std::f64;
+
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
 
@@ -6,12 +8,15 @@ use crate::rendering::math::Matrix4x4;
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::shape::ShapeType;
 use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::azimuth_tangent;
 
 use crate::rendering::Material;
 
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
 pub struct Cone {
   pub id: u64,
   pub transform: Matrix4x4,
@@ -166,6 +171,25 @@ impl Shape for Cone {
     Vector::new(0.0, 0.0, 0.0)     
   }
 
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Cylindrical mapping, azimuth around y axis and height repeating every unit
+    let theta = object_point.x.atan2(object_point.z);
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    (1.0 - (raw_u + 0.5), object_point.y - object_point.y.floor())
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Height increases along line from apex through point, on either side of apex
+    let bitangent = Vector::new(object_point.x, object_point.y, object_point.z).multiply(object_point.y);
+
+    (self.transform.mult_vector(&azimuth_tangent(&object_point)), self.transform.mult_vector(&bitangent))
+  }
+
   fn interpolates_normals(&self) -> bool {
     false
   }
@@ -173,4 +197,8 @@ impl Shape for Cone {
   fn get_base_type(&self) -> ShapeType {
     ShapeType::Cone
   }
+
+  fn tessellate(&self, resolution: usize, extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::cone(self.minimum, self.maximum, self.capped, resolution, extent, self.material.clone())]
+  }
 }
@@ -12,6 +12,10 @@ use crate::rendering::Material;
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
 pub struct Cube {
   pub id: u64,
   pub transform: Matrix4x4,
@@ -151,11 +155,95 @@ impl Shape for Cube {
     Vector::new(0.0, 0.0, 0.0)     
   }
 
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    let x_abs = object_point.x.abs();
+    let y_abs = object_point.y.abs();
+    let z_abs = object_point.z.abs();
+
+    // Each face maps the whole texture, as seen from outside of the cube
+    if x_abs >= y_abs && x_abs >= z_abs {
+      if object_point.x > 0.0 {
+        ((1.0 - object_point.z) / 2.0, (object_point.y + 1.0) / 2.0)
+      } else {
+        ((object_point.z + 1.0) / 2.0, (object_point.y + 1.0) / 2.0)
+      }
+    } else if y_abs >= x_abs && y_abs >= z_abs {
+      if object_point.y > 0.0 {
+        ((object_point.x + 1.0) / 2.0, (1.0 - object_point.z) / 2.0)
+      } else {
+        ((object_point.x + 1.0) / 2.0, (object_point.z + 1.0) / 2.0)
+      }
+    } else if object_point.z > 0.0 {
+      ((1.0 - object_point.x) / 2.0, (object_point.y + 1.0) / 2.0)
+    } else {
+      ((object_point.x + 1.0) / 2.0, (object_point.y + 1.0) / 2.0)
+    }
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    let x_abs = object_point.x.abs();
+    let y_abs = object_point.y.abs();
+    let z_abs = object_point.z.abs();
+
+    // Follows texture coordinates of each face
+    let (tangent, bitangent) = if x_abs >= y_abs && x_abs >= z_abs {
+      if object_point.x > 0.0 {
+        (Vector::new(0.0, 0.0, -1.0), Vector::y_axis())
+      } else {
+        (Vector::z_axis(), Vector::y_axis())
+      }
+    } else if y_abs >= x_abs && y_abs >= z_abs {
+      if object_point.y > 0.0 {
+        (Vector::x_axis(), Vector::new(0.0, 0.0, -1.0))
+      } else {
+        (Vector::x_axis(), Vector::z_axis())
+      }
+    } else if object_point.z > 0.0 {
+      (Vector::new(-1.0, 0.0, 0.0), Vector::y_axis())
+    } else {
+      (Vector::x_axis(), Vector::y_axis())
+    };
+
+    (self.transform.mult_vector(&tangent), self.transform.mult_vector(&bitangent))
+  }
+
   fn interpolates_normals(&self) -> bool {
     false
   }
 
+  fn surface_area(&self) -> Option<f64> {
+    Some(24.0)
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    // First part of sample picks a face, and what remains of it places point across face
+    let face_sample = sample.0 * 6.0;
+    let face = (face_sample.floor() as usize).min(5);
+
+    let across = 2.0 * (face_sample - face as f64) - 1.0;
+    let along = 2.0 * sample.1 - 1.0;
+
+    let (point, normal) = match face {
+      0 => (Point::new(1.0, across, along), Vector::new(1.0, 0.0, 0.0)),
+      1 => (Point::new(-1.0, across, along), Vector::new(-1.0, 0.0, 0.0)),
+      2 => (Point::new(across, 1.0, along), Vector::new(0.0, 1.0, 0.0)),
+      3 => (Point::new(across, -1.0, along), Vector::new(0.0, -1.0, 0.0)),
+      4 => (Point::new(across, along, 1.0), Vector::new(0.0, 0.0, 1.0)),
+      _ => (Point::new(across, along, -1.0), Vector::new(0.0, 0.0, -1.0))
+    };
+
+    Some(SurfaceSample::new(point, normal, 1.0 / 24.0, self))
+  }
+
   fn get_base_type(&self) -> ShapeType {
     ShapeType::Cube
   }
+
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::cube(self.material.clone())]
+  }
 }
@@ -172,4 +172,45 @@ mod tests {
 
     assert_eq!(normal.get_quad(), (-1.0, 0.0, 0.0, 0.0));
   }
+
+  #[test]
+  fn texture_coordinates_map_each_cube_face() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(transform, material);
+
+    assert!(cube.texture_coordinates(&Point::new(-0.5, 0.5, -1.0), 0.0, 0.0) == (0.25, 0.75));
+    assert!(cube.texture_coordinates(&Point::new(0.5, -0.5, 1.0), 0.0, 0.0) == (0.25, 0.25));
+    assert!(cube.texture_coordinates(&Point::new(1.0, 0.5, -0.5), 0.0, 0.0) == (0.75, 0.75));
+    assert!(cube.texture_coordinates(&Point::new(-1.0, -0.5, 0.5), 0.0, 0.0) == (0.75, 0.25));
+    assert!(cube.texture_coordinates(&Point::new(-0.5, 1.0, -0.5), 0.0, 0.0) == (0.25, 0.75));
+    assert!(cube.texture_coordinates(&Point::new(-0.5, -1.0, 0.5), 0.0, 0.0) == (0.25, 0.75));
+  }
+
+  #[test]
+  fn surface_tangents_follow_texture_coordinates_on_each_cube_face() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let cube = Cube::new(transform, material);
+
+    let points = [
+      Point::new(1.0, 0.5, -0.5),
+      Point::new(-1.0, -0.5, 0.5),
+      Point::new(-0.5, 1.0, -0.5),
+      Point::new(-0.5, -1.0, 0.5),
+      Point::new(0.5, -0.5, 1.0),
+      Point::new(-0.5, 0.5, -1.0)
+    ];
+
+    for point in points.iter() {
+      let (tangent, bitangent) = cube.surface_tangents(point, 0.0, 0.0);
+      let (u, v) = cube.texture_coordinates(point, 0.0, 0.0);
+
+      let (u_along_tangent, v_along_tangent) = cube.texture_coordinates(&point.add_vector(&tangent.multiply(0.1)), 0.0, 0.0);
+      let (u_along_bitangent, v_along_bitangent) = cube.texture_coordinates(&point.add_vector(&bitangent.multiply(0.1)), 0.0, 0.0);
+
+      assert!((u_along_tangent - u - 0.05).abs() < 0.0001 && (v_along_tangent - v).abs() < 0.0001);
+      assert!((v_along_bitangent - v - 0.05).abs() < 0.0001 && (u_along_bitangent - u).abs() < 0.0001);
+    }
+  }
 }
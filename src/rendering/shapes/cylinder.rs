@@ -1,3 +1,5 @@
+use std::f64;
+
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
 
@@ -6,12 +8,17 @@ use crate::rendering::math::Matrix4x4;
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::shape::ShapeType;
 use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::azimuth_tangent;
 
 use crate::rendering::Material;
 
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
 pub struct Cylinder {
   pub id: u64,
   pub transform: Matrix4x4,
@@ -157,11 +164,67 @@ impl Shape for Cylinder {
     Vector::new(0.0, 0.0, 0.0)     
   }
 
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Cylindrical mapping, azimuth around y axis and height repeating every unit
+    let theta = object_point.x.atan2(object_point.z);
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    (1.0 - (raw_u + 0.5), object_point.y - object_point.y.floor())
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Caps have no height to follow, so their frame is settled against the normal
+    (self.transform.mult_vector(&azimuth_tangent(&object_point)), self.transform.mult_vector(&Vector::y_axis()))
+  }
+
   fn interpolates_normals(&self) -> bool {
     false
   }
 
+  fn surface_area(&self) -> Option<f64> {
+    if !self.minimum.is_finite() || !self.maximum.is_finite() {
+      return None;
+    }
+
+    let side_area = 2.0 * f64::consts::PI * (self.maximum - self.minimum);
+    let caps_area = if self.capped { 2.0 * f64::consts::PI } else { 0.0 };
+
+    Some(side_area + caps_area)
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let area = self.surface_area()?;
+    let side_area = 2.0 * f64::consts::PI * (self.maximum - self.minimum);
+
+    // First part of sample picks side or a cap by their areas, and what remains of it places point on that part
+    let area_sample = sample.0 * area;
+    let azimuth = 2.0 * f64::consts::PI * sample.1;
+
+    let (point, normal) = if area_sample < side_area {
+      let y = self.minimum + (area_sample / side_area) * (self.maximum - self.minimum);
+
+      (Point::new(azimuth.cos(), y, azimuth.sin()), Vector::new(azimuth.cos(), 0.0, azimuth.sin()))
+    } else {
+      let cap_sample = (area_sample - side_area) / f64::consts::PI;
+      let (y, normal) = if cap_sample < 1.0 { (self.minimum, Vector::new(0.0, -1.0, 0.0)) } else { (self.maximum, Vector::new(0.0, 1.0, 0.0)) };
+
+      let radius = (cap_sample - cap_sample.floor()).sqrt();
+
+      (Point::new(radius * azimuth.cos(), y, radius * azimuth.sin()), normal)
+    };
+
+    Some(SurfaceSample::new(point, normal, 1.0 / area, self))
+  }
+
   fn get_base_type(&self) -> ShapeType {
     ShapeType::Cylinder
   }
+
+  fn tessellate(&self, resolution: usize, extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::cylinder(self.minimum, self.maximum, self.capped, resolution, extent, self.material.clone())]
+  }
 }
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod tests {
+  use std::f64;
+
   use crate::rendering::math::tuple::Tuple;
   use crate::rendering::math::Point;
   use crate::rendering::math::Vector;
@@ -220,4 +222,30 @@ mod tests {
 
     assert_eq!(normal.get_quad(), (0.0, 1.0, 0.0, 0.0));
   }
+
+  #[test]
+  fn only_finite_cylinders_have_sampled_surfaces() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let infinite = Cylinder::new(Matrix4x4::identity(), false, -f64::INFINITY, f64::INFINITY, material.clone());
+    let capped = Cylinder::new(Matrix4x4::identity(), true, 1.0, 2.0, material);
+
+    assert!(infinite.surface_area().is_none());
+    assert!(infinite.sample_surface((0.5, 0.5)).is_none());
+
+    // Side of height one and two caps of radius one
+    assert!((capped.surface_area().unwrap() - 4.0 * f64::consts::PI).abs() < 0.0001);
+
+    let side = capped.sample_surface((0.25, 0.0)).unwrap();
+    assert!(side.point == Point::new(1.0, 1.5, 0.0));
+    assert!(side.normal == Vector::new(1.0, 0.0, 0.0));
+
+    let bottom = capped.sample_surface((0.625, 0.0)).unwrap();
+    assert!((bottom.point.x - 0.5_f64.sqrt()).abs() < 0.0001);
+    assert!(bottom.point.y == 1.0);
+    assert!(bottom.normal == Vector::new(0.0, -1.0, 0.0));
+
+    let top = capped.sample_surface((0.875, 0.0)).unwrap();
+    assert!(top.point.y == 2.0);
+    assert!(top.normal == Vector::new(0.0, 1.0, 0.0));
+  }
 }
@@ -0,0 +1,147 @@
+use std::f64;
+
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Bounds;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
+// Circle in xz plane centered on origin, facing up like plane
+pub struct Disk {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub radius: f64,
+  pub material: Material
+}
+
+impl Disk {
+  pub fn new(transform: Matrix4x4, radius: f64, material: Material) -> Disk {
+    let tmp_inverse = transform.inverse();
+
+    Disk {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      radius,
+      material
+    }
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    Bounds::new(Point::new(-self.radius, 0.0, -self.radius), Point::new(self.radius, 0.0, self.radius))
+  }
+}
+
+impl Shape for Disk {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    // Ray parallel to disk
+    if transformed_ray.direction.y.abs() < 0.0001 {
+      return intersections;
+    }
+
+    let t = -transformed_ray.origin.y / transformed_ray.direction.y;
+    let point = transformed_ray.position(t);
+
+    if point.x * point.x + point.z * point.z <= self.radius * self.radius {
+      intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, _point: &Point) -> Vector {
+    let object_normal = Vector::new(0.0, 1.0, 0.0);
+
+    self.transpose.mult_vector(&object_normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Planar mapping, square around disk covers texture once
+    ((object_point.x / self.radius + 1.0) / 2.0, (object_point.z / self.radius + 1.0) / 2.0)
+  }
+
+  fn surface_tangents(&self, _point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    (self.transform.mult_vector(&Vector::x_axis()), self.transform.mult_vector(&Vector::z_axis()))
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn surface_area(&self) -> Option<f64> {
+    Some(f64::consts::PI * self.radius * self.radius)
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    // Square root spreads points evenly, as rings grow with their radius
+    let radius = self.radius * sample.0.sqrt();
+    let azimuth = 2.0 * f64::consts::PI * sample.1;
+
+    let point = Point::new(radius * azimuth.cos(), 0.0, radius * azimuth.sin());
+
+    Some(SurfaceSample::new(point, Vector::new(0.0, 1.0, 0.0), 1.0 / self.surface_area()?, self))
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Disk
+  }
+
+  fn encloses_volume(&self) -> bool {
+    false
+  }
+
+  fn tessellate(&self, resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::annulus(0.0, self.radius, resolution, self.material.clone())]
+  }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Disk;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn disk_created_with_transform_radius_and_material() {
+    let transform = Matrix4x4::translate(5.0, -3.0, 2.0);
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(transform, 2.0, material);
+
+    assert!(disk.transform == Matrix4x4::translate(5.0, -3.0, 2.0));
+    assert!(disk.radius == 2.0);
+    assert!(disk.material.ambient == 0.1);
+  }
+
+  #[test]
+  fn normal_of_disk_is_constant() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 1.0, material);
+
+    assert!(disk.normal_at(&Point::new(0.0, 0.0, 0.0)) == Vector::new(0.0, 1.0, 0.0));
+    assert!(disk.normal_at(&Point::new(0.5, 0.0, -0.5)) == Vector::new(0.0, 1.0, 0.0));
+  }
+
+  #[test]
+  fn ray_intersects_disk_inside_radius() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 1.0, material);
+
+    let ray = Ray::new(&Point::new(0.5, 2.0, 0.5), &Vector::new(0.0, -1.0, 0.0));
+
+    let intersections = disk.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert_eq!(intersections[0].t, 2.0);
+    assert!(intersections[0].object.is_eq(&disk));
+  }
+
+  #[test]
+  fn ray_misses_disk_outside_radius_or_parallel() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 1.0, material);
+
+    let outside = Ray::new(&Point::new(0.8, 2.0, 0.8), &Vector::new(0.0, -1.0, 0.0));
+    let parallel = Ray::new(&Point::new(0.0, 1.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!(disk.intersections(&outside, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+    assert_eq!(disk.intersections(&parallel, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn ray_intersects_transformed_disk() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::translate(0.0, 0.0, 5.0).mult4x4(&Matrix4x4::rotate_x(std::f64::consts::PI / 2.0)), 1.0, material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = disk.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 5.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn disk_texture_coordinates_span_its_diameter() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 2.0, material);
+
+    let (center_u, center_v) = disk.texture_coordinates(&Point::new(0.0, 0.0, 0.0), 0.0, 0.0);
+    let (edge_u, edge_v) = disk.texture_coordinates(&Point::new(2.0, 0.0, -2.0), 0.0, 0.0);
+
+    assert!((center_u - 0.5).abs() < 0.0001 && (center_v - 0.5).abs() < 0.0001);
+    assert!((edge_u - 1.0).abs() < 0.0001 && edge_v.abs() < 0.0001);
+  }
+
+  #[test]
+  fn disk_bounds_cover_radius() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 3.0, material);
+
+    let bounds = disk.bounds();
+
+    assert!(bounds.minimum == Point::new(-3.0, 0.0, -3.0));
+    assert!(bounds.maximum == Point::new(3.0, 0.0, 3.0));
+  }
+
+  #[test]
+  fn disk_tessellates_to_upward_fan() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let disk = Disk::new(Matrix4x4::identity(), 1.0, material);
+
+    let tessellations = disk.tessellate(8, 10.0);
+
+    assert_eq!(tessellations.len(), 1);
+    assert_eq!(tessellations[0].faces.len(), 8);
+    assert!(tessellations[0].normals.iter().all(|normal| *normal == Vector::new(0.0, 1.0, 0.0)));
+  }
+}
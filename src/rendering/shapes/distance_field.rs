@@ -0,0 +1,194 @@
+use std::f64;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::DistanceFunction;
+
+pub const DEFAULT_MAX_STEPS: usize = 256;
+pub const DEFAULT_SURFACE_EPSILON: f64 = 0.0001;
+pub const DEFAULT_MAX_DISTANCE: f64 = 1000.0;
+
+// Implicit surface where distance function is zero, found by sphere tracing
+pub struct DistanceField {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub function: DistanceFunction,
+  pub max_steps: usize,
+  pub epsilon: f64,
+  pub max_distance: f64,
+  pub material: Material
+}
+
+impl DistanceField {
+  pub fn new(transform: Matrix4x4, function: DistanceFunction, material: Material) -> DistanceField {
+    let tmp_inverse = transform.inverse();
+
+    DistanceField {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      function,
+      max_steps: DEFAULT_MAX_STEPS,
+      epsilon: DEFAULT_SURFACE_EPSILON,
+      max_distance: DEFAULT_MAX_DISTANCE,
+      material
+    }
+  }
+
+  // Steps taken along each ray, distance within which surface is hit, and object space distance after which rays stop
+  pub fn set_limits(&mut self, max_steps: usize, epsilon: f64, max_distance: f64) {
+    self.max_steps = max_steps;
+    self.epsilon = epsilon;
+    self.max_distance = max_distance;
+  }
+
+  // Distances along ray where it enters or leaves surface, ray must already be in object space
+  pub fn local_intersections(&self, ray: &Ray) -> Vec<f64> {
+    let mut hits = Vec::new();
+
+    // Marching is done along unit direction, so each step covers distance to surface exactly
+    let direction_length = ray.direction.magnitude();
+    if direction_length < f64::EPSILON {
+      return hits;
+    }
+
+    let direction = ray.direction.normalize();
+
+    let start_distance = self.function.distance(&ray.origin);
+    let mut inside = start_distance < 0.0;
+
+    // Rays leaving surface, like shadow rays, must not hit it where they start
+    let mut t = if start_distance.abs() < self.epsilon { 2.0 * self.epsilon } else { 0.0 };
+
+    let mut steps = 0;
+    while steps < self.max_steps && t <= self.max_distance {
+      steps += 1;
+
+      let distance = self.function.distance(&ray.origin.add_vector(&direction.multiply(t)));
+      let step = if inside { -distance } else { distance };
+
+      if step >= self.epsilon {
+        t += step;
+        continue;
+      }
+
+      // Near surface, walks through thin shell around it to find which side ray leaves on, so grazing rays are not hits
+      let surface_t = t;
+      let mut shell_distance = distance;
+
+      while shell_distance.abs() < self.epsilon && steps < self.max_steps {
+        steps += 1;
+        t += self.epsilon;
+
+        shell_distance = self.function.distance(&ray.origin.add_vector(&direction.multiply(t)));
+      }
+
+      if (shell_distance < 0.0) != inside {
+        hits.push(surface_t / direction_length);
+        inside = !inside;
+      }
+    }
+
+    hits
+  }
+
+  // Gradient of distance function by central differences, in object space
+  pub fn local_normal_at(&self, point: &Point) -> Vector {
+    let offset = self.epsilon;
+
+    let difference = |axis: Vector| {
+      let delta = axis.multiply(offset);
+
+      self.function.distance(&point.add_vector(&delta)) - self.function.distance(&point.subtract_vector(&delta))
+    };
+
+    Vector::new(difference(Vector::x_axis()), difference(Vector::y_axis()), difference(Vector::z_axis())).normalize()
+  }
+}
+
+impl Shape for DistanceField {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    for t in self.local_intersections(&transformed_ray) {
+      intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, point: &Point) -> Vector {
+    let object_point = self.inverse.mult_point(point);
+
+    let object_normal = self.local_normal_at(&object_point);
+
+    self.transpose.mult_vector(&object_normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Spherical mapping around origin, as surface has no parameterization of its own
+    let theta = object_point.x.atan2(object_point.z);
+    let radius = Vector::new(object_point.x, object_point.y, object_point.z).magnitude();
+    let phi = if radius > 0.0 { (object_point.y / radius).clamp(-1.0, 1.0).acos() } else { 0.0 };
+
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    (1.0 - (raw_u + 0.5), 1.0 - phi / f64::consts::PI)
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::DistanceField
+  }
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::DistanceField;
+  use crate::rendering::shapes::distance_field::DEFAULT_MAX_STEPS;
+
+  use crate::rendering::DistanceFunction;
+
+  use crate::rendering::Camera;
+  use crate::rendering::PointLight;
+  use crate::rendering::Container;
+  use crate::rendering::Scene;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn distance_field_created_with_default_limits() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut distance_field = DistanceField::new(Matrix4x4::translate(1.0, 2.0, 3.0), DistanceFunction::sphere(1.0), material);
+
+    assert!(distance_field.transform == Matrix4x4::translate(1.0, 2.0, 3.0));
+    assert_eq!(distance_field.max_steps, DEFAULT_MAX_STEPS);
+    assert!(distance_field.material.ambient == 0.1);
+
+    distance_field.set_limits(32, 0.01, 50.0);
+
+    assert_eq!(distance_field.max_steps, 32);
+    assert!(distance_field.epsilon == 0.01);
+    assert!(distance_field.max_distance == 50.0);
+  }
+
+  #[test]
+  fn ray_enters_and_leaves_distance_field_sphere() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let distance_field = DistanceField::new(Matrix4x4::identity(), DistanceFunction::sphere(1.0), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 4.0).abs() < 0.001);
+    assert!((intersections[1].t - 6.0).abs() < 0.001);
+  }
+
+  #[test]
+  fn ray_starting_inside_distance_field_only_leaves() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let distance_field = DistanceField::new(Matrix4x4::identity(), DistanceFunction::sphere(1.0), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 1.0).abs() < 0.001);
+  }
+
+  #[test]
+  fn ray_misses_distance_field() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let distance_field = DistanceField::new(Matrix4x4::identity(), DistanceFunction::sphere(1.0), material);
+
+    let ray = Ray::new(&Point::new(0.0, 2.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!(distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn distance_field_intersections_respect_transform_and_direction_length() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let distance_field = DistanceField::new(Matrix4x4::scale(2.0, 2.0, 2.0), DistanceFunction::sphere(1.0), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 2.0));
+    let intersections = distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 1.5).abs() < 0.001);
+    assert!((intersections[1].t - 3.5).abs() < 0.001);
+  }
+
+  #[test]
+  fn max_steps_limits_how_far_ray_travels() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut distance_field = DistanceField::new(Matrix4x4::identity(), DistanceFunction::sphere(1.0), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    distance_field.set_limits(1, 0.0001, 1000.0);
+
+    assert_eq!(distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+
+    distance_field.set_limits(64, 0.0001, 3.0);
+
+    assert_eq!(distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+
+    distance_field.set_limits(64, 0.0001, 1000.0);
+
+    assert_eq!(distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 2);
+  }
+
+  #[test]
+  fn grazing_ray_does_not_hit_distance_field() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut distance_field = DistanceField::new(Matrix4x4::identity(), DistanceFunction::sphere(1.0), material);
+
+    distance_field.set_limits(1000, 0.001, 1000.0);
+
+    // Passes within epsilon of sphere without entering it
+    let ray = Ray::new(&Point::new(0.0, 1.0001, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!(distance_field.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn normal_on_distance_field_from_central_differences() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let distance_field = DistanceField::new(Matrix4x4::translate(0.0, 1.0, 0.0), DistanceFunction::rounded_box(Vector::new(1.0, 1.0, 1.0), 0.2), material);
+
+    let normal = distance_field.normal_at(&Point::new(0.3, 2.0, -0.2));
+
+    assert!(normal.x.abs() < 0.0001);
+    assert!((normal.y - 1.0).abs() < 0.0001);
+    assert!(normal.z.abs() < 0.0001);
+
+    let value = 1.0 / 3.0_f64.sqrt();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere_field = DistanceField::new(Matrix4x4::identity(), DistanceFunction::sphere(1.0), material);
+
+    let normal = sphere_field.normal_at(&Point::new(value, value, value));
+
+    assert!((normal.x - value).abs() < 0.0001);
+    assert!((normal.y - value).abs() < 0.0001);
+    assert!((normal.z - value).abs() < 0.0001);
+  }
+
+  #[test]
+  fn distance_field_casts_shadows_in_scene() {
+    let camera = Camera::new(200, 100, f64::consts::PI / 2.0, Matrix4x4::identity());
+
+    let light = PointLight::new(Color::new(1.0, 1.0, 1.0, 1.0), Point::new(0.0, 10.0, 0.0));
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let function = DistanceFunction::capsule(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 0.5).twist(0.5);
+    let distance_field = DistanceField::new(Matrix4x4::translate(0.0, 5.0, 0.0), function, material);
+
+    let container = Container::new(Matrix4x4::identity(), vec![&distance_field as &dyn Shape]);
+
+    let scene = Scene::new(camera, vec![light], vec![container]);
+
+    assert!(scene.is_shadowed(&Point::new(0.0, 0.0, 0.0), &Point::new(0.0, 10.0, 0.0)));
+    assert!(!scene.is_shadowed(&Point::new(5.0, 0.0, 0.0), &Point::new(0.0, 10.0, 0.0)));
+  }
+}
@@ -0,0 +1,391 @@
+use std::f64;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Bounds;
+
+use crate::rendering::Canvas;
+
+use crate::rendering::Tessellation;
+
+// Grid of heights spread over unit square in xz plane, each cell split into two triangles along its diagonal
+pub struct Heightfield {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub columns: usize,
+  pub rows: usize,
+  pub heights: Vec<f64>,
+  pub normals: Vec<Vector>,
+  pub minimum: f64,
+  pub maximum: f64,
+  pub smooth: bool,
+  pub material: Material
+}
+
+impl Heightfield {
+  // Heights are listed row by row, with columns running along x and rows along z
+  pub fn new(transform: Matrix4x4, columns: usize, rows: usize, heights: Vec<f64>, smooth: bool, material: Material) -> Heightfield {
+    assert!(columns >= 2 && rows >= 2, "Heightfield needs at least two columns and two rows");
+    assert!(heights.len() == columns * rows, "Heightfield needs one height for every grid point");
+
+    let tmp_inverse = transform.inverse();
+
+    let minimum = heights.iter().cloned().fold(f64::INFINITY, f64::min);
+    let maximum = heights.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let mut heightfield = Heightfield {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      columns,
+      rows,
+      heights,
+      normals: Vec::new(),
+      minimum,
+      maximum,
+      smooth,
+      material
+    };
+
+    heightfield.normals = heightfield.vertex_normals();
+
+    heightfield
+  }
+
+  // Samples function at every grid point, given position across field from zero to one
+  pub fn from_function(transform: Matrix4x4, columns: usize, rows: usize, height_at: impl Fn(f64, f64) -> f64, smooth: bool, material: Material) -> Heightfield {
+    let mut heights = Vec::with_capacity(columns * rows);
+
+    for row in 0..rows {
+      for column in 0..columns {
+        heights.push(height_at(column as f64 / (columns - 1) as f64, row as f64 / (rows - 1) as f64));
+      }
+    }
+
+    Heightfield::new(transform, columns, rows, heights, smooth, material)
+  }
+
+  // One grid point per pixel, brightness from zero to one gives height, top of image lies along far edge
+  pub fn from_canvas(transform: Matrix4x4, canvas: &Canvas, smooth: bool, material: Material) -> Heightfield {
+    let columns = canvas.width as usize;
+    let rows = canvas.height as usize;
+
+    let mut heights = Vec::with_capacity(columns * rows);
+
+    for row in 0..rows {
+      for column in 0..columns {
+        let color = canvas.pixel_color((rows - 1 - row) as u64, column as u64);
+
+        heights.push((color.r + color.g + color.b) / 3.0);
+      }
+    }
+
+    Heightfield::new(transform, columns, rows, heights, smooth, material)
+  }
+
+  pub fn load_image(file_name: &str, transform: Matrix4x4, smooth: bool, material: Material) -> std::io::Result<Heightfield> {
+    let canvas = Canvas::load_image(file_name)?;
+
+    Ok(Heightfield::from_canvas(transform, &canvas, smooth, material))
+  }
+
+  pub fn height(&self, column: usize, row: usize) -> f64 {
+    self.heights[row * self.columns + column]
+  }
+
+  pub fn grid_point(&self, column: usize, row: usize) -> Point {
+    Point::new(column as f64 / (self.columns - 1) as f64, self.height(column, row), row as f64 / (self.rows - 1) as f64)
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    Bounds::new(Point::new(0.0, self.minimum, 0.0), Point::new(1.0, self.maximum, 1.0))
+  }
+
+  // Normal at each grid point from slope between its neighbours
+  fn vertex_normals(&self) -> Vec<Vector> {
+    let mut normals = Vec::with_capacity(self.heights.len());
+
+    let spacing_x = 1.0 / (self.columns - 1) as f64;
+    let spacing_z = 1.0 / (self.rows - 1) as f64;
+
+    for row in 0..self.rows {
+      for column in 0..self.columns {
+        let (left, right) = (column.saturating_sub(1), (column + 1).min(self.columns - 1));
+        let (near, far) = (row.saturating_sub(1), (row + 1).min(self.rows - 1));
+
+        let slope_x = (self.height(right, row) - self.height(left, row)) / ((right - left) as f64 * spacing_x);
+        let slope_z = (self.height(column, far) - self.height(column, near)) / ((far - near) as f64 * spacing_z);
+
+        normals.push(Vector::new(-slope_x, 1.0, -slope_z).normalize());
+      }
+    }
+
+    normals
+  }
+
+  // Cell holding point, and position within it from zero to one along x and z
+  fn cell_at(&self, point: &Point) -> (usize, usize, f64, f64) {
+    let grid_x = (point.x * (self.columns - 1) as f64).clamp(0.0, (self.columns - 1) as f64);
+    let grid_z = (point.z * (self.rows - 1) as f64).clamp(0.0, (self.rows - 1) as f64);
+
+    let column = (grid_x.floor() as usize).min(self.columns - 2);
+    let row = (grid_z.floor() as usize).min(self.rows - 2);
+
+    (column, row, grid_x - column as f64, grid_z - row as f64)
+  }
+
+  // Corners of triangle holding a position within cell, with weight of each corner at that position
+  fn triangle_weights(column: usize, row: usize, cell_x: f64, cell_z: f64) -> [((usize, usize), f64); 3] {
+    if cell_x >= cell_z {
+      [((column, row), 1.0 - cell_x), ((column + 1, row), cell_x - cell_z), ((column + 1, row + 1), cell_z)]
+    } else {
+      [((column, row), 1.0 - cell_z), ((column + 1, row + 1), cell_x), ((column, row + 1), cell_z - cell_x)]
+    }
+  }
+
+  // Distances where ray crosses the two triangles of a cell
+  pub fn cell_intersections(&self, ray: &Ray, column: usize, row: usize, hits: &mut Vec<f64>) {
+    let corner = self.grid_point(column, row);
+    let across = self.grid_point(column + 1, row + 1);
+
+    let triangles = [
+      (self.grid_point(column + 1, row), true),
+      (self.grid_point(column, row + 1), false)
+    ];
+
+    let mut cell_hits = Vec::new();
+
+    for (third, lower) in triangles.iter() {
+      let normal = across.subtract_point(&corner).cross(&third.subtract_point(&corner));
+      let denominator = ray.direction.dot(&normal);
+
+      if denominator.abs() < f64::EPSILON {
+        continue;
+      }
+
+      let t = corner.subtract_point(&ray.origin).dot(&normal) / denominator;
+      let point = ray.position(t);
+
+      let cell_x = point.x * (self.columns - 1) as f64 - column as f64;
+      let cell_z = point.z * (self.rows - 1) as f64 - row as f64;
+
+      // Point must lie in this cell, on side of diagonal belonging to triangle
+      let in_cell = (-0.000001..=1.000001).contains(&cell_x) && (-0.000001..=1.000001).contains(&cell_z);
+      let in_triangle = if *lower { cell_x >= cell_z } else { cell_x <= cell_z };
+
+      if in_cell && in_triangle {
+        cell_hits.push(t);
+      }
+    }
+
+    cell_hits.sort_by(|t_a, t_b| t_a.total_cmp(t_b));
+
+    for t in cell_hits {
+      // Rays through shared edges would otherwise hit both triangles touching them
+      if hits.last().is_none_or(|last: &f64| (t - last).abs() > 0.000001) {
+        hits.push(t);
+      }
+    }
+  }
+
+  // Walks cells under ray in order, testing only those it passes over, ray must already be in object space
+  pub fn local_intersections(&self, ray: &Ray) -> Vec<f64> {
+    let mut hits = Vec::new();
+
+    let (t_enter, t_exit) = match self.bounds().intersection_range(ray) {
+      Some(range) => range,
+      None => return hits
+    };
+
+    let cells_x = (self.columns - 1) as f64;
+    let cells_z = (self.rows - 1) as f64;
+
+    let entry = ray.position(t_enter);
+    let (mut column, mut row, _, _) = self.cell_at(&entry);
+
+    // Distance along ray between crossings of cell walls in each direction, and to first crossing
+    let step_column: i64 = if ray.direction.x > 0.0 { 1 } else { -1 };
+    let step_row: i64 = if ray.direction.z > 0.0 { 1 } else { -1 };
+
+    let t_delta_x = if ray.direction.x.abs() < f64::EPSILON { f64::INFINITY } else { 1.0 / (cells_x * ray.direction.x.abs()) };
+    let t_delta_z = if ray.direction.z.abs() < f64::EPSILON { f64::INFINITY } else { 1.0 / (cells_z * ray.direction.z.abs()) };
+
+    let next_wall_x = if step_column > 0 { (column + 1) as f64 / cells_x } else { column as f64 / cells_x };
+    let next_wall_z = if step_row > 0 { (row + 1) as f64 / cells_z } else { row as f64 / cells_z };
+
+    let mut t_max_x = if t_delta_x.is_infinite() { f64::INFINITY } else { (next_wall_x - ray.origin.x) / ray.direction.x };
+    let mut t_max_z = if t_delta_z.is_infinite() { f64::INFINITY } else { (next_wall_z - ray.origin.z) / ray.direction.z };
+
+    loop {
+      self.cell_intersections(ray, column, row, &mut hits);
+
+      if t_max_x.min(t_max_z) > t_exit {
+        break;
+      }
+
+      if t_max_x < t_max_z {
+        let next_column = column as i64 + step_column;
+        if next_column < 0 || next_column >= cells_x as i64 {
+          break;
+        }
+
+        column = next_column as usize;
+        t_max_x += t_delta_x;
+      } else {
+        let next_row = row as i64 + step_row;
+        if next_row < 0 || next_row >= cells_z as i64 {
+          break;
+        }
+
+        row = next_row as usize;
+        t_max_z += t_delta_z;
+      }
+    }
+
+    hits
+  }
+
+  // Normal of surface in object space, interpolated between grid points when smooth
+  pub fn local_normal_at(&self, point: &Point) -> Vector {
+    let (column, row, cell_x, cell_z) = self.cell_at(point);
+    let weights = Heightfield::triangle_weights(column, row, cell_x, cell_z);
+
+    if self.smooth {
+      let mut normal = Vector::new(0.0, 0.0, 0.0);
+
+      for ((corner_column, corner_row), weight) in weights.iter() {
+        normal = normal.add_vector(&self.normals[corner_row * self.columns + corner_column].multiply(*weight));
+      }
+
+      return normal.normalize();
+    }
+
+    let [((column_1, row_1), _), ((column_2, row_2), _), ((column_3, row_3), _)] = weights;
+
+    let point_1 = self.grid_point(column_1, row_1);
+    let edge_1 = self.grid_point(column_2, row_2).subtract_point(&point_1);
+    let edge_2 = self.grid_point(column_3, row_3).subtract_point(&point_1);
+
+    // Corners are ordered so this faces up
+    let normal = edge_2.cross(&edge_1).normalize();
+
+    if normal.y < 0.0 { normal.multiply(-1.0) } else { normal }
+  }
+}
+
+impl Shape for Heightfield {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    for t in self.local_intersections(&transformed_ray) {
+      intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, point: &Point) -> Vector {
+    let object_point = self.inverse.mult_point(point);
+
+    self.transpose.mult_vector(&self.local_normal_at(&object_point)).normalize()
+  }
+
+  fn normal_at_with_uv(&self, point: &Point, _u: f64, _v: f64) -> Vector {
+    self.normal_at(point)
+  }
+
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Texture covers whole field once
+    (object_point.x.clamp(0.0, 1.0), object_point.z.clamp(0.0, 1.0))
+  }
+
+  fn surface_tangents(&self, _point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    // Texture lies flat over field, so frame is settled against slope of normal
+    (self.transform.mult_vector(&Vector::x_axis()), self.transform.mult_vector(&Vector::z_axis()))
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    self.smooth
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Heightfield
+  }
+
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    let mut tessellation = Tessellation::new(self.material.clone());
+
+    for row in 0..(self.rows - 1) {
+      for column in 0..(self.columns - 1) {
+        // Center of each triangle in cell, used to find its flat normal
+        let triangles = [
+          ([(column, row), (column + 1, row), (column + 1, row + 1)], (0.75, 0.25)),
+          ([(column, row), (column + 1, row + 1), (column, row + 1)], (0.25, 0.75))
+        ];
+
+        for (corners, (center_x, center_z)) in triangles.iter() {
+          let center = Point::new(
+            (column as f64 + center_x) / (self.columns - 1) as f64,
+            0.0,
+            (row as f64 + center_z) / (self.rows - 1) as f64
+          );
+          let flat_normal = self.local_normal_at(&center);
+
+          let mut face = [0; 3];
+          for (index, (corner_column, corner_row)) in corners.iter().enumerate() {
+            let normal = if self.smooth { self.normals[corner_row * self.columns + corner_column] } else { flat_normal };
+
+            face[index] = tessellation.add_vertex(self.grid_point(*corner_column, *corner_row), normal);
+          }
+
+          tessellation.add_oriented_face(face);
+        }
+      }
+    }
+
+    vec![tessellation]
+  }
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::fs;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::math::noise;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Heightfield;
+
+  use crate::rendering::Canvas;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn heightfield_created_from_grid_of_heights() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::new(Matrix4x4::scale(10.0, 2.0, 10.0), 3, 2, vec![0.0, 0.5, 1.0, 0.25, 0.75, 0.5], true, material);
+
+    assert!(heightfield.transform == Matrix4x4::scale(10.0, 2.0, 10.0));
+    assert_eq!(heightfield.columns, 3);
+    assert_eq!(heightfield.rows, 2);
+    assert!(heightfield.height(1, 1) == 0.75);
+    assert!(heightfield.grid_point(2, 1) == Point::new(1.0, 0.5, 1.0));
+
+    let bounds = heightfield.bounds();
+
+    assert!(bounds.minimum == Point::new(0.0, 0.0, 0.0));
+    assert!(bounds.maximum == Point::new(1.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn ray_hits_flat_heightfield_once() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_function(Matrix4x4::identity(), 4, 4, |_, _| 0.5, false, material);
+
+    let ray = Ray::new(&Point::new(0.3, 5.0, 0.6), &Vector::new(0.0, -1.0, 0.0));
+    let intersections = heightfield.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 4.5).abs() < 0.0001);
+
+    let miss = Ray::new(&Point::new(1.5, 5.0, 0.6), &Vector::new(0.0, -1.0, 0.0));
+
+    assert_eq!(heightfield.intersections(&miss, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn ray_walks_cells_to_reach_slope() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_function(Matrix4x4::identity(), 9, 9, |u, _| u, false, material);
+
+    // Surface rises with x, crossing height of a half halfway along
+    let ray = Ray::new(&Point::new(-1.0, 0.5, 0.3), &Vector::new(1.0, 0.0, 0.0));
+    let intersections = heightfield.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 1.5).abs() < 0.0001);
+
+    // Same from other side, walking cells in negative direction
+    let ray = Ray::new(&Point::new(2.0, 0.5, 0.7), &Vector::new(-1.0, 0.0, 0.0));
+    let intersections = heightfield.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 1.5).abs() < 0.0001);
+  }
+
+  #[test]
+  fn grid_traversal_matches_testing_every_cell() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_function(Matrix4x4::identity(), 12, 9, |u, v| noise::fractal_noise(u * 4.0, v * 4.0, 3, 17), false, material);
+
+    let rays = [
+      Ray::new(&Point::new(-0.5, 0.9, -0.3), &Vector::new(1.0, -0.4, 0.8)),
+      Ray::new(&Point::new(1.4, 1.2, 0.2), &Vector::new(-1.0, -0.5, 0.3)),
+      Ray::new(&Point::new(0.37, 2.0, 0.61), &Vector::new(0.05, -1.0, -0.02)),
+      Ray::new(&Point::new(0.2, 0.5, 1.5), &Vector::new(0.3, 0.0, -1.0))
+    ];
+
+    for ray in rays.iter() {
+      let traversed = heightfield.local_intersections(ray);
+
+      let mut brute_force = Vec::new();
+      for row in 0..(heightfield.rows - 1) {
+        for column in 0..(heightfield.columns - 1) {
+          heightfield.cell_intersections(ray, column, row, &mut brute_force);
+        }
+      }
+      brute_force.sort_by(|t_a, t_b| t_a.total_cmp(t_b));
+      brute_force.dedup_by(|t_a, t_b| (*t_a - *t_b).abs() < 0.000001);
+
+      assert_eq!(traversed.len(), brute_force.len());
+      for (t_a, t_b) in traversed.iter().zip(brute_force.iter()) {
+        assert!((t_a - t_b).abs() < 0.000001);
+      }
+    }
+  }
+
+  #[test]
+  fn flat_normals_follow_triangles_of_slope() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_function(Matrix4x4::identity(), 3, 3, |u, _| u, false, material);
+
+    let normal = heightfield.normal_at(&Point::new(0.3, 0.3, 0.6));
+    let expected = Vector::new(-1.0, 1.0, 0.0).normalize();
+
+    assert!((normal.x - expected.x).abs() < 0.0001);
+    assert!((normal.y - expected.y).abs() < 0.0001);
+    assert!(normal.z.abs() < 0.0001);
+    assert!(!heightfield.interpolates_normals());
+  }
+
+  #[test]
+  fn smooth_normals_blend_between_grid_points() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+
+    // Ridge along middle column, flat normal changes sharply there but smooth normal points straight up
+    let heightfield = Heightfield::new(Matrix4x4::identity(), 3, 2, vec![0.0, 0.5, 0.0, 0.0, 0.5, 0.0], true, material);
+
+    assert!(heightfield.interpolates_normals());
+
+    let ridge = heightfield.normal_at_with_uv(&Point::new(0.5, 0.5, 0.5), 0.0, 0.0);
+
+    assert!(ridge.x.abs() < 0.0001);
+    assert!((ridge.y - 1.0).abs() < 0.0001);
+
+    let left = heightfield.normal_at_with_uv(&Point::new(0.25, 0.25, 0.5), 0.0, 0.0);
+    let right = heightfield.normal_at_with_uv(&Point::new(0.75, 0.25, 0.5), 0.0, 0.0);
+
+    assert!(left.x < 0.0 && right.x > 0.0);
+    assert!((left.x + right.x).abs() < 0.0001);
+  }
+
+  #[test]
+  fn heightfield_texture_coordinates_span_whole_field() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_function(Matrix4x4::scale(100.0, 10.0, 50.0), 5, 5, |_, _| 0.0, false, material);
+
+    let (u, v) = heightfield.texture_coordinates(&Point::new(25.0, 0.0, 40.0), 0.0, 0.0);
+
+    assert!((u - 0.25).abs() < 0.0001);
+    assert!((v - 0.8).abs() < 0.0001);
+  }
+
+  #[test]
+  fn heightfield_built_from_image_brightness() {
+    let canvas = Canvas::from_ppm("P3\n2 2\n255\n255 255 255  255 255 255\n0 0 0  255 0 0\n".as_bytes()).unwrap();
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_canvas(Matrix4x4::identity(), &canvas, true, material);
+
+    // Bottom of image lies along near edge
+    assert!(heightfield.height(0, 0) == 0.0);
+    assert!((heightfield.height(1, 0) - 1.0 / 3.0).abs() < 0.0001);
+    assert!(heightfield.height(0, 1) == 1.0);
+    assert!(heightfield.height(1, 1) == 1.0);
+  }
+
+  #[test]
+  fn heightfield_loaded_from_image_file() {
+    let directory = env::temp_dir().join("jb_tracer_heightfield_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    let file_name = directory.join("terrain.ppm");
+    fs::write(&file_name, "P3\n3 2\n255\n0 0 0  128 128 128  255 255 255\n0 0 0  0 0 0  0 0 0\n").unwrap();
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::load_image(&file_name.to_string_lossy(), Matrix4x4::identity(), false, material).unwrap();
+
+    assert_eq!(heightfield.columns, 3);
+    assert_eq!(heightfield.rows, 2);
+    assert!(heightfield.height(2, 1) == 1.0);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+
+    assert!(Heightfield::load_image(&directory.join("missing.ppm").to_string_lossy(), Matrix4x4::identity(), false, material).is_err());
+  }
+
+  #[test]
+  fn heightfield_tessellates_two_triangles_per_cell() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let heightfield = Heightfield::from_function(Matrix4x4::identity(), 4, 3, |u, v| u * v, true, material);
+
+    let tessellations = heightfield.tessellate(16, 10.0);
+    let tessellation = &tessellations[0];
+
+    assert_eq!(tessellation.faces.len(), 12);
+
+    // Faces wind counter clockwise seen from above
+    for face in &tessellation.faces {
+      let edge_1 = tessellation.vertices[face[1]].subtract_point(&tessellation.vertices[face[0]]);
+      let edge_2 = tessellation.vertices[face[2]].subtract_point(&tessellation.vertices[face[0]]);
+
+      assert!(edge_1.cross(&edge_2).dot(&Vector::new(0.0, 1.0, 0.0)) > 0.0);
+    }
+  }
+}
@@ -0,0 +1,544 @@
+use std::rc::Rc;
+
+use std::collections::HashMap;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::triangle_tangents;
+use crate::rendering::shapes::shape::perpendicular_tangents;
+use crate::rendering::shapes::shape::triangle_sample_weights;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
+use crate::rendering::Bounds;
+use crate::rendering::bounds::axis_value;
+
+// Faces per BVH leaf, small leaves trade memory for fewer triangle tests
+const MAX_LEAF_FACES: usize = 4;
+
+// Scanned meshes may have very small faces, so only near parallel rays are treated as misses
+const PARALLEL_EPSILON: f64 = 1e-12;
+
+// Indices of a single triangle, used when building a mesh
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct MeshIndices {
+  pub vertices: [usize; 3],
+  pub normals: Option<[usize; 3]>,
+  pub texture_vertices: Option<[usize; 3]>,
+  pub material: usize
+}
+
+impl MeshIndices {
+  pub fn new(vertices: [usize; 3]) -> MeshIndices {
+    MeshIndices { vertices, normals: None, texture_vertices: None, material: 0 }
+  }
+}
+
+// Buffers shared by mesh and all of its faces
+pub struct MeshData {
+  pub id: u64,
+  pub vertices: Vec<Point>,
+  pub normals: Vec<Vector>,
+  pub texture_vertices: Vec<(f64, f64)>,
+  pub materials: Vec<Material>,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4
+}
+
+// Lightweight triangle referencing shared mesh buffers, returned as object of mesh intersections
+pub struct MeshFace {
+  pub mesh: Rc<MeshData>,
+  pub vertices: [u32; 3],
+  pub normals: Option<[u32; 3]>,
+  pub texture_vertices: Option<[u32; 3]>,
+  pub material: u32
+}
+
+impl MeshFace {
+  pub fn points(&self) -> (Point, Point, Point) {
+    (
+      self.mesh.vertices[self.vertices[0] as usize],
+      self.mesh.vertices[self.vertices[1] as usize],
+      self.mesh.vertices[self.vertices[2] as usize]
+    )
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    let (point_1, point_2, point_3) = self.points();
+
+    Bounds::from_points(&[point_1, point_2, point_3])
+  }
+
+  pub fn area(&self) -> f64 {
+    let (point_1, point_2, point_3) = self.points();
+
+    point_2.subtract_point(&point_1).cross(&point_3.subtract_point(&point_1)).magnitude() / 2.0
+  }
+
+  // Ray must already be in mesh space, returns t, u and v of hit
+  pub fn local_intersection(&self, ray: &Ray) -> Option<(f64, f64, f64)> {
+    let (point_1, point_2, point_3) = self.points();
+
+    let edge_1 = point_2.subtract_point(&point_1);
+    let edge_2 = point_3.subtract_point(&point_1);
+
+    let direction_cross_e_2 = ray.direction.cross(&edge_2);
+
+    let determinant = edge_1.dot(&direction_cross_e_2);
+
+    // Misses if determinant is roughly zero
+    if determinant.abs() < PARALLEL_EPSILON {
+      return None;
+    }
+
+    let f = 1.0 / determinant;
+
+    let p_1_to_origin = ray.origin.subtract_point(&point_1);
+
+    let u = f * p_1_to_origin.dot(&direction_cross_e_2);
+
+    // Misses if u less than zero, or above one
+    if !(0.0..=1.0).contains(&u) {
+      return None;
+    }
+
+    let origin_cross_e_1 = p_1_to_origin.cross(&edge_1);
+
+    let v = f * ray.direction.dot(&origin_cross_e_1);
+
+    // Misses if v is less than zero, or v + u is greater than one
+    if v < 0.0 || (v + u) > 1.0 {
+      return None;
+    }
+
+    Some((f * edge_2.dot(&origin_cross_e_1), u, v))
+  }
+}
+
+impl Shape for MeshFace {
+  // Faces share id of mesh, so refraction and constructive geometry treat mesh as one object
+  fn get_id(&self) -> u64 {
+    self.mesh.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.mesh.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.mesh.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.mesh.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.mesh.materials[self.material as usize]
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.mesh.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.mesh.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    if let Some((t, u, v)) = self.local_intersection(&transformed_ray) {
+      intersections.push(Intersection::new_with_uv(t, self, world_to_container, normal_to_world, u, v));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, _point: &Point) -> Vector {
+    let (point_1, point_2, point_3) = self.points();
+
+    let edge_1 = point_2.subtract_point(&point_1);
+    let edge_2 = point_3.subtract_point(&point_1);
+
+    let normal = edge_2.cross(&edge_1).normalize();
+
+    self.mesh.transpose.mult_vector(&normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, point: &Point, u: f64, v: f64) -> Vector {
+    let normals = match self.normals {
+      Some(normals) => normals,
+      None => return self.normal_at(point)
+    };
+
+    // Interpolates normals
+    let i_1 = self.mesh.transpose.mult_vector(&self.mesh.normals[normals[1] as usize]).multiply(u);
+    let i_2 = self.mesh.transpose.mult_vector(&self.mesh.normals[normals[2] as usize]).multiply(v);
+    let i_3 = self.mesh.transpose.mult_vector(&self.mesh.normals[normals[0] as usize]).multiply(1.0 - u - v);
+
+    i_1.add_vector(&i_2).add_vector(&i_3).normalize()
+  }
+
+  fn texture_coordinates(&self, _point: &Point, u: f64, v: f64) -> (f64, f64) {
+    // Barycentric coordinates used when face has no texture coordinates
+    let texture_vertices = match self.texture_vertices {
+      Some(texture_vertices) => texture_vertices,
+      None => return (u, v)
+    };
+
+    let (u_1, v_1) = self.mesh.texture_vertices[texture_vertices[0] as usize];
+    let (u_2, v_2) = self.mesh.texture_vertices[texture_vertices[1] as usize];
+    let (u_3, v_3) = self.mesh.texture_vertices[texture_vertices[2] as usize];
+
+    (
+      u_2 * u + u_3 * v + u_1 * (1.0 - u - v),
+      v_2 * u + v_3 * v + v_1 * (1.0 - u - v)
+    )
+  }
+
+  fn surface_tangents(&self, point: &Point, u: f64, v: f64) -> (Vector, Vector) {
+    let (point_1, point_2, point_3) = self.points();
+
+    let edge_1 = point_2.subtract_point(&point_1);
+    let edge_2 = point_3.subtract_point(&point_1);
+
+    // Barycentric coordinates stand in for missing texture coordinates, as in texture_coordinates
+    let (uv_1, uv_2, uv_3) = match self.texture_vertices {
+      Some(texture_vertices) => (
+        self.mesh.texture_vertices[texture_vertices[0] as usize],
+        self.mesh.texture_vertices[texture_vertices[1] as usize],
+        self.mesh.texture_vertices[texture_vertices[2] as usize]
+      ),
+      None => ((0.0, 0.0), (1.0, 0.0), (0.0, 1.0))
+    };
+
+    match triangle_tangents(&edge_1, &edge_2, uv_1, uv_2, uv_3) {
+      Some((tangent, bitangent)) => (self.mesh.transform.mult_vector(&tangent), self.mesh.transform.mult_vector(&bitangent)),
+      None => perpendicular_tangents(&self.normal_at_with_uv(point, u, v))
+    }
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    self.normals.is_some()
+  }
+
+  fn surface_area(&self) -> Option<f64> {
+    Some(self.area())
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let (point_1, point_2, point_3) = self.points();
+
+    let edge_1 = point_2.subtract_point(&point_1);
+    let edge_2 = point_3.subtract_point(&point_1);
+
+    let (weight_2, weight_3) = triangle_sample_weights(sample);
+    let point = point_1.add_vector(&edge_1.multiply(weight_2)).add_vector(&edge_2.multiply(weight_3));
+    let normal = edge_2.cross(&edge_1).normalize();
+
+    Some(SurfaceSample::new(point, normal, 1.0 / self.area(), self))
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Mesh
+  }
+}
+
+// Node of flattened BVH, first child directly follows its parent
+pub struct BvhNode {
+  pub bounds: Bounds,
+  pub first_face: usize,
+  pub face_count: usize,
+  pub second_child: usize
+}
+
+impl BvhNode {
+  pub fn is_leaf(&self) -> bool {
+    self.face_count > 0
+  }
+}
+
+pub struct Mesh {
+  pub data: Rc<MeshData>,
+  pub faces: Vec<MeshFace>,
+  pub nodes: Vec<BvhNode>,
+  // Faces sampled when mesh is a light, with running total of their areas
+  pub light_faces: Vec<(usize, f64)>
+}
+
+impl Mesh {
+  pub fn new(vertices: Vec<Point>, normals: Vec<Vector>, texture_vertices: Vec<(f64, f64)>, triangles: Vec<MeshIndices>, transform: Matrix4x4, materials: Vec<Material>) -> Mesh {
+    let tmp_inverse = transform.inverse();
+
+    let data = Rc::new(MeshData {
+      id: generate_shape_id(),
+      vertices,
+      normals,
+      texture_vertices,
+      materials,
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose()
+    });
+
+    let mut faces = Vec::new();
+    for triangle in triangles {
+      faces.push(MeshFace {
+        mesh: Rc::clone(&data),
+        vertices: to_indices(triangle.vertices),
+        normals: triangle.normals.map(to_indices),
+        texture_vertices: triangle.texture_vertices.map(to_indices),
+        material: triangle.material as u32
+      });
+    }
+
+    let (nodes, faces) = build_bvh(faces);
+    let light_faces = light_faces(&faces);
+
+    Mesh { data, faces, nodes, light_faces }
+  }
+
+  pub fn from_triangles(vertices: Vec<Point>, triangles: Vec<[usize; 3]>, transform: Matrix4x4, material: Material) -> Mesh {
+    let triangles = triangles.into_iter().map(MeshIndices::new).collect();
+
+    Mesh::new(vertices, Vec::new(), Vec::new(), triangles, transform, vec![material])
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    match self.nodes.first() {
+      Some(root) => root.bounds,
+      None => Bounds::empty()
+    }
+  }
+}
+
+impl Shape for Mesh {
+  fn get_id(&self) -> u64 {
+    self.data.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.data.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.data.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.data.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.data.materials[0]
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.data.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.data.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    if self.nodes.is_empty() {
+      return intersections;
+    }
+
+    let mut node_stack = vec![0];
+
+    while let Some(node_index) = node_stack.pop() {
+      let node = &self.nodes[node_index];
+
+      if !node.bounds.intersects(&transformed_ray) {
+        continue;
+      }
+
+      if !node.is_leaf() {
+        node_stack.push(node.second_child);
+        node_stack.push(node_index + 1);
+        continue;
+      }
+
+      // Hit faces become objects of intersections, so computations use their normals and materials
+      for face in &self.faces[node.first_face..(node.first_face + node.face_count)] {
+        if let Some((t, u, v)) = face.local_intersection(&transformed_ray) {
+          intersections.push(Intersection::new_with_uv(t, face, world_to_container, normal_to_world, u, v));
+        }
+      }
+    }
+
+    intersections.sort_by(|intersection_a, intersection_b| intersection_a.t.total_cmp(&intersection_b.t));
+
+    intersections
+  }
+
+  fn normal_at(&self, _point: &Point) -> Vector {
+    // Not defined, normals come from faces
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined, normals come from faces
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, _point: &Point, u: f64, v: f64) -> (f64, f64) {
+    (u, v)
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Mesh
+  }
+
+  fn emits_light(&self) -> bool {
+    self.data.materials.iter().any(|material| material.emits_light())
+  }
+
+  fn surface_area(&self) -> Option<f64> {
+    self.light_faces.last().map(|(_, total_area)| *total_area)
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let total_area = self.surface_area()?;
+
+    // First part of sample picks a face by its area, and what remains of it places point on that face
+    let area_sample = sample.0 * total_area;
+    let index = self.light_faces.partition_point(|(_, running_area)| *running_area <= area_sample).min(self.light_faces.len() - 1);
+
+    let (face_index, running_area) = self.light_faces[index];
+    let face = &self.faces[face_index];
+    let face_area = face.area();
+
+    let face_sample = ((area_sample - (running_area - face_area)) / face_area).clamp(0.0, 1.0);
+
+    face.sample_surface((face_sample, sample.1)).map(|surface_sample| SurfaceSample::new(surface_sample.point, surface_sample.normal, 1.0 / total_area, face))
+  }
+
+  // One tessellation per material, vertices are shared by faces that share normals
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    let mut tessellations: Vec<Tessellation> = self.data.materials.iter().map(|material| Tessellation::new(material.clone())).collect();
+    let mut vertex_indices: Vec<HashMap<(u32, u32), usize>> = vec![HashMap::new(); tessellations.len()];
+
+    for face in &self.faces {
+      let tessellation = &mut tessellations[face.material as usize];
+      let mut corners = [0; 3];
+
+      match face.normals {
+        Some(normals) => {
+          for corner in 0..3 {
+            let key = (face.vertices[corner], normals[corner]);
+            let vertex = self.data.vertices[key.0 as usize];
+            let normal = self.data.normals[key.1 as usize];
+
+            corners[corner] = *vertex_indices[face.material as usize].entry(key).or_insert_with(|| tessellation.add_vertex(vertex, normal));
+          }
+        },
+        None => {
+          // Flat faces need their own vertices to carry face normal
+          let (point_1, point_2, point_3) = face.points();
+          let normal = point_3.subtract_point(&point_1).cross(&point_2.subtract_point(&point_1)).normalize();
+
+          corners = [tessellation.add_vertex(point_1, normal), tessellation.add_vertex(point_2, normal), tessellation.add_vertex(point_3, normal)];
+        }
+      }
+
+      tessellation.add_face(corners);
+    }
+
+    tessellations.retain(|tessellation| !tessellation.faces.is_empty());
+
+    tessellations
+  }
+}
+
+// Only faces that emit light are sampled, unless none do and mesh emits through a container material
+fn light_faces(faces: &[MeshFace]) -> Vec<(usize, f64)> {
+  let emits_light = faces.iter().any(|face| face.get_material().emits_light());
+
+  let mut running_area = 0.0;
+  let mut light_faces = Vec::new();
+
+  for (index, face) in faces.iter().enumerate() {
+    let area = face.area();
+
+    if area > 0.0 && (!emits_light || face.get_material().emits_light()) {
+      running_area += area;
+      light_faces.push((index, running_area));
+    }
+  }
+
+  light_faces
+}
+
+fn to_indices(indices: [usize; 3]) -> [u32; 3] {
+  [indices[0] as u32, indices[1] as u32, indices[2] as u32]
+}
+
+// Builds BVH over faces, returning nodes and faces reordered so each leaf covers a contiguous range
+fn build_bvh(faces: Vec<MeshFace>) -> (Vec<BvhNode>, Vec<MeshFace>) {
+  let face_bounds: Vec<Bounds> = faces.iter().map(|face| face.bounds()).collect();
+  let centroids: Vec<Point> = face_bounds.iter().map(|bounds| bounds.centroid()).collect();
+
+  let mut order: Vec<usize> = (0..faces.len()).collect();
+  let mut nodes = Vec::new();
+
+  if !faces.is_empty() {
+    build_node(&mut nodes, &mut order, 0, &face_bounds, &centroids);
+  }
+
+  let mut indexed_faces: Vec<Option<MeshFace>> = faces.into_iter().map(Some).collect();
+  let ordered_faces = order.iter().map(|&index| indexed_faces[index].take().unwrap()).collect();
+
+  (nodes, ordered_faces)
+}
+
+fn build_node(nodes: &mut Vec<BvhNode>, order: &mut [usize], first_face: usize, face_bounds: &[Bounds], centroids: &[Point]) -> usize {
+  let mut bounds = Bounds::empty();
+  let mut centroid_bounds = Bounds::empty();
+
+  for &index in order.iter() {
+    bounds = bounds.merge(&face_bounds[index]);
+    centroid_bounds.add_point(&centroids[index]);
+  }
+
+  let node_index = nodes.len();
+  nodes.push(BvhNode { bounds, first_face, face_count: order.len(), second_child: 0 });
+
+  // Faces with identical centroids cannot be split
+  let axis = centroid_bounds.largest_axis();
+  if order.len() <= MAX_LEAF_FACES || centroid_bounds.extent(axis) <= 0.0 {
+    return node_index;
+  }
+
+  // Median split along longest axis of centroids
+  let middle = order.len() / 2;
+  order.select_nth_unstable_by(middle, |&a, &b| axis_value(&centroids[a], axis).total_cmp(&axis_value(&centroids[b], axis)));
+
+  let (first_order, second_order) = order.split_at_mut(middle);
+
+  build_node(nodes, first_order, first_face, face_bounds, centroids);
+  let second_child = build_node(nodes, second_order, first_face + middle, face_bounds, centroids);
+
+  nodes[node_index].face_count = 0;
+  nodes[node_index].second_child = second_child;
+
+  node_index
+}
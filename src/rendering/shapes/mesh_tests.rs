@@ -0,0 +1,288 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::MeshIndices;
+  use crate::rendering::shapes::Mesh;
+
+  use crate::rendering::Ray;
+  use crate::rendering::Computations;
+
+  use crate::rendering::Material;
+
+  fn grid_mesh(size: usize, transform: Matrix4x4) -> Mesh {
+    let mut vertices = Vec::new();
+    for y in 0..(size + 1) {
+      for x in 0..(size + 1) {
+        vertices.push(Point::new(x as f64, y as f64, 0.0));
+      }
+    }
+
+    let mut triangles = Vec::new();
+    for y in 0..size {
+      for x in 0..size {
+        let corner = y * (size + 1) + x;
+
+        triangles.push([corner, corner + 1, corner + size + 2]);
+        triangles.push([corner, corner + size + 2, corner + size + 1]);
+      }
+    }
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+
+    Mesh::from_triangles(vertices, triangles, transform, material)
+  }
+
+  #[test]
+  fn mesh_faces_share_buffers_and_id() {
+    let mesh = grid_mesh(2, Matrix4x4::translate(1.0, 0.0, 0.0));
+
+    assert_eq!(mesh.faces.len(), 8);
+    assert_eq!(mesh.data.vertices.len(), 9);
+
+    for face in &mesh.faces {
+      assert!(face.get_id() == mesh.get_id());
+      assert!(face.is_eq(&mesh as &dyn Shape));
+      assert!(*face.get_transform() == Matrix4x4::translate(1.0, 0.0, 0.0));
+    }
+  }
+
+  #[test]
+  fn mesh_builds_bvh_over_faces() {
+    let mesh = grid_mesh(8, Matrix4x4::identity());
+
+    assert!(mesh.nodes.len() > 1);
+    assert!(mesh.bounds().minimum == Point::new(0.0, 0.0, 0.0));
+    assert!(mesh.bounds().maximum == Point::new(8.0, 8.0, 0.0));
+
+    // Every face appears in exactly one leaf
+    let leaf_face_count: usize = mesh.nodes.iter().filter(|node| node.is_leaf()).map(|node| node.face_count).sum();
+    assert_eq!(leaf_face_count, 128);
+  }
+
+  #[test]
+  fn ray_intersects_face_of_mesh() {
+    let mesh = grid_mesh(8, Matrix4x4::identity());
+
+    let ray = Ray::new(&Point::new(2.25, 5.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert_eq!(intersections[0].t, 5.0);
+
+    let (point_1, point_2, point_3) = mesh.faces.iter().find(|face| face.local_intersection(&ray).is_some()).unwrap().points();
+    assert!(point_1 == Point::new(2.0, 5.0, 0.0));
+    assert!(point_2 == Point::new(3.0, 6.0, 0.0));
+    assert!(point_3 == Point::new(2.0, 6.0, 0.0));
+  }
+
+  #[test]
+  fn ray_misses_mesh() {
+    let mesh = grid_mesh(8, Matrix4x4::identity());
+
+    let ray = Ray::new(&Point::new(9.0, 5.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).is_empty());
+
+    let ray = Ray::new(&Point::new(2.25, 5.5, -5.0), &Vector::new(1.0, 0.0, 0.0));
+    assert!(mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).is_empty());
+  }
+
+  #[test]
+  fn ray_intersects_transformed_mesh() {
+    let mesh = grid_mesh(4, Matrix4x4::scale(2.0, 2.0, 2.0));
+
+    let ray = Ray::new(&Point::new(7.5, 6.9, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 1);
+
+    let ray = Ray::new(&Point::new(8.5, 6.9, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn mesh_intersections_are_sorted() {
+    let vertices = vec![
+      Point::new(-1.0, -1.0, 0.0), Point::new(1.0, -1.0, 0.0), Point::new(0.0, 1.0, 0.0),
+      Point::new(-1.0, -1.0, 3.0), Point::new(1.0, -1.0, 3.0), Point::new(0.0, 1.0, 3.0),
+      Point::new(-1.0, -1.0, -2.0), Point::new(1.0, -1.0, -2.0), Point::new(0.0, 1.0, -2.0)
+    ];
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = Mesh::from_triangles(vertices, vec![[0, 1, 2], [3, 4, 5], [6, 7, 8]], Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 3);
+    assert_eq!(intersections[0].t, 3.0);
+    assert_eq!(intersections[1].t, 5.0);
+    assert_eq!(intersections[2].t, 8.0);
+  }
+
+  #[test]
+  fn mesh_with_vertices_that_are_not_finite_builds_and_intersects() {
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+    for i in 0..8 {
+      let z = i as f64;
+      vertices.extend_from_slice(&[Point::new(-1.0, -1.0, z), Point::new(1.0, -1.0, z), Point::new(0.0, 1.0, z)]);
+      triangles.push([3 * i, 3 * i + 1, 3 * i + 2]);
+    }
+
+    // Corners at both infinities give a face whose centroid is not a number
+    vertices[4] = Point::new(1.0, -1.0, f64::INFINITY);
+    vertices[5] = Point::new(0.0, 1.0, f64::NEG_INFINITY);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = Mesh::from_triangles(vertices, triangles, Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert!(intersections.len() >= 7);
+    assert_eq!(intersections[0].t, 5.0);
+  }
+
+  #[test]
+  fn mesh_faces_interpolate_normals_and_texture_coordinates() {
+    let vertices = vec![Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)];
+    let normals = vec![Vector::new(0.0, 1.0, 0.0), Vector::new(-1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)];
+    let texture_vertices = vec![(0.5, 1.0), (0.0, 0.0), (1.0, 0.0)];
+
+    let mut triangle = MeshIndices::new([0, 1, 2]);
+    triangle.normals = Some([0, 1, 2]);
+    triangle.texture_vertices = Some([0, 1, 2]);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mesh = Mesh::new(vertices, normals, texture_vertices, vec![triangle], Matrix4x4::identity(), vec![material]);
+
+    let face = &mesh.faces[0];
+
+    assert!(face.interpolates_normals());
+
+    let normal = face.normal_at_with_uv(&Point::new(0.0, 0.0, 0.0), 0.45, 0.25);
+    assert!((normal.x - -0.5547).abs() < 0.0001);
+    assert!((normal.y - 0.83205).abs() < 0.0001);
+    assert!((normal.z - 0.0).abs() < 0.0001);
+
+    let (u, v) = face.texture_coordinates(&Point::new(0.0, 0.0, 0.0), 0.45, 0.25);
+    assert!((u - 0.4).abs() < 0.0001);
+    assert!((v - 0.3).abs() < 0.0001);
+  }
+
+  #[test]
+  fn mesh_faces_without_normals_are_flat() {
+    let mesh = grid_mesh(1, Matrix4x4::identity());
+
+    let face = &mesh.faces[0];
+
+    assert!(!face.interpolates_normals());
+    assert!(face.normal_at(&Point::new(0.5, 0.25, 0.0)).get_quad() == Vector::new(0.0, 0.0, -1.0).get_quad());
+    assert!(face.texture_coordinates(&Point::new(0.0, 0.0, 0.0), 0.3, 0.2) == (0.3, 0.2));
+  }
+
+  #[test]
+  fn mesh_faces_use_their_material_index() {
+    let vertices = vec![Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, -1.0, 0.0)];
+
+    let mut triangle = MeshIndices::new([0, 1, 2]);
+    triangle.material = 1;
+
+    let materials = vec![
+      Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()),
+      Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity())
+    ];
+    let mesh = Mesh::new(vertices, Vec::new(), Vec::new(), vec![triangle, MeshIndices::new([1, 3, 2])], Matrix4x4::identity(), materials);
+
+    let ray = Ray::new(&Point::new(0.0, 0.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = mesh.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    let computations = Computations::new(&intersections[0], &ray, &intersections);
+
+    assert!(computations.get_material().color_1 == Color::new(0.0, 1.0, 0.0, 1.0));
+    assert!(mesh.get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn tessellating_mesh_shares_vertices_with_same_normals() {
+    let mesh = grid_mesh(2, Matrix4x4::translate(1.0, 0.0, 0.0));
+    let tessellations = mesh.tessellate(16, 100.0);
+
+    // Flat faces each keep their own vertices, in space of mesh
+    assert_eq!(tessellations.len(), 1);
+    assert_eq!(tessellations[0].faces.len(), 8);
+    assert_eq!(tessellations[0].vertices.len(), 24);
+    assert!(tessellations[0].vertices[0] == Point::new(0.0, 0.0, 0.0));
+    assert!(tessellations[0].normals[0] == Vector::new(0.0, 0.0, -1.0));
+
+    let vertices = vec![Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, -1.0, 0.0)];
+
+    let mut triangle_1 = MeshIndices::new([0, 1, 2]);
+    triangle_1.normals = Some([0, 0, 0]);
+
+    let mut triangle_2 = MeshIndices::new([1, 3, 2]);
+    triangle_2.normals = Some([0, 0, 0]);
+    triangle_2.material = 1;
+
+    let mut triangle_3 = MeshIndices::new([1, 3, 0]);
+    triangle_3.normals = Some([0, 0, 0]);
+
+    let materials = vec![
+      Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 0.0, 0.0, 1.0), Matrix4x4::identity()),
+      Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(0.0, 1.0, 0.0, 1.0), Matrix4x4::identity())
+    ];
+    let mesh = Mesh::new(vertices, vec![Vector::new(0.0, 0.0, -1.0)], Vec::new(), vec![triangle_1, triangle_2, triangle_3], Matrix4x4::identity(), materials);
+
+    // One tessellation per material
+    let tessellations = mesh.tessellate(16, 100.0);
+
+    assert_eq!(tessellations.len(), 2);
+    assert_eq!(tessellations[0].faces, vec![[0, 1, 2], [1, 3, 0]]);
+    assert_eq!(tessellations[0].vertices.len(), 4);
+    assert!(tessellations[0].get_material().color_1 == Color::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(tessellations[1].vertices.len(), 3);
+    assert!(tessellations[1].get_material().color_1 == Color::new(0.0, 1.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn only_emitting_faces_of_mesh_are_sampled() {
+    let vertices = vec![
+      Point::new(0.0, 0.0, 0.0),
+      Point::new(1.0, 0.0, 0.0),
+      Point::new(0.0, 1.0, 0.0),
+      Point::new(0.0, 0.0, 5.0),
+      Point::new(4.0, 0.0, 5.0),
+      Point::new(0.0, 4.0, 5.0)
+    ];
+
+    let mut emitting_triangle = MeshIndices::new([3, 4, 5]);
+    emitting_triangle.material = 1;
+
+    let wall = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let light = Material::emissive(Color::new(2.0, 2.0, 2.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+
+    let mesh = Mesh::new(vertices, Vec::new(), Vec::new(), vec![MeshIndices::new([0, 1, 2]), emitting_triangle], Matrix4x4::identity(), vec![wall, light]);
+
+    assert!(mesh.emits_light());
+    assert_eq!(mesh.surface_area(), Some(8.0));
+
+    for sample in [(0.0, 0.0), (0.3, 0.6), (0.999, 0.2)] {
+      let surface_sample = mesh.sample_surface(sample).unwrap();
+
+      assert_eq!(surface_sample.point.z, 5.0);
+      assert_eq!(surface_sample.pdf, 1.0 / 8.0);
+      assert!(surface_sample.object.get_material().emits_light());
+    }
+
+    // Meshes without emitting faces are sampled over every face, for when a container material emits
+    let plain = grid_mesh(2, Matrix4x4::identity());
+    assert!(!plain.emits_light());
+    assert_eq!(plain.surface_area(), Some(4.0));
+  }
+}
@@ -21,6 +21,34 @@ pub mod cone;
 pub mod cone_tests;
 pub use self::cone::Cone;
 
+pub mod torus;
+pub mod torus_tests;
+pub use self::torus::Torus;
+
+pub mod disk;
+pub mod disk_tests;
+pub use self::disk::Disk;
+
+pub mod quad;
+pub mod quad_tests;
+pub use self::quad::Quad;
+
+pub mod annulus;
+pub mod annulus_tests;
+pub use self::annulus::Annulus;
+
+pub mod quadric;
+pub mod quadric_tests;
+pub use self::quadric::Quadric;
+
+pub mod distance_field;
+pub mod distance_field_tests;
+pub use self::distance_field::DistanceField;
+
+pub mod heightfield;
+pub mod heightfield_tests;
+pub use self::heightfield::Heightfield;
+
 pub mod triangle;
 pub mod triangle_tests;
 pub use self::triangle::Triangle;
@@ -28,3 +56,10 @@ pub use self::triangle::Triangle;
 pub mod smooth_triangle;
 pub mod smooth_triangle_tests;
 pub use self::smooth_triangle::SmoothTriangle;
+
+pub mod mesh;
+pub mod mesh_tests;
+pub use self::mesh::MeshIndices;
+pub use self::mesh::MeshData;
+pub use self::mesh::MeshFace;
+pub use self::mesh::Mesh;
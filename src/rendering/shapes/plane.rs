@@ -12,6 +12,8 @@ use crate::rendering::Material;
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
 pub struct Plane {
   pub id: u64,
   pub transform: Matrix4x4,
@@ -84,6 +86,17 @@ impl Shape for Plane {
     Vector::new(0.0, 0.0, 0.0)     
   }
 
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Planar mapping repeats every unit along x and z
+    (object_point.x - object_point.x.floor(), object_point.z - object_point.z.floor())
+  }
+
+  fn surface_tangents(&self, _point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    (self.transform.mult_vector(&Vector::x_axis()), self.transform.mult_vector(&Vector::z_axis()))
+  }
+
   fn interpolates_normals(&self) -> bool {
     false
   }
@@ -91,4 +104,8 @@ impl Shape for Plane {
   fn get_base_type(&self) -> ShapeType {
     ShapeType::Plane
   }
+
+  fn tessellate(&self, _resolution: usize, extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::plane(extent, self.material.clone())]
+  }
 }
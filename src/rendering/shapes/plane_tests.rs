@@ -99,4 +99,15 @@ mod tests {
     assert_eq!(intersections[0].t, 1.0);
     assert!(plane.is_eq(intersections[0].object));
   }
+
+  #[test]
+  fn planar_texture_coordinates_repeat_on_plane() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let plane = Plane::new(transform, material);
+
+    assert!(plane.texture_coordinates(&Point::new(0.25, 0.0, 0.5), 0.0, 0.0) == (0.25, 0.5));
+    assert!(plane.texture_coordinates(&Point::new(1.25, 0.0, -0.5), 0.0, 0.0) == (0.25, 0.5));
+    assert!(plane.texture_coordinates(&Point::new(-0.75, 0.0, 2.5), 0.0, 0.0) == (0.25, 0.5));
+  }
 }
@@ -0,0 +1,184 @@
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Bounds;
+
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
+// Parallelogram spanned by two edges from a corner
+pub struct Quad {
+  pub id: u64,
+  pub corner: Point,
+  pub edge_1: Vector,
+  pub edge_2: Vector,
+  pub normal: Vector,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub material: Material
+}
+
+impl Quad {
+  pub fn new(corner: Point, edge_1: Vector, edge_2: Vector, transform: Matrix4x4, material: Material) -> Quad {
+    let tmp_inverse = transform.inverse();
+
+    Quad {
+      id: generate_shape_id(),
+      corner,
+      edge_1,
+      edge_2,
+      // Same winding as triangle
+      normal: edge_2.cross(&edge_1).normalize(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      material
+    }
+  }
+
+  // Rectangle in xz plane centered on origin, facing up like plane
+  pub fn rectangle(width: f64, depth: f64, transform: Matrix4x4, material: Material) -> Quad {
+    Quad::new(Point::new(-width / 2.0, 0.0, -depth / 2.0), Vector::new(width, 0.0, 0.0), Vector::new(0.0, 0.0, depth), transform, material)
+  }
+
+  pub fn corners(&self) -> [Point; 4] {
+    [
+      self.corner,
+      self.edge_1.add_point(&self.corner),
+      self.edge_1.add_vector(&self.edge_2).add_point(&self.corner),
+      self.edge_2.add_point(&self.corner)
+    ]
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    Bounds::from_points(&self.corners())
+  }
+
+  // Position of point within quad along each edge, zero at corner and one at end of edge
+  pub fn edge_coordinates(&self, object_point: &Point) -> (f64, f64) {
+    let corner_to_point = object_point.subtract_point(&self.corner);
+
+    let cross = self.edge_1.cross(&self.edge_2);
+    let cross_squared = cross.dot(&cross);
+
+    (
+      corner_to_point.cross(&self.edge_2).dot(&cross) / cross_squared,
+      self.edge_1.cross(&corner_to_point).dot(&cross) / cross_squared
+    )
+  }
+}
+
+impl Shape for Quad {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    let denominator = transformed_ray.direction.dot(&self.normal);
+
+    // Ray parallel to quad
+    if denominator.abs() < 0.0001 {
+      return intersections;
+    }
+
+    let t = self.corner.subtract_point(&transformed_ray.origin).dot(&self.normal) / denominator;
+    let (u, v) = self.edge_coordinates(&transformed_ray.position(t));
+
+    if (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v) {
+      intersections.push(Intersection::new_with_uv(t, self, world_to_container, normal_to_world, u, v));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, _point: &Point) -> Vector {
+    self.transpose.mult_vector(&self.normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, _point: &Point, u: f64, v: f64) -> (f64, f64) {
+    // Texture covers quad once, following its edges
+    (u, v)
+  }
+
+  fn surface_tangents(&self, _point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    (self.transform.mult_vector(&self.edge_1), self.transform.mult_vector(&self.edge_2))
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn surface_area(&self) -> Option<f64> {
+    Some(self.edge_1.cross(&self.edge_2).magnitude())
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let point = self.corner.add_vector(&self.edge_1.multiply(sample.0)).add_vector(&self.edge_2.multiply(sample.1));
+
+    Some(SurfaceSample::new(point, self.normal, 1.0 / self.surface_area()?, self))
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Quad
+  }
+
+  fn encloses_volume(&self) -> bool {
+    false
+  }
+
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    let mut tessellation = Tessellation::new(self.material.clone());
+
+    let corners = self.corners();
+    for corner in corners.iter() {
+      tessellation.add_vertex(*corner, self.normal);
+    }
+
+    tessellation.add_oriented_face([0, 1, 2]);
+    tessellation.add_oriented_face([0, 2, 3]);
+
+    vec![tessellation]
+  }
+}
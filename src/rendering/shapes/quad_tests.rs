@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Quad;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn quad_created_with_corner_and_edges() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::new(Point::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), Matrix4x4::identity(), material);
+
+    assert!(quad.corner == Point::new(0.0, 0.0, 0.0));
+    assert!(quad.edge_1 == Vector::new(1.0, 0.0, 0.0));
+    assert!(quad.edge_2 == Vector::new(0.0, 1.0, 0.0));
+    assert!(quad.normal == Vector::new(0.0, 0.0, -1.0));
+  }
+
+  #[test]
+  fn rectangle_is_centered_and_faces_up() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::rectangle(4.0, 2.0, Matrix4x4::identity(), material);
+
+    assert!(quad.corner == Point::new(-2.0, 0.0, -1.0));
+    assert!(quad.normal_at(&Point::new(0.0, 0.0, 0.0)) == Vector::new(0.0, 1.0, 0.0));
+
+    let bounds = quad.bounds();
+
+    assert!(bounds.minimum == Point::new(-2.0, 0.0, -1.0));
+    assert!(bounds.maximum == Point::new(2.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn ray_intersects_rectangle_inside_edges() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::rectangle(4.0, 2.0, Matrix4x4::identity(), material);
+
+    let examples = [
+      (Point::new(0.0, 1.0, 0.0), 1),
+      (Point::new(1.9, 1.0, 0.9), 1),
+      (Point::new(2.1, 1.0, 0.0), 0),
+      (Point::new(0.0, 1.0, -1.1), 0)
+    ];
+
+    for (origin, count) in examples.iter() {
+      let ray = Ray::new(origin, &Vector::new(0.0, -1.0, 0.0));
+
+      assert_eq!(quad.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), *count);
+    }
+  }
+
+  #[test]
+  fn ray_parallel_to_quad_misses() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::rectangle(4.0, 2.0, Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(-5.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+
+    assert_eq!(quad.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn ray_intersects_slanted_parallelogram_with_uv() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::new(Point::new(0.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0), Vector::new(1.0, 1.0, 0.0), Matrix4x4::identity(), material);
+
+    // Inside slanted edge, but outside rectangle it leans out of
+    let ray = Ray::new(&Point::new(2.5, 0.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = quad.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 5.0).abs() < 0.0001);
+    assert!((intersections[0].u - 1.0).abs() < 0.0001);
+    assert!((intersections[0].v - 0.5).abs() < 0.0001);
+
+    let miss = Ray::new(&Point::new(0.2, 0.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!(quad.intersections(&miss, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn quad_texture_coordinates_follow_edges() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::rectangle(4.0, 2.0, Matrix4x4::identity(), material);
+
+    let ray = Ray::new(&Point::new(1.0, 1.0, 0.5), &Vector::new(0.0, -1.0, 0.0));
+    let intersections = quad.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    let (u, v) = quad.texture_coordinates(&Point::new(1.0, 0.0, 0.5), intersections[0].u, intersections[0].v);
+
+    assert!((u - 0.75).abs() < 0.0001);
+    assert!((v - 0.75).abs() < 0.0001);
+  }
+
+  #[test]
+  fn quad_tessellates_to_two_triangles() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quad = Quad::rectangle(4.0, 2.0, Matrix4x4::identity(), material);
+
+    let tessellations = quad.tessellate(8, 10.0);
+
+    assert_eq!(tessellations[0].vertices.len(), 4);
+    assert_eq!(tessellations[0].faces.len(), 2);
+    assert!(tessellations[0].normals.iter().all(|normal| *normal == Vector::new(0.0, 1.0, 0.0)));
+  }
+}
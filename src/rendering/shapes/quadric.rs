@@ -0,0 +1,258 @@
+use std::f64;
+
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::math::polynomial;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::azimuth_tangent;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+// Surface where a x^2 + b y^2 + c z^2 + d xy + e xz + f yz + g x + h y + i z + j is zero,
+// coefficients are stored in that order and points inside the surface give negative values
+pub struct Quadric {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub coefficients: [f64; 10],
+  pub capped: bool,
+  pub minimum: f64,
+  pub maximum: f64,
+  pub material: Material
+}
+
+impl Quadric {
+  pub fn new(transform: Matrix4x4, coefficients: [f64; 10], capped: bool, minimum: f64, maximum: f64, material: Material) -> Quadric {
+    let tmp_inverse = transform.inverse();
+
+    Quadric {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      coefficients,
+      capped,
+      minimum,
+      maximum,
+      material
+    }
+  }
+
+  pub fn ellipsoid(transform: Matrix4x4, radius_x: f64, radius_y: f64, radius_z: f64, material: Material) -> Quadric {
+    let coefficients = [
+      1.0 / (radius_x * radius_x), 1.0 / (radius_y * radius_y), 1.0 / (radius_z * radius_z),
+      0.0, 0.0, 0.0,
+      0.0, 0.0, 0.0,
+      -1.0
+    ];
+
+    Quadric::new(transform, coefficients, false, f64::NEG_INFINITY, f64::INFINITY, material)
+  }
+
+  // Opens upward from origin, with given radii at a height of one
+  pub fn paraboloid(transform: Matrix4x4, radius_x: f64, radius_z: f64, capped: bool, minimum: f64, maximum: f64, material: Material) -> Quadric {
+    let coefficients = [
+      1.0 / (radius_x * radius_x), 0.0, 1.0 / (radius_z * radius_z),
+      0.0, 0.0, 0.0,
+      0.0, -1.0, 0.0,
+      0.0
+    ];
+
+    Quadric::new(transform, coefficients, capped, minimum, maximum, material)
+  }
+
+  // Single tube around y axis, narrowest at origin where it has given radii
+  pub fn hyperboloid_one_sheet(transform: Matrix4x4, radius_x: f64, radius_z: f64, capped: bool, minimum: f64, maximum: f64, material: Material) -> Quadric {
+    let coefficients = [
+      1.0 / (radius_x * radius_x), -1.0, 1.0 / (radius_z * radius_z),
+      0.0, 0.0, 0.0,
+      0.0, 0.0, 0.0,
+      -1.0
+    ];
+
+    Quadric::new(transform, coefficients, capped, minimum, maximum, material)
+  }
+
+  // Two bowls opening away from origin along y axis, starting one unit above and below it
+  pub fn hyperboloid_two_sheets(transform: Matrix4x4, radius_x: f64, radius_z: f64, capped: bool, minimum: f64, maximum: f64, material: Material) -> Quadric {
+    let coefficients = [
+      1.0 / (radius_x * radius_x), -1.0, 1.0 / (radius_z * radius_z),
+      0.0, 0.0, 0.0,
+      0.0, 0.0, 0.0,
+      1.0
+    ];
+
+    Quadric::new(transform, coefficients, capped, minimum, maximum, material)
+  }
+
+  pub fn elliptic_cylinder(transform: Matrix4x4, radius_x: f64, radius_z: f64, capped: bool, minimum: f64, maximum: f64, material: Material) -> Quadric {
+    let coefficients = [
+      1.0 / (radius_x * radius_x), 0.0, 1.0 / (radius_z * radius_z),
+      0.0, 0.0, 0.0,
+      0.0, 0.0, 0.0,
+      -1.0
+    ];
+
+    Quadric::new(transform, coefficients, capped, minimum, maximum, material)
+  }
+
+  pub fn evaluate(&self, point: &Point) -> f64 {
+    let [a, b, c, d, e, f, g, h, i, j] = self.coefficients;
+    let (x, y, z) = (point.x, point.y, point.z);
+
+    a * x * x + b * y * y + c * z * z + d * x * y + e * x * z + f * y * z + g * x + h * y + i * z + j
+  }
+
+  // Gradient of quadric, pointing away from inside
+  pub fn gradient(&self, point: &Point) -> Vector {
+    let [a, b, c, d, e, f, g, h, i, _] = self.coefficients;
+    let (x, y, z) = (point.x, point.y, point.z);
+
+    Vector::new(
+      2.0 * a * x + d * y + e * z + g,
+      2.0 * b * y + d * x + f * z + h,
+      2.0 * c * z + e * x + f * y + i
+    )
+  }
+
+  // Distances along ray where it crosses surface between minimum and maximum, ray must already be in object space
+  pub fn local_intersections(&self, ray: &Ray) -> Vec<f64> {
+    let [a, b, c, d, e, f, g, h, i, _] = self.coefficients;
+    let (origin, direction) = (ray.origin, ray.direction);
+
+    let quadratic_term =
+      a * direction.x * direction.x + b * direction.y * direction.y + c * direction.z * direction.z +
+      d * direction.x * direction.y + e * direction.x * direction.z + f * direction.y * direction.z;
+
+    let linear_term =
+      2.0 * (a * origin.x * direction.x + b * origin.y * direction.y + c * origin.z * direction.z) +
+      d * (origin.x * direction.y + origin.y * direction.x) +
+      e * (origin.x * direction.z + origin.z * direction.x) +
+      f * (origin.y * direction.z + origin.z * direction.y) +
+      g * direction.x + h * direction.y + i * direction.z;
+
+    let mut hits: Vec<f64> = polynomial::solve_quadratic(quadratic_term, linear_term, self.evaluate(&origin))
+      .into_iter()
+      .filter(|t| {
+        let y = origin.y + t * direction.y;
+
+        self.minimum < y && y < self.maximum
+      })
+      .collect();
+
+    // Caps close surface where inside of quadric crosses clipping planes
+    if self.capped && direction.y.abs() > f64::EPSILON {
+      for cap in [self.minimum, self.maximum].iter() {
+        if cap.is_infinite() {
+          continue;
+        }
+
+        let t = (cap - origin.y) / direction.y;
+        if self.evaluate(&ray.position(t)) <= 0.0 {
+          hits.push(t);
+        }
+      }
+    }
+
+    hits.sort_by(|t_a, t_b| t_a.total_cmp(t_b));
+
+    hits
+  }
+}
+
+impl Shape for Quadric {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    for t in self.local_intersections(&transformed_ray) {
+      intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, point: &Point) -> Vector {
+    let object_point = self.inverse.mult_point(point);
+
+    let object_normal;
+
+    // Normal from top cap
+    if self.capped && object_point.y >= (self.maximum - 0.0001) {
+      object_normal = Vector::new(0.0, 1.0, 0.0);
+
+    // Normal from bottom cap
+    } else if self.capped && object_point.y <= (self.minimum + 0.0001) {
+      object_normal = Vector::new(0.0, -1.0, 0.0);
+
+    // Normal from quadric surface
+    } else {
+      object_normal = self.gradient(&object_point).normalize();
+    }
+
+    self.transpose.mult_vector(&object_normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Cylindrical mapping, azimuth around y axis and height repeating every unit
+    let theta = object_point.x.atan2(object_point.z);
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    (1.0 - (raw_u + 0.5), object_point.y - object_point.y.floor())
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    (self.transform.mult_vector(&azimuth_tangent(&object_point)), self.transform.mult_vector(&Vector::y_axis()))
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Quadric
+  }
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Quadric;
+  use crate::rendering::shapes::Sphere;
+
+  use crate::rendering::Ray;
+
+  use crate::rendering::Material;
+
+  #[test]
+  fn quadric_created_with_coefficients_and_bounds() {
+    let transform = Matrix4x4::translate(5.0, -3.0, 2.0);
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let coefficients = [1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0];
+    let quadric = Quadric::new(transform, coefficients, true, -1.0, 2.0, material);
+
+    assert!(quadric.transform == Matrix4x4::translate(5.0, -3.0, 2.0));
+    assert!(quadric.coefficients == coefficients);
+    assert!(quadric.capped);
+    assert!(quadric.minimum == -1.0);
+    assert!(quadric.maximum == 2.0);
+    assert!(quadric.material.ambient == 0.1);
+  }
+
+  #[test]
+  fn unit_ellipsoid_matches_sphere() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::ellipsoid(Matrix4x4::scale(2.0, 2.0, 2.0), 1.0, 1.0, 1.0, material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::scale(2.0, 2.0, 2.0), material);
+
+    let ray = Ray::new(&Point::new(0.3, -0.4, -5.0), &Vector::new(0.1, 0.2, 1.0).normalize());
+
+    let quadric_intersections = quadric.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+    let sphere_intersections = sphere.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(quadric_intersections.len(), 2);
+    assert!((quadric_intersections[0].t - sphere_intersections[0].t).abs() < 0.0001);
+    assert!((quadric_intersections[1].t - sphere_intersections[1].t).abs() < 0.0001);
+
+    let point = ray.position(quadric_intersections[0].t);
+    let quadric_normal = quadric.normal_at(&point);
+    let sphere_normal = sphere.normal_at(&point);
+
+    assert!((quadric_normal.x - sphere_normal.x).abs() < 0.0001);
+    assert!((quadric_normal.y - sphere_normal.y).abs() < 0.0001);
+    assert!((quadric_normal.z - sphere_normal.z).abs() < 0.0001);
+  }
+
+  #[test]
+  fn ray_intersects_ellipsoid_along_each_radius() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::ellipsoid(Matrix4x4::identity(), 3.0, 2.0, 1.0, material);
+
+    let examples = [
+      (Point::new(-10.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), 7.0, 13.0),
+      (Point::new(0.0, -10.0, 0.0), Vector::new(0.0, 1.0, 0.0), 8.0, 12.0),
+      (Point::new(0.0, 0.0, -10.0), Vector::new(0.0, 0.0, 1.0), 9.0, 11.0)
+    ];
+
+    for (origin, direction, t_0, t_1) in examples.iter() {
+      let ray = Ray::new(origin, direction);
+      let intersections = quadric.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+      assert_eq!(intersections.len(), 2);
+      assert!((intersections[0].t - t_0).abs() < 0.0001);
+      assert!((intersections[1].t - t_1).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn normal_on_ellipsoid_follows_gradient() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::ellipsoid(Matrix4x4::identity(), 2.0, 1.0, 1.0, material);
+
+    // Point on surface where x^2 / 4 + y^2 is one
+    let point = Point::new(2.0_f64.sqrt(), 0.5_f64.sqrt(), 0.0);
+    let normal = quadric.normal_at(&point);
+    let expected = Vector::new(2.0_f64.sqrt() / 2.0, 2.0 * 0.5_f64.sqrt(), 0.0).normalize();
+
+    assert!((normal.x - expected.x).abs() < 0.0001);
+    assert!((normal.y - expected.y).abs() < 0.0001);
+    assert!(normal.z.abs() < 0.0001);
+  }
+
+  #[test]
+  fn ray_along_paraboloid_axis_hits_once() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::paraboloid(Matrix4x4::identity(), 1.0, 1.0, false, f64::NEG_INFINITY, f64::INFINITY, material);
+
+    let ray = Ray::new(&Point::new(0.0, -5.0, 0.0), &Vector::new(0.0, 1.0, 0.0));
+    let intersections = quadric.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t - 5.0).abs() < 0.0001);
+    assert!(quadric.normal_at(&Point::new(0.0, 0.0, 0.0)) == Vector::new(0.0, -1.0, 0.0));
+
+    // Crossing paraboloid at height of one, where its radius is one
+    let ray = Ray::new(&Point::new(-5.0, 1.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let intersections = quadric.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 4.0).abs() < 0.0001);
+    assert!((intersections[1].t - 6.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn ray_intersects_hyperboloids() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let one_sheet = Quadric::hyperboloid_one_sheet(Matrix4x4::identity(), 1.0, 1.0, false, f64::NEG_INFINITY, f64::INFINITY, material);
+
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let two_sheets = Quadric::hyperboloid_two_sheets(Matrix4x4::identity(), 1.0, 1.0, false, f64::NEG_INFINITY, f64::INFINITY, material);
+
+    // Waist has radius one, widening to square root of two at a height of one
+    let waist = Ray::new(&Point::new(-5.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let raised = Ray::new(&Point::new(-5.0, 1.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+
+    let waist_intersections = one_sheet.intersections(&waist, Matrix4x4::identity(), Matrix4x4::identity());
+    let raised_intersections = one_sheet.intersections(&raised, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert!((waist_intersections[0].t - 4.0).abs() < 0.0001);
+    assert!((raised_intersections[0].t - (5.0 - 2.0_f64.sqrt())).abs() < 0.0001);
+
+    // Sheets start one unit above and below origin
+    let axis = Ray::new(&Point::new(0.0, -5.0, 0.0), &Vector::new(0.0, 1.0, 0.0));
+    let axis_intersections = two_sheets.intersections(&axis, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(axis_intersections.len(), 2);
+    assert!((axis_intersections[0].t - 4.0).abs() < 0.0001);
+    assert!((axis_intersections[1].t - 6.0).abs() < 0.0001);
+    assert_eq!(two_sheets.intersections(&waist, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn clipped_elliptic_cylinder_limits_height() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::elliptic_cylinder(Matrix4x4::identity(), 2.0, 1.0, false, 1.0, 2.0, material);
+
+    let examples = [
+      (Point::new(-5.0, 1.5, 0.0), Vector::new(1.0, 0.0, 0.0), 2),
+      (Point::new(-5.0, 3.0, 0.0), Vector::new(1.0, 0.0, 0.0), 0),
+      (Point::new(0.0, 1.5, -5.0), Vector::new(0.0, 0.0, 1.0), 2),
+      (Point::new(0.0, -5.0, 0.0), Vector::new(0.0, 1.0, 0.0), 0)
+    ];
+
+    for (origin, direction, count) in examples.iter() {
+      let ray = Ray::new(origin, direction);
+
+      assert_eq!(quadric.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).len(), *count);
+    }
+
+    let ray = Ray::new(&Point::new(-5.0, 1.5, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let intersections = quadric.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert!((intersections[0].t - 3.0).abs() < 0.0001);
+    assert!((intersections[1].t - 7.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn capped_quadric_is_closed_at_clipping_planes() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::paraboloid(Matrix4x4::identity(), 1.0, 1.0, true, f64::NEG_INFINITY, 4.0, material);
+
+    // Cap at height of four has radius two
+    let inside = Ray::new(&Point::new(1.5, 10.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+    let outside = Ray::new(&Point::new(2.5, 10.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+
+    let intersections = quadric.intersections(&inside, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t - 6.0).abs() < 0.0001);
+    assert!((intersections[1].t - (10.0 - 2.25)).abs() < 0.0001);
+    assert!(quadric.normal_at(&Point::new(1.5, 4.0, 0.0)) == Vector::new(0.0, 1.0, 0.0));
+
+    assert_eq!(quadric.intersections(&outside, Matrix4x4::identity(), Matrix4x4::identity()).len(), 0);
+  }
+
+  #[test]
+  fn quadric_texture_coordinates_wrap_around_y_axis() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let quadric = Quadric::elliptic_cylinder(Matrix4x4::identity(), 1.0, 1.0, false, f64::NEG_INFINITY, f64::INFINITY, material);
+
+    let (u, v) = quadric.texture_coordinates(&Point::new(1.0, 1.25, 0.0), 0.0, 0.0);
+
+    assert!((u - 0.25).abs() < 0.0001);
+    assert!((v - 0.25).abs() < 0.0001);
+  }
+}
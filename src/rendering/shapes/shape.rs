@@ -14,6 +14,10 @@ use crate::rendering::Intersection;
 
 use crate::rendering::Material;
 
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
 #[derive(PartialEq)]
 pub enum ShapeType {
   Sphere,
@@ -21,8 +25,17 @@ pub enum ShapeType {
   Cube,
   Cylinder,
   Cone,
+  Torus,
+  Disk,
+  Quad,
+  Annulus,
+  Quadric,
+  DistanceField,
+  Heightfield,
   Triangle,
   SmoothTriangle,
+  Mesh,
+  Instance,
   ConstructiveGeometry
 }
 
@@ -40,10 +53,58 @@ pub trait Shape {
   fn normal_at(&self, point: &Point) -> Vector;
   fn normal_at_with_uv(&self, point: &Point, u: f64, v: f64) -> Vector;
 
+  fn texture_coordinates(&self, point: &Point, u: f64, v: f64) -> (f64, f64);
+
   fn interpolates_normals(&self) -> bool;
 
+  // Directions of increasing texture u and v along surface, used to orient normal maps and bumps
+  fn surface_tangents(&self, point: &Point, u: f64, v: f64) -> (Vector, Vector) {
+    let normal = if self.interpolates_normals() {
+      self.normal_at_with_uv(point, u, v)
+    } else {
+      self.normal_at(point)
+    };
+
+    perpendicular_tangents(&normal)
+  }
+
   fn get_base_type(&self) -> ShapeType;
+
+  // Triangles approximating shape before its transform is applied, shapes without one are not exported
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    Vec::new()
+  }
   
+  // Shapes made of several materials emit light if any of them do
+  fn emits_light(&self) -> bool {
+    self.get_material().emits_light()
+  }
+
+  // Area of surface before transform is applied, none for shapes whose surface is unbounded or only known implicitly
+  fn surface_area(&self) -> Option<f64> {
+    None
+  }
+
+  // Point spread uniformly over surface before transform is applied, from a sample in the unit square
+  fn sample_surface(&self, _sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    None
+  }
+
+  // Flat shapes have no inside, so crossing them never enters or leaves a volume
+  fn encloses_volume(&self) -> bool {
+    true
+  }
+
+  // Groupings of shapes also include shapes reported by their intersections
+  fn includes(&self, object: &dyn Shape) -> bool {
+    self.is_eq(object)
+  }
+
+  // Whether a hit on object, whose container is reached from world by hit_world_to_container, is on this copy of shape in its own container
+  fn includes_hit(&self, object: &dyn Shape, hit_world_to_container: &Matrix4x4, world_to_container: &Matrix4x4) -> bool {
+    self.includes(object) && hit_world_to_container == world_to_container
+  }
+
   fn is_eq(&self, r_hand: &Shape) -> bool {
     self.get_base_type() == r_hand.get_base_type() && self.get_id() == r_hand.get_id()
   }
@@ -54,3 +115,41 @@ pub fn generate_shape_id() -> u64 {
   
   (Utc::now().timestamp_subsec_micros() as u64) + range.gen_range(0, 1000000)
 }
+
+// Any pair of directions perpendicular to normal, for surfaces without a parameterization to follow
+pub fn perpendicular_tangents(normal: &Vector) -> (Vector, Vector) {
+  let axis = if normal.x.abs() < 0.9 { Vector::x_axis() } else { Vector::y_axis() };
+
+  let bitangent = normal.cross(&axis).normalize();
+  let tangent = bitangent.cross(normal).normalize();
+
+  (tangent, bitangent)
+}
+
+// Tangents of triangle from texture coordinates of its corners, none if corners share coordinates
+pub fn triangle_tangents(edge_1: &Vector, edge_2: &Vector, uv_1: (f64, f64), uv_2: (f64, f64), uv_3: (f64, f64)) -> Option<(Vector, Vector)> {
+  let (delta_u_1, delta_v_1) = (uv_2.0 - uv_1.0, uv_2.1 - uv_1.1);
+  let (delta_u_2, delta_v_2) = (uv_3.0 - uv_1.0, uv_3.1 - uv_1.1);
+
+  let determinant = delta_u_1 * delta_v_2 - delta_u_2 * delta_v_1;
+  if determinant.abs() < 1e-12 {
+    return None;
+  }
+
+  let tangent = edge_1.multiply(delta_v_2).subtract_vector(&edge_2.multiply(delta_v_1)).divide(determinant);
+  let bitangent = edge_2.multiply(delta_u_1).subtract_vector(&edge_1.multiply(delta_u_2)).divide(determinant);
+
+  Some((tangent, bitangent))
+}
+
+// Tangents of surfaces mapped by azimuth around y axis, zero on the axis itself
+pub fn azimuth_tangent(object_point: &Point) -> Vector {
+  Vector::new(-object_point.z, 0.0, object_point.x)
+}
+
+// Weights of second and third corners for a point spread uniformly over a triangle
+pub fn triangle_sample_weights(sample: (f64, f64)) -> (f64, f64) {
+  let root = sample.0.sqrt();
+
+  (root * (1.0 - sample.1), root * sample.1)
+}
@@ -7,12 +7,19 @@ use crate::rendering::math::Matrix4x4;
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::shape::ShapeType;
 use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::triangle_tangents;
+use crate::rendering::shapes::shape::perpendicular_tangents;
+use crate::rendering::shapes::shape::triangle_sample_weights;
 
 use crate::rendering::Material;
 
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
 pub struct SmoothTriangle {
   pub id: u64,
   pub point_1: Point,
@@ -23,6 +30,9 @@ pub struct SmoothTriangle {
   pub normal_3: Vector,
   pub edge_1: Vector,
   pub edge_2: Vector,
+  pub uv_1: (f64, f64),
+  pub uv_2: (f64, f64),
+  pub uv_3: (f64, f64),
   pub transform: Matrix4x4,
   pub inverse: Matrix4x4,
   pub transpose: Matrix4x4,
@@ -46,12 +56,22 @@ impl SmoothTriangle {
       normal_3: normal_3,
       edge_1: edge_1,
       edge_2: edge_2,
+      // Default texture coordinates match barycentric coordinates
+      uv_1: (0.0, 0.0),
+      uv_2: (1.0, 0.0),
+      uv_3: (0.0, 1.0),
       transform: transform,
       inverse: tmp_inverse,
       transpose: tmp_inverse.transpose(),
       material: material
     }
   }
+
+  pub fn set_texture_coordinates(&mut self, uv_1: (f64, f64), uv_2: (f64, f64), uv_3: (f64, f64)) {
+    self.uv_1 = uv_1;
+    self.uv_2 = uv_2;
+    self.uv_3 = uv_3;
+  }
 }
 
 impl Shape for SmoothTriangle {
@@ -133,11 +153,54 @@ impl Shape for SmoothTriangle {
     i_1.add_vector(&i_2).add_vector(&i_3).normalize()
   }
 
+  fn texture_coordinates(&self, _point: &Point, u: f64, v: f64) -> (f64, f64) {
+    // Interpolates vertex texture coordinates
+    let (u_1, v_1) = self.uv_1;
+    let (u_2, v_2) = self.uv_2;
+    let (u_3, v_3) = self.uv_3;
+
+    (
+      u_2 * u + u_3 * v + u_1 * (1.0 - u - v),
+      v_2 * u + v_3 * v + v_1 * (1.0 - u - v)
+    )
+  }
+
+  fn surface_tangents(&self, point: &Point, u: f64, v: f64) -> (Vector, Vector) {
+    match triangle_tangents(&self.edge_1, &self.edge_2, self.uv_1, self.uv_2, self.uv_3) {
+      Some((tangent, bitangent)) => (self.transform.mult_vector(&tangent), self.transform.mult_vector(&bitangent)),
+      None => perpendicular_tangents(&self.normal_at_with_uv(point, u, v))
+    }
+  }
+
   fn interpolates_normals(&self) -> bool {
     true
   }
 
+  fn surface_area(&self) -> Option<f64> {
+    Some(self.edge_1.cross(&self.edge_2).magnitude() / 2.0)
+  }
+
+  // Normal of sample is that of flat triangle, as it is the area being sampled rather than shading
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let (weight_2, weight_3) = triangle_sample_weights(sample);
+    let point = self.point_1.add_vector(&self.edge_1.multiply(weight_2)).add_vector(&self.edge_2.multiply(weight_3));
+    let normal = self.edge_2.cross(&self.edge_1).normalize();
+
+    Some(SurfaceSample::new(point, normal, 1.0 / self.surface_area()?, self))
+  }
+
   fn get_base_type(&self) -> ShapeType {
     ShapeType::SmoothTriangle
   }
+
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    let mut tessellation = Tessellation::new(self.material.clone());
+
+    let vertex_1 = tessellation.add_vertex(self.point_1, self.normal_1);
+    let vertex_2 = tessellation.add_vertex(self.point_2, self.normal_2);
+    let vertex_3 = tessellation.add_vertex(self.point_3, self.normal_3);
+    tessellation.add_face([vertex_1, vertex_2, vertex_3]);
+
+    vec![tessellation]
+  }
 }
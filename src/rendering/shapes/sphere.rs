@@ -1,3 +1,5 @@
+use std::f64;
+
 use crate::rendering::math::tuple::Tuple;
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
@@ -7,12 +9,18 @@ use crate::rendering::math::Matrix4x4;
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::shape::ShapeType;
 use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::azimuth_tangent;
+use crate::rendering::shapes::shape::perpendicular_tangents;
 
 use crate::rendering::Material;
 
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
 pub struct Sphere {
   pub id: u64,
   pub transform: Matrix4x4,
@@ -93,11 +101,59 @@ impl Shape for Sphere {
     Vector::new(0.0, 0.0, 0.0)     
   }
 
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Spherical mapping, azimuth around y axis and polar angle from top
+    let theta = object_point.x.atan2(object_point.z);
+    let radius = Vector::new(object_point.x, object_point.y, object_point.z).magnitude();
+    let phi = (object_point.y / radius).acos();
+
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    (1.0 - (raw_u + 0.5), 1.0 - phi / f64::consts::PI)
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Mapping has no direction at poles
+    let tangent = azimuth_tangent(&object_point);
+    if tangent.magnitude() < 1e-9 {
+      return perpendicular_tangents(&self.normal_at(point));
+    }
+
+    // Polar angle decreases towards top of sphere, where v increases
+    let ring_distance_squared = object_point.x * object_point.x + object_point.z * object_point.z;
+    let bitangent = Vector::new(-object_point.x * object_point.y, ring_distance_squared, -object_point.y * object_point.z);
+
+    (self.transform.mult_vector(&tangent), self.transform.mult_vector(&bitangent))
+  }
+
   fn interpolates_normals(&self) -> bool {
     false
   }
 
+  fn surface_area(&self) -> Option<f64> {
+    Some(4.0 * f64::consts::PI)
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    // Heights are spread evenly, as are areas of bands between them on a sphere
+    let y = 1.0 - 2.0 * sample.0;
+    let ring_radius = (1.0 - y * y).max(0.0).sqrt();
+    let azimuth = 2.0 * f64::consts::PI * sample.1;
+
+    let normal = Vector::new(ring_radius * azimuth.cos(), y, ring_radius * azimuth.sin());
+
+    Some(SurfaceSample::new(Point::new(normal.x, normal.y, normal.z), normal, 1.0 / (4.0 * f64::consts::PI), self))
+  }
+
   fn get_base_type(&self) -> ShapeType {
     ShapeType::Sphere
   }
+
+  fn tessellate(&self, resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::sphere(resolution, self.material.clone())]
+  }
 }
@@ -214,4 +214,61 @@ mod tests {
 
     assert_eq!(normal.get_quad(), (0.0, 0.9701425001453319, -0.24253562503633294, 0.0));
   }
+
+  #[test]
+  fn spherical_texture_coordinates_on_sphere() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(transform, material);
+
+    assert!(sphere.texture_coordinates(&Point::new(0.0, 0.0, -1.0), 0.0, 0.0) == (0.0, 0.5));
+    assert!(sphere.texture_coordinates(&Point::new(1.0, 0.0, 0.0), 0.0, 0.0) == (0.25, 0.5));
+    assert!(sphere.texture_coordinates(&Point::new(0.0, 0.0, 1.0), 0.0, 0.0) == (0.5, 0.5));
+    assert!(sphere.texture_coordinates(&Point::new(-1.0, 0.0, 0.0), 0.0, 0.0) == (0.75, 0.5));
+    assert!(sphere.texture_coordinates(&Point::new(0.0, 1.0, 0.0), 0.0, 0.0) == (0.5, 1.0));
+    assert!(sphere.texture_coordinates(&Point::new(0.0, -1.0, 0.0), 0.0, 0.0) == (0.5, 0.0));
+  }
+
+  #[test]
+  fn surface_tangents_follow_texture_coordinates_on_sphere() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(transform, material);
+
+    let (tangent, bitangent) = sphere.surface_tangents(&Point::new(0.0, 0.0, 1.0), 0.0, 0.0);
+
+    assert!(tangent.normalize() == Vector::new(-1.0, 0.0, 0.0));
+    assert!(bitangent.normalize() == Vector::new(0.0, 1.0, 0.0));
+  }
+
+  #[test]
+  fn surface_tangents_at_pole_of_sphere_are_perpendicular_to_normal() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(transform, material);
+
+    let (tangent, bitangent) = sphere.surface_tangents(&Point::new(0.0, 1.0, 0.0), 0.0, 0.0);
+
+    assert!(tangent.magnitude() > 0.0 && bitangent.magnitude() > 0.0);
+    assert!(tangent.dot(&Vector::new(0.0, 1.0, 0.0)).abs() < 0.0001);
+    assert!(bitangent.dot(&Vector::new(0.0, 1.0, 0.0)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn surface_samples_lie_on_unit_sphere_with_outward_normals() {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let sphere = Sphere::new(Matrix4x4::translate(5.0, 0.0, 0.0), material);
+
+    assert!((sphere.surface_area().unwrap() - 4.0 * f64::consts::PI).abs() < 0.0001);
+
+    for sample in [(0.0, 0.0), (0.25, 0.5), (0.5, 0.75), (1.0, 0.3)] {
+      let surface_sample = sphere.sample_surface(sample).unwrap();
+      let from_center = Vector::new(surface_sample.point.x, surface_sample.point.y, surface_sample.point.z);
+
+      // Samples are before transform of sphere
+      assert!((from_center.magnitude() - 1.0).abs() < 0.0001);
+      assert!((surface_sample.normal.dot(&from_center) - 1.0).abs() < 0.0001);
+      assert!((surface_sample.pdf - 1.0 / (4.0 * f64::consts::PI)).abs() < 0.0001);
+    }
+  }
 }
@@ -0,0 +1,189 @@
+use std::f64;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::math::polynomial;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::shape::ShapeType;
+use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::azimuth_tangent;
+
+use crate::rendering::Material;
+
+use crate::rendering::Ray;
+use crate::rendering::Intersection;
+
+use crate::rendering::Bounds;
+
+use crate::rendering::Tessellation;
+
+// Ring lying in xz plane around y axis, major radius to center of tube and minor radius of tube
+pub struct Torus {
+  pub id: u64,
+  pub transform: Matrix4x4,
+  pub inverse: Matrix4x4,
+  pub transpose: Matrix4x4,
+  pub major_radius: f64,
+  pub minor_radius: f64,
+  pub material: Material
+}
+
+impl Torus {
+  pub fn new(transform: Matrix4x4, major_radius: f64, minor_radius: f64, material: Material) -> Torus {
+    let tmp_inverse = transform.inverse();
+
+    Torus {
+      id: generate_shape_id(),
+      transform,
+      inverse: tmp_inverse,
+      transpose: tmp_inverse.transpose(),
+      major_radius,
+      minor_radius,
+      material
+    }
+  }
+
+  pub fn bounds(&self) -> Bounds {
+    let outer_radius = self.major_radius + self.minor_radius;
+
+    Bounds::new(
+      Point::new(-outer_radius, -self.minor_radius, -outer_radius),
+      Point::new(outer_radius, self.minor_radius, outer_radius)
+    )
+  }
+
+  // Distances along ray where it crosses torus, ray must already be in object space
+  pub fn local_intersections(&self, ray: &Ray) -> Vec<f64> {
+    if !self.bounds().intersects(ray) {
+      return Vec::new();
+    }
+
+    // Quartic is solved for a unit direction starting near torus, as distant origins make coefficients lose precision
+    let direction_length = ray.direction.magnitude();
+    let direction = ray.direction.normalize();
+
+    let origin_to_center = Vector::new(ray.origin.x, ray.origin.y, ray.origin.z);
+    let outer_radius = self.major_radius + self.minor_radius;
+    let shift = (-origin_to_center.dot(&direction) - outer_radius).max(0.0);
+
+    let origin = Point::new(ray.origin.x + direction.x * shift, ray.origin.y + direction.y * shift, ray.origin.z + direction.z * shift);
+
+    let major_squared = self.major_radius * self.major_radius;
+    let minor_squared = self.minor_radius * self.minor_radius;
+
+    let origin_squared = origin.x * origin.x + origin.y * origin.y + origin.z * origin.z;
+    let f = origin.x * direction.x + origin.y * direction.y + origin.z * direction.z;
+    let e = origin_squared - major_squared - minor_squared;
+
+    let roots = polynomial::solve_quartic(
+      1.0,
+      4.0 * f,
+      4.0 * f * f + 2.0 * e + 4.0 * major_squared * direction.y * direction.y,
+      4.0 * f * e + 8.0 * major_squared * origin.y * direction.y,
+      e * e + 4.0 * major_squared * (origin.y * origin.y - minor_squared)
+    );
+
+    roots.iter().map(|root| (root + shift) / direction_length).collect()
+  }
+}
+
+impl Shape for Torus {
+  fn get_id(&self) -> u64 {
+    self.id
+  }
+
+  fn get_transform(&self) -> &Matrix4x4 {
+    &self.transform
+  }
+
+  fn get_inverse(&self) -> &Matrix4x4 {
+    &self.inverse
+  }
+
+  fn get_transpose(&self) -> &Matrix4x4 {
+    &self.transpose
+  }
+
+  fn get_material(&self) -> &Material {
+    &self.material
+  }
+
+  fn intersections(&self, ray: &Ray, world_to_container: Matrix4x4, normal_to_world: Matrix4x4) -> Vec<Intersection<'_>> {
+    let transformed_point = self.inverse.mult_point(&ray.origin);
+    let transformed_vector = self.inverse.mult_vector(&ray.direction);
+
+    let transformed_ray = Ray::new(&transformed_point, &transformed_vector);
+
+    let mut intersections: Vec<Intersection> = Vec::new();
+
+    for t in self.local_intersections(&transformed_ray) {
+      intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    }
+
+    intersections
+  }
+
+  fn normal_at(&self, point: &Point) -> Vector {
+    let object_point = self.inverse.mult_point(point);
+
+    let ring_distance = (object_point.x * object_point.x + object_point.z * object_point.z).sqrt();
+
+    // Points on y axis only exist when tube is wider than ring, and face along axis
+    let object_normal = if ring_distance < f64::EPSILON {
+      Vector::new(0.0, object_point.y.signum(), 0.0)
+    } else {
+      // Points away from nearest point on circle running through center of tube
+      let ring_scale = self.major_radius / ring_distance;
+
+      Vector::new(object_point.x - object_point.x * ring_scale, object_point.y, object_point.z - object_point.z * ring_scale).normalize()
+    };
+
+    self.transpose.mult_vector(&object_normal).normalize()
+  }
+
+  fn normal_at_with_uv(&self, _point: &Point, _u: f64, _v: f64) -> Vector {
+    // Not defined
+    Vector::new(0.0, 0.0, 0.0)
+  }
+
+  fn texture_coordinates(&self, point: &Point, _u: f64, _v: f64) -> (f64, f64) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Toroidal mapping, azimuth around y axis and angle around tube starting from inner edge
+    let theta = object_point.x.atan2(object_point.z);
+    let raw_u = theta / (2.0 * f64::consts::PI);
+
+    let ring_distance = (object_point.x * object_point.x + object_point.z * object_point.z).sqrt();
+    let phi = object_point.y.atan2(ring_distance - self.major_radius);
+    let raw_v = phi / (2.0 * f64::consts::PI);
+
+    (1.0 - (raw_u + 0.5), raw_v + 0.5)
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    let object_point = self.inverse.mult_point(point);
+
+    // Angle around tube increases from inner edge over top of tube
+    let ring_distance = (object_point.x * object_point.x + object_point.z * object_point.z).sqrt();
+    let bitangent = Vector::new(-object_point.x * object_point.y, ring_distance * (ring_distance - self.major_radius), -object_point.y * object_point.z);
+
+    (self.transform.mult_vector(&azimuth_tangent(&object_point)), self.transform.mult_vector(&bitangent))
+  }
+
+  fn interpolates_normals(&self) -> bool {
+    false
+  }
+
+  fn get_base_type(&self) -> ShapeType {
+    ShapeType::Torus
+  }
+
+  fn tessellate(&self, resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    vec![Tessellation::torus(self.major_radius, self.minor_radius, resolution, self.material.clone())]
+  }
+}
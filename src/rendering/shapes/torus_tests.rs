@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Torus;
+
+  use crate::rendering::Ray;
+  use crate::rendering::Computations;
+
+  use crate::rendering::Material;
+
+  fn unit_torus(transform: Matrix4x4) -> Torus {
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+
+    Torus::new(transform, 1.0, 0.25, material)
+  }
+
+  fn t_values_match(ray: &Ray, torus: &Torus, expected: &[f64]) -> bool {
+    let intersections = torus.intersections(ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    intersections.len() == expected.len() && intersections.iter().zip(expected.iter()).all(|(intersection, t)| (intersection.t - t).abs() < 0.0001)
+  }
+
+  #[test]
+  fn torus_created_with_transform_radii_and_material() {
+    let torus = unit_torus(Matrix4x4::translate(5.0, -3.0, 2.0));
+
+    assert!(torus.transform == Matrix4x4::translate(5.0, -3.0, 2.0));
+    assert!(torus.major_radius == 1.0);
+    assert!(torus.minor_radius == 0.25);
+    assert!(torus.material.ambient == 0.1);
+    assert!(torus.material.diffuse == 0.9);
+    assert!(torus.material.specular == 0.9);
+    assert!(torus.material.shininess == 200.0);
+  }
+
+  #[test]
+  fn torus_bounds_enclose_ring_and_tube() {
+    let bounds = unit_torus(Matrix4x4::identity()).bounds();
+
+    assert!(bounds.minimum == Point::new(-1.25, -0.25, -1.25));
+    assert!(bounds.maximum == Point::new(1.25, 0.25, 1.25));
+  }
+
+  #[test]
+  fn ray_intersects_torus() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    // Through both sides of ring
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(t_values_match(&ray, &torus, &[3.75, 4.25, 5.75, 6.25]));
+
+    // Along tube, crossing outer wall twice
+    let ray = Ray::new(&Point::new(1.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(t_values_match(&ray, &torus, &[4.25, 5.75]));
+
+    // Down through tube
+    let ray = Ray::new(&Point::new(1.0, 5.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+    assert!(t_values_match(&ray, &torus, &[4.75, 5.25]));
+
+    // Diagonally through near side of tube
+    let ray = Ray::new(&Point::new(0.0, -5.0, -6.0), &Vector::new(0.0, 1.0, 1.0).normalize());
+    assert!(t_values_match(&ray, &torus, &[50.0_f64.sqrt() - 0.25, 50.0_f64.sqrt() + 0.25]));
+  }
+
+  #[test]
+  fn ray_originating_inside_tube_intersects_behind_it() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -1.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert!(t_values_match(&ray, &torus, &[-0.25, 0.25, 1.75, 2.25]));
+  }
+
+  #[test]
+  fn ray_misses_torus() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    // Through hole
+    let ray = Ray::new(&Point::new(0.0, 5.0, 0.0), &Vector::new(0.0, -1.0, 0.0));
+    assert!(torus.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).is_empty());
+
+    // Above ring
+    let ray = Ray::new(&Point::new(0.0, 0.5, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(torus.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).is_empty());
+
+    // Outside bounds
+    let ray = Ray::new(&Point::new(5.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(torus.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).is_empty());
+  }
+
+  #[test]
+  fn distant_rays_intersect_torus_accurately() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    let ray = Ray::new(&Point::new(0.0, 0.0, -1000000.0), &Vector::new(0.0, 0.0, 1.0));
+
+    assert!(t_values_match(&ray, &torus, &[999998.75, 999999.25, 1000000.75, 1000001.25]));
+  }
+
+  #[test]
+  fn intersecting_transformed_torus() {
+    let torus = unit_torus(Matrix4x4::translate(0.0, 0.0, 2.0).mult4x4(&Matrix4x4::rotate_x(f64::consts::PI / 2.0)).mult4x4(&Matrix4x4::scale(2.0, 2.0, 2.0)));
+
+    // Ring stands upright, so ray passes through hole
+    let ray = Ray::new(&Point::new(0.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(torus.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity()).is_empty());
+
+    let ray = Ray::new(&Point::new(2.0, 0.0, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    assert!(t_values_match(&ray, &torus, &[6.5, 7.5]));
+  }
+
+  #[test]
+  fn normal_on_torus() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    let normal = torus.normal_at(&Point::new(1.25, 0.0, 0.0));
+    assert_eq!(normal.get_quad(), (1.0, 0.0, 0.0, 0.0));
+
+    let normal = torus.normal_at(&Point::new(0.75, 0.0, 0.0));
+    assert_eq!(normal.get_quad(), (-1.0, 0.0, 0.0, 0.0));
+
+    let normal = torus.normal_at(&Point::new(0.0, 0.25, -1.0));
+    assert_eq!(normal.get_quad(), (0.0, 1.0, 0.0, 0.0));
+
+    let normal = torus.normal_at(&Point::new(0.0, 0.0, -1.25));
+    assert_eq!(normal.get_quad(), (0.0, 0.0, -1.0, 0.0));
+
+    let offset = 0.25 / 2.0_f64.sqrt();
+    let normal = torus.normal_at(&Point::new(0.0, -offset, 1.0 + offset));
+    assert!((normal.y - -1.0 / 2.0_f64.sqrt()).abs() < 0.0001);
+    assert!((normal.z - 1.0 / 2.0_f64.sqrt()).abs() < 0.0001);
+  }
+
+  #[test]
+  fn normal_on_transformed_torus() {
+    let torus = unit_torus(Matrix4x4::translate(0.0, 1.0, 0.0).mult4x4(&Matrix4x4::rotate_z(f64::consts::PI / 2.0)));
+
+    let normal = torus.normal_at(&Point::new(0.0, 2.25, 0.0));
+
+    assert!((normal.x - 0.0).abs() < 0.0001);
+    assert!((normal.y - 1.0).abs() < 0.0001);
+    assert!((normal.z - 0.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn normals_at_intersections_face_away_from_tube() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    let ray = Ray::new(&Point::new(0.3, 0.1, -5.0), &Vector::new(0.0, 0.0, 1.0));
+    let intersections = torus.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert_eq!(intersections.len(), 4);
+
+    for intersection in &intersections {
+      let computations = Computations::new(intersection, &ray, &intersections);
+      let point = ray.position(intersection.t);
+
+      // Nearest point on circle through center of tube
+      let ring_distance = (point.x * point.x + point.z * point.z).sqrt();
+      let ring_point = Point::new(point.x / ring_distance, 0.0, point.z / ring_distance);
+
+      let outward = point.subtract_point(&ring_point).normalize();
+      let normal = torus.normal_at(&point);
+
+      assert!((normal.x - outward.x).abs() < 0.0001);
+      assert!((normal.y - outward.y).abs() < 0.0001);
+      assert!((normal.z - outward.z).abs() < 0.0001);
+      assert!((point.subtract_point(&ring_point).magnitude() - 0.25).abs() < 0.0001);
+      assert!(computations.normal.dot(&computations.eye_v) >= 0.0);
+    }
+  }
+
+  #[test]
+  fn toroidal_texture_coordinates_on_torus() {
+    let torus = unit_torus(Matrix4x4::identity());
+
+    let (u, v) = torus.texture_coordinates(&Point::new(0.0, 0.0, -1.25), 0.0, 0.0);
+    assert!((u - 0.0).abs() < 0.0001);
+    assert!((v - 0.5).abs() < 0.0001);
+
+    let (u, v) = torus.texture_coordinates(&Point::new(1.0, 0.25, 0.0), 0.0, 0.0);
+    assert!((u - 0.25).abs() < 0.0001);
+    assert!((v - 0.75).abs() < 0.0001);
+
+    let (u, v) = torus.texture_coordinates(&Point::new(0.0, -0.25, 1.0), 0.0, 0.0);
+    assert!((u - 0.5).abs() < 0.0001);
+    assert!((v - 0.25).abs() < 0.0001);
+  }
+}
@@ -1,12 +1,19 @@
 use crate::rendering::shapes::shape::Shape;
 use crate::rendering::shapes::shape::ShapeType;
 use crate::rendering::shapes::shape::generate_shape_id;
+use crate::rendering::shapes::shape::triangle_tangents;
+use crate::rendering::shapes::shape::perpendicular_tangents;
+use crate::rendering::shapes::shape::triangle_sample_weights;
 
 use crate::rendering::Material;
 
 use crate::rendering::Ray;
 use crate::rendering::Intersection;
 
+use crate::rendering::Tessellation;
+
+use crate::rendering::SurfaceSample;
+
 use crate::rendering::math::tuple::Tuple;
 use crate::rendering::math::Point;
 use crate::rendering::math::Vector;
@@ -21,6 +28,9 @@ pub struct Triangle {
   pub edge_1: Vector,
   pub edge_2: Vector,
   pub normal: Vector,
+  pub uv_1: (f64, f64),
+  pub uv_2: (f64, f64),
+  pub uv_3: (f64, f64),
   pub transform: Matrix4x4,
   pub inverse: Matrix4x4,
   pub transpose: Matrix4x4,
@@ -45,6 +55,10 @@ impl Triangle {
       edge_1: edge_1,
       edge_2: edge_2,
       normal: normal,
+      // Default texture coordinates match barycentric coordinates
+      uv_1: (0.0, 0.0),
+      uv_2: (1.0, 0.0),
+      uv_3: (0.0, 1.0),
       transform: transform,
       inverse: tmp_inverse,
       transpose: tmp_inverse.transpose(),
@@ -52,6 +66,22 @@ impl Triangle {
       material: material
     }
   }
+
+  pub fn new_with_normal(point_1: Point, point_2: Point, point_3: Point, normal: Vector, transform: Matrix4x4, material: Material) -> Triangle {
+    let mut triangle = Triangle::new(point_1, point_2, point_3, transform, material);
+
+    // Supplied normal replaces normal calculated from edges
+    triangle.normal = normal.normalize();
+    triangle.transformed_normal = triangle.transpose.mult_vector(&triangle.normal).normalize();
+
+    triangle
+  }
+
+  pub fn set_texture_coordinates(&mut self, uv_1: (f64, f64), uv_2: (f64, f64), uv_3: (f64, f64)) {
+    self.uv_1 = uv_1;
+    self.uv_2 = uv_2;
+    self.uv_3 = uv_3;
+  }
 }
 
 impl Shape for Triangle {
@@ -114,7 +144,7 @@ impl Shape for Triangle {
 
     let t = f * self.edge_2.dot(&origin_cross_e_1);
 
-    intersections.push(Intersection::new(t, self, world_to_container, normal_to_world));
+    intersections.push(Intersection::new_with_uv(t, self, world_to_container, normal_to_world, u, v));
 
     intersections
   }
@@ -128,11 +158,53 @@ impl Shape for Triangle {
     Vector::new(0.0, 0.0, 0.0)     
   }
 
+  fn texture_coordinates(&self, _point: &Point, u: f64, v: f64) -> (f64, f64) {
+    // Interpolates vertex texture coordinates
+    let (u_1, v_1) = self.uv_1;
+    let (u_2, v_2) = self.uv_2;
+    let (u_3, v_3) = self.uv_3;
+
+    (
+      u_2 * u + u_3 * v + u_1 * (1.0 - u - v),
+      v_2 * u + v_3 * v + v_1 * (1.0 - u - v)
+    )
+  }
+
+  fn surface_tangents(&self, point: &Point, _u: f64, _v: f64) -> (Vector, Vector) {
+    match triangle_tangents(&self.edge_1, &self.edge_2, self.uv_1, self.uv_2, self.uv_3) {
+      Some((tangent, bitangent)) => (self.transform.mult_vector(&tangent), self.transform.mult_vector(&bitangent)),
+      None => perpendicular_tangents(&self.normal_at(point))
+    }
+  }
+
   fn interpolates_normals(&self) -> bool {
     false
   }
 
+  fn surface_area(&self) -> Option<f64> {
+    Some(self.edge_1.cross(&self.edge_2).magnitude() / 2.0)
+  }
+
+  fn sample_surface(&self, sample: (f64, f64)) -> Option<SurfaceSample<'_>> {
+    let (weight_2, weight_3) = triangle_sample_weights(sample);
+    let point = self.point_1.add_vector(&self.edge_1.multiply(weight_2)).add_vector(&self.edge_2.multiply(weight_3));
+
+    Some(SurfaceSample::new(point, self.normal, 1.0 / self.surface_area()?, self))
+  }
+
   fn get_base_type(&self) -> ShapeType {
     ShapeType::Triangle
   }
+
+  fn tessellate(&self, _resolution: usize, _extent: f64) -> Vec<Tessellation> {
+    let mut tessellation = Tessellation::new(self.material.clone());
+
+    // Winding is kept as loaded, as normal may have been supplied separately
+    let vertex_1 = tessellation.add_vertex(self.point_1, self.normal);
+    let vertex_2 = tessellation.add_vertex(self.point_2, self.normal);
+    let vertex_3 = tessellation.add_vertex(self.point_3, self.normal);
+    tessellation.add_face([vertex_1, vertex_2, vertex_3]);
+
+    vec![tessellation]
+  }
 }
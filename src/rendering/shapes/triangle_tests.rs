@@ -146,4 +146,82 @@ mod tests {
     assert!(intersections.len() == 1); 
     assert!(intersections[0].t == 2.0);
   }
+
+  #[test]
+  fn intersection_with_triangle_stores_u_and_v() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let triangle = Triangle::new(Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), transform, material);
+
+    let ray = Ray::new(&Point::new(-0.2, 0.3, -2.0), &Vector::new(0.0, 0.0, 1.0));
+
+    let intersections = triangle.intersections(&ray, Matrix4x4::identity(), Matrix4x4::identity());
+
+    assert!((intersections[0].u - 0.45).abs() < 0.0001);
+    assert!((intersections[0].v - 0.25).abs() < 0.0001);
+  }
+
+  #[test]
+  fn triangle_interpolates_vertex_texture_coordinates() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut triangle = Triangle::new(Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), transform, material);
+    triangle.set_texture_coordinates((0.5, 1.0), (0.0, 0.0), (1.0, 0.0));
+
+    assert!(triangle.texture_coordinates(&Point::empty(), 0.0, 0.0) == (0.5, 1.0));
+    assert!(triangle.texture_coordinates(&Point::empty(), 1.0, 0.0) == (0.0, 0.0));
+    assert!(triangle.texture_coordinates(&Point::empty(), 0.0, 1.0) == (1.0, 0.0));
+    assert!(triangle.texture_coordinates(&Point::empty(), 0.25, 0.25) == (0.5, 0.5));
+  }
+
+  #[test]
+  fn triangle_without_texture_coordinates_uses_barycentric_coordinates() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let triangle = Triangle::new(Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), transform, material);
+
+    assert!(triangle.texture_coordinates(&Point::empty(), 0.45, 0.25) == (0.45, 0.25));
+  }
+
+  #[test]
+  fn triangle_created_with_supplied_normal() {
+    let transform = Matrix4x4::scale(1.0, 1.0, 2.0);
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    
+    let point_1 = Point::new(0.0, 1.0, 0.0);
+    let point_2 = Point::new(-1.0, 0.0, 0.0);
+    let point_3 = Point::new(1.0, 0.0, 0.0);
+    let triangle = Triangle::new_with_normal(point_1, point_2, point_3, Vector::new(0.0, 0.0, 2.0), transform, material);
+
+    assert!(triangle.edge_1.get_quad() == Vector::new(-1.0, -1.0, 0.0).get_quad());
+    assert!(triangle.normal.get_quad() == Vector::new(0.0, 0.0, 1.0).get_quad());
+    assert!(triangle.normal_at(&Point::new(0.0, 0.5, 0.0)).get_quad() == Vector::new(0.0, 0.0, 1.0).get_quad());
+  }
+
+  #[test]
+  fn surface_tangents_of_triangle_follow_vertex_texture_coordinates() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 2.0, 0.0), Point::new(2.0, 0.0, 0.0), transform, material);
+    triangle.set_texture_coordinates((0.0, 0.0), (0.0, 1.0), (1.0, 0.0));
+
+    let (tangent, bitangent) = triangle.surface_tangents(&Point::new(0.5, 0.5, 0.0), 0.25, 0.25);
+
+    assert!(tangent.get_quad() == Vector::new(2.0, 0.0, 0.0).get_quad());
+    assert!(bitangent.get_quad() == Vector::new(0.0, 2.0, 0.0).get_quad());
+  }
+
+  #[test]
+  fn surface_tangents_of_triangle_without_distinct_texture_coordinates_are_perpendicular_to_normal() {
+    let transform = Matrix4x4::identity();
+    let material = Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity());
+    let mut triangle = Triangle::new(Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), transform, material);
+    triangle.set_texture_coordinates((0.5, 0.5), (0.5, 0.5), (0.5, 0.5));
+
+    let (tangent, bitangent) = triangle.surface_tangents(&Point::new(0.0, 0.5, 0.0), 0.25, 0.25);
+
+    assert!((tangent.magnitude() - 1.0).abs() < 0.0001 && (bitangent.magnitude() - 1.0).abs() < 0.0001);
+    assert!(tangent.dot(&triangle.normal).abs() < 0.0001);
+    assert!(bitangent.dot(&triangle.normal).abs() < 0.0001);
+  }
 }
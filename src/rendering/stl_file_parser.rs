@@ -0,0 +1,339 @@
+use std::fmt;
+use std::error::Error;
+
+use std::collections::HashMap;
+
+use std::fs;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Color;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::shapes::shape::Shape;
+use crate::rendering::shapes::Triangle;
+use crate::rendering::shapes::SmoothTriangle;
+use crate::rendering::shapes::Mesh;
+
+use crate::rendering::Container;
+
+use crate::rendering::ObjFileParser;
+
+// Binary files have an 80 byte header and a 4 byte facet count, then 50 bytes per facet
+const BINARY_HEADER_SIZE: usize = 84;
+const BINARY_FACET_SIZE: usize = 50;
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct StlFacet {
+  pub normal: Vector,
+  pub points: [Point; 3]
+}
+
+#[derive(Debug)]
+pub enum StlError {
+  Io { file_name: String, error: std::io::Error },
+  Parse { file_name: String, message: String }
+}
+
+impl StlError {
+  pub fn parse(file_name: &str, message: String) -> StlError {
+    StlError::Parse { file_name: file_name.to_string(), message }
+  }
+}
+
+impl fmt::Display for StlError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      StlError::Io { file_name, error } => write!(f, "{}: {}", file_name, error),
+      StlError::Parse { file_name, message } => write!(f, "{}: {}", file_name, message)
+    }
+  }
+}
+
+impl Error for StlError {}
+
+pub struct StlFileParser {}
+
+impl StlFileParser {
+  pub fn load_file<'a>(file_name: String, transform: Matrix4x4, color: Color, triangles: &'a mut Vec<Triangle>) -> Result<Container<'a>, StlError> {
+    let facets = StlFileParser::read_facets(&file_name)?;
+
+    let material = ObjFileParser::default_material(color);
+
+    for facet in facets {
+      let [point_1, point_2, point_3] = facet.points;
+
+      // Many exporters write zero normals, leaving triangle to calculate its own
+      if facet.normal.magnitude() > 0.0 {
+        triangles.push(Triangle::new_with_normal(point_1, point_2, point_3, facet.normal, Matrix4x4::identity(), material.clone()));
+      } else {
+        triangles.push(Triangle::new(point_1, point_2, point_3, Matrix4x4::identity(), material.clone()));
+      }
+    }
+
+    let mut container_shape_references = Vec::new();
+    for triangle in triangles {
+      container_shape_references.push(triangle as &dyn Shape);
+    }
+
+    Ok(Container::new(transform, container_shape_references))
+  }
+
+  pub fn load_file_with_smooth_normals<'a>(file_name: String, transform: Matrix4x4, color: Color, smooth_triangles: &'a mut Vec<SmoothTriangle>) -> Result<Container<'a>, StlError> {
+    let facets = StlFileParser::read_facets(&file_name)?;
+
+    let material = ObjFileParser::default_material(color);
+
+    let (vertices, faces) = StlFileParser::weld_vertices(&facets);
+    let normals = StlFileParser::smooth_normals(&vertices, &faces);
+
+    for (facet, face) in facets.iter().zip(faces) {
+      let [point_1, point_2, point_3] = facet.points;
+
+      smooth_triangles.push(SmoothTriangle::new(
+        point_1,
+        point_2,
+        point_3,
+        normals[face[0]],
+        normals[face[1]],
+        normals[face[2]],
+        Matrix4x4::identity(),
+        material.clone()
+      ));
+    }
+
+    let mut container_shape_references = Vec::new();
+    for smooth_triangle in smooth_triangles {
+      container_shape_references.push(smooth_triangle as &dyn Shape);
+    }
+
+    Ok(Container::new(transform, container_shape_references))
+  }
+
+  // Welds vertices into a single mesh, faces are flat shaded
+  pub fn load_mesh(file_name: String, transform: Matrix4x4, color: Color) -> Result<Mesh, StlError> {
+    let facets = StlFileParser::read_facets(&file_name)?;
+
+    let (vertices, faces) = StlFileParser::weld_vertices(&facets);
+
+    Ok(Mesh::from_triangles(vertices, faces, transform, ObjFileParser::default_material(color)))
+  }
+
+  pub fn read_facets(file_name: &str) -> Result<Vec<StlFacet>, StlError> {
+    let stl_data = fs::read(file_name).map_err(|error| StlError::Io { file_name: file_name.to_string(), error })?;
+
+    StlFileParser::parse_facets(&stl_data).map_err(|message| StlError::parse(file_name, message))
+  }
+
+  pub fn parse_facets(stl_data: &[u8]) -> Result<Vec<StlFacet>, String> {
+    if StlFileParser::is_binary(stl_data) {
+      StlFileParser::parse_binary(stl_data)
+    } else {
+      StlFileParser::parse_ascii(stl_data)
+    }
+  }
+
+  pub fn is_binary(stl_data: &[u8]) -> bool {
+    // Binary headers may also begin with "solid", so a matching facet count decides first
+    if stl_data.len() >= BINARY_HEADER_SIZE {
+      let facet_count = u32::from_le_bytes([stl_data[80], stl_data[81], stl_data[82], stl_data[83]]) as usize;
+
+      if stl_data.len() == BINARY_HEADER_SIZE + facet_count * BINARY_FACET_SIZE {
+        return true;
+      }
+    }
+
+    // Header padding and float bytes of a truncated binary file are not text, so it still reports being truncated
+    let is_text = stl_data.iter().all(|byte| byte.is_ascii_graphic() || byte.is_ascii_whitespace());
+
+    !(is_text && stl_data.trim_ascii_start().starts_with(b"solid"))
+  }
+
+  pub fn parse_binary(stl_data: &[u8]) -> Result<Vec<StlFacet>, String> {
+    if stl_data.len() < BINARY_HEADER_SIZE {
+      return Err(format!("binary file is {} bytes, shorter than {} byte header", stl_data.len(), BINARY_HEADER_SIZE));
+    }
+
+    let facet_count = u32::from_le_bytes([stl_data[80], stl_data[81], stl_data[82], stl_data[83]]) as usize;
+    let expected_size = BINARY_HEADER_SIZE + facet_count * BINARY_FACET_SIZE;
+
+    if stl_data.len() < expected_size {
+      return Err(format!("binary file is truncated, {} facets need {} bytes, found {}", facet_count, expected_size, stl_data.len()));
+    }
+
+    let mut facets = Vec::new();
+
+    for facet_index in 0..facet_count {
+      let facet_start = BINARY_HEADER_SIZE + facet_index * BINARY_FACET_SIZE;
+
+      // Normal and three vertices, followed by an unused two byte attribute count
+      let mut values = [0.0; 12];
+      for (value_index, value) in values.iter_mut().enumerate() {
+        let value_start = facet_start + value_index * 4;
+        let bytes = [stl_data[value_start], stl_data[value_start + 1], stl_data[value_start + 2], stl_data[value_start + 3]];
+
+        *value = f32::from_le_bytes(bytes) as f64;
+      }
+
+      if values.iter().any(|value| !value.is_finite()) {
+        return Err(format!("facet {} contains a value that is not a finite number", facet_index + 1));
+      }
+
+      facets.push(StlFacet {
+        normal: Vector::new(values[0], values[1], values[2]),
+        points: [
+          Point::new(values[3], values[4], values[5]),
+          Point::new(values[6], values[7], values[8]),
+          Point::new(values[9], values[10], values[11])
+        ]
+      });
+    }
+
+    Ok(facets)
+  }
+
+  pub fn parse_ascii(stl_data: &[u8]) -> Result<Vec<StlFacet>, String> {
+    let stl_text = match std::str::from_utf8(stl_data) {
+      Ok(stl_text) => stl_text,
+      Err(_) => return Err(String::from("ascii file contains invalid characters"))
+    };
+
+    let mut facets = Vec::new();
+
+    let mut facet_normal: Option<Vector> = None;
+    let mut facet_points: Vec<Point> = Vec::new();
+    let mut ended = false;
+
+    for (line_index, line) in stl_text.lines().enumerate() {
+      let tokens = line.split_whitespace().collect::<Vec<&str>>();
+
+      if tokens.is_empty() {
+        continue;
+      }
+
+      let line_result = match tokens[0] {
+        "solid" => Ok(()),
+        "facet" => {
+          if facet_normal.is_some() {
+            Err(String::from("facet started before previous facet ended"))
+          } else if tokens.get(1) != Some(&"normal") {
+            Err(String::from("expected facet normal"))
+          } else {
+            StlFileParser::parse_values(&tokens[2..]).map(|values| facet_normal = Some(Vector::new(values[0], values[1], values[2])))
+          }
+        },
+        "outer" => {
+          if tokens.get(1) == Some(&"loop") {
+            Ok(())
+          } else {
+            Err(String::from("expected outer loop"))
+          }
+        },
+        "vertex" => {
+          if facet_normal.is_none() {
+            Err(String::from("vertex outside of facet"))
+          } else {
+            StlFileParser::parse_values(&tokens[1..]).map(|values| facet_points.push(Point::new(values[0], values[1], values[2])))
+          }
+        },
+        "endloop" => Ok(()),
+        "endfacet" => match facet_normal.take() {
+          Some(normal) if facet_points.len() == 3 => {
+            facets.push(StlFacet { normal, points: [facet_points[0], facet_points[1], facet_points[2]] });
+            facet_points.clear();
+
+            Ok(())
+          },
+          Some(_) => Err(format!("facet requires 3 vertices, found {}", facet_points.len())),
+          None => Err(String::from("endfacet without facet"))
+        },
+        "endsolid" => {
+          ended = true;
+
+          Ok(())
+        },
+        keyword => Err(format!("unexpected keyword '{}'", keyword))
+      };
+
+      line_result.map_err(|message| format!("line {}: {}", line_index + 1, message))?;
+
+      if ended {
+        break;
+      }
+    }
+
+    if !ended {
+      return Err(String::from("ascii file is truncated, no endsolid found"));
+    }
+
+    Ok(facets)
+  }
+
+  pub fn parse_values(value_texts: &[&str]) -> Result<Vec<f64>, String> {
+    if value_texts.len() != 3 {
+      return Err(format!("expected 3 values, found {}", value_texts.len()));
+    }
+
+    let mut values = Vec::new();
+    for value_text in value_texts {
+      match value_text.parse::<f64>() {
+        Ok(value) if value.is_finite() => values.push(value),
+        Ok(_) => return Err(format!("'{}' is not a finite number", value_text)),
+        Err(_) => return Err(format!("invalid number '{}'", value_text))
+      }
+    }
+
+    Ok(values)
+  }
+
+  // Merges facet corners at identical positions, returning unique vertices and index triples
+  pub fn weld_vertices(facets: &[StlFacet]) -> (Vec<Point>, Vec<[usize; 3]>) {
+    let mut vertices = Vec::new();
+    let mut faces = Vec::new();
+    let mut vertex_indices: HashMap<(u64, u64, u64), usize> = HashMap::new();
+
+    for facet in facets {
+      let mut face = [0; 3];
+
+      for (corner, point) in facet.points.iter().enumerate() {
+        // Adding zero folds negative zero into positive zero
+        let key = ((point.x + 0.0).to_bits(), (point.y + 0.0).to_bits(), (point.z + 0.0).to_bits());
+
+        face[corner] = *vertex_indices.entry(key).or_insert_with(|| {
+          vertices.push(*point);
+          vertices.len() - 1
+        });
+      }
+
+      faces.push(face);
+    }
+
+    (vertices, faces)
+  }
+
+  pub fn smooth_normals(vertices: &[Point], faces: &[[usize; 3]]) -> Vec<Vector> {
+    let mut normals = vec![Vector::new(0.0, 0.0, 0.0); vertices.len()];
+
+    // Unnormalized cross product weights each face by its area
+    for face in faces {
+      let edge_1 = vertices[face[1]].subtract_point(&vertices[face[0]]);
+      let edge_2 = vertices[face[2]].subtract_point(&vertices[face[0]]);
+      let face_normal = edge_1.cross(&edge_2);
+
+      for &index in face {
+        normals[index] = normals[index].add_vector(&face_normal);
+      }
+    }
+
+    for normal in &mut normals {
+      if normal.magnitude() > 0.0 {
+        *normal = normal.normalize();
+      }
+    }
+
+    normals
+  }
+}
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::env;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::Triangle;
+  use crate::rendering::shapes::SmoothTriangle;
+
+  use crate::rendering::StlFacet;
+  use crate::rendering::StlError;
+  use crate::rendering::StlFileParser;
+
+  const ASCII_PYRAMID_SIDE: &str = "solid side\n  facet normal 0 0 -1\n    outer loop\n      vertex 0 1 0\n      vertex 1 0 0\n      vertex -1 0 0\n    endloop\n  endfacet\n  facet normal 0 0 0\n    outer loop\n      vertex 0 1 0\n      vertex 0 0 1\n      vertex 1 0 0\n    endloop\n  endfacet\nendsolid side\n";
+
+  fn write_stl_file(file_name: &str, stl_data: &[u8]) -> String {
+    let directory = env::temp_dir().join("jb_tracer_stl_file_parser_tests");
+    fs::create_dir_all(&directory).unwrap();
+
+    let file_path = directory.join(file_name);
+    fs::write(&file_path, stl_data).unwrap();
+
+    file_path.to_string_lossy().to_string()
+  }
+
+  fn binary_stl(header: &str, facet_values: Vec<[f32; 12]>) -> Vec<u8> {
+    let mut stl_data = vec![0u8; 80];
+    stl_data[..header.len()].copy_from_slice(header.as_bytes());
+
+    stl_data.extend_from_slice(&(facet_values.len() as u32).to_le_bytes());
+
+    for values in facet_values {
+      for value in values.iter() {
+        stl_data.extend_from_slice(&value.to_le_bytes());
+      }
+
+      stl_data.extend_from_slice(&[0, 0]);
+    }
+
+    stl_data
+  }
+
+  #[test]
+  fn parses_ascii_facets() {
+    let facets = StlFileParser::parse_facets(ASCII_PYRAMID_SIDE.as_bytes()).unwrap();
+
+    assert_eq!(facets.len(), 2);
+    assert!(facets[0] == StlFacet {
+      normal: Vector::new(0.0, 0.0, -1.0),
+      points: [Point::new(0.0, 1.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(-1.0, 0.0, 0.0)]
+    });
+    assert!(facets[1].points[1] == Point::new(0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn parses_binary_facets_even_when_header_starts_with_solid() {
+    let stl_data = binary_stl("solid exported by cad", vec![
+      [0.0, 0.0, -1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0],
+      [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]
+    ]);
+
+    assert!(StlFileParser::is_binary(&stl_data));
+
+    let facets = StlFileParser::parse_facets(&stl_data).unwrap();
+
+    assert_eq!(facets.len(), 2);
+    assert!(facets[0].normal == Vector::new(0.0, 0.0, -1.0));
+    assert!(facets[0].points[2] == Point::new(-1.0, 0.0, 0.0));
+    assert!(facets[1].points[1] == Point::new(0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn reports_truncated_binary_files() {
+    let mut stl_data = binary_stl("binary", vec![[0.0; 12], [0.0; 12]]);
+    stl_data.truncate(stl_data.len() - 10);
+
+    assert!(StlFileParser::parse_facets(&stl_data).is_err());
+    assert!(StlFileParser::parse_facets(&[0u8; 40]).is_err());
+  }
+
+  #[test]
+  fn reports_truncated_binary_files_whose_header_starts_with_solid() {
+    let mut stl_data = binary_stl("solid exported by cad", vec![
+      [0.0, 0.0, -1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0],
+      [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]
+    ]);
+    stl_data.truncate(stl_data.len() - 10);
+
+    assert!(StlFileParser::is_binary(&stl_data));
+
+    let error = StlFileParser::parse_facets(&stl_data).err().unwrap();
+    assert_eq!(error, "binary file is truncated, 2 facets need 184 bytes, found 174");
+  }
+
+  #[test]
+  fn reports_binary_facets_that_are_not_finite() {
+    let stl_data = binary_stl("binary", vec![[0.0, 0.0, -1.0, 0.0, 1.0, 0.0, f32::NAN, 0.0, 0.0, -1.0, 0.0, 0.0]]);
+
+    assert!(StlFileParser::parse_facets(&stl_data).is_err());
+  }
+
+  #[test]
+  fn reports_malformed_ascii_files() {
+    let truncated = &ASCII_PYRAMID_SIDE[..ASCII_PYRAMID_SIDE.len() - 20];
+    assert!(StlFileParser::parse_facets(truncated.as_bytes()).is_err());
+
+    let missing_vertex = ASCII_PYRAMID_SIDE.replacen("      vertex -1 0 0\n", "", 1);
+    assert!(StlFileParser::parse_facets(missing_vertex.as_bytes()).is_err());
+
+    let bad_number = ASCII_PYRAMID_SIDE.replacen("vertex 1 0 0", "vertex 1 zero 0", 1);
+    let error = StlFileParser::parse_facets(bad_number.as_bytes()).err().unwrap();
+    assert_eq!(error, "line 5: invalid number 'zero'");
+
+    let not_finite = ASCII_PYRAMID_SIDE.replacen("vertex 1 0 0", "vertex 1 NaN 0", 1);
+    let error = StlFileParser::parse_facets(not_finite.as_bytes()).err().unwrap();
+    assert_eq!(error, "line 5: 'NaN' is not a finite number");
+
+    let unknown_keyword = ASCII_PYRAMID_SIDE.replacen("outer loop", "inner loop", 1);
+    assert!(StlFileParser::parse_facets(unknown_keyword.as_bytes()).is_err());
+  }
+
+  #[test]
+  fn loads_triangles_with_facet_normals() {
+    let file_name = write_stl_file("side.stl", ASCII_PYRAMID_SIDE.as_bytes());
+
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let container = StlFileParser::load_file(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles).unwrap();
+
+    assert_eq!(container.shapes.len(), 2);
+
+    // Facet normal used as given
+    assert!(triangles[0].normal == Vector::new(0.0, 0.0, -1.0));
+
+    // Zero facet normal calculated from edges
+    assert!(triangles[1].normal.magnitude() > 0.99);
+  }
+
+  #[test]
+  fn welds_vertices_shared_by_facets() {
+    let facets = StlFileParser::parse_facets(ASCII_PYRAMID_SIDE.as_bytes()).unwrap();
+
+    let (vertices, faces) = StlFileParser::weld_vertices(&facets);
+
+    assert_eq!(vertices.len(), 4);
+    assert!(faces == vec![[0, 1, 2], [0, 3, 1]]);
+  }
+
+  #[test]
+  fn computes_area_weighted_smooth_normals() {
+    let vertices = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0), Point::new(0.0, 0.0, -2.0)];
+    let faces = vec![[0, 1, 2], [0, 1, 3]];
+
+    let normals = StlFileParser::smooth_normals(&vertices, &faces);
+
+    // Second face is twice the area of the first, so it pulls the shared normals towards it
+    let expected = Vector::new(0.0, 1.0, 0.5).normalize();
+    assert!((normals[0].y - expected.y).abs() < 0.0001);
+    assert!((normals[0].z - expected.z).abs() < 0.0001);
+    assert!(normals[2] == Vector::new(0.0, 0.0, 1.0));
+    assert!(normals[3] == Vector::new(0.0, 1.0, 0.0));
+  }
+
+  #[test]
+  fn loads_smooth_triangles_with_welded_vertices() {
+    let file_name = write_stl_file("smooth_side.stl", ASCII_PYRAMID_SIDE.as_bytes());
+
+    let mut smooth_triangles: Vec<SmoothTriangle> = Vec::new();
+    let container = StlFileParser::load_file_with_smooth_normals(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut smooth_triangles).unwrap();
+
+    assert_eq!(container.shapes.len(), 2);
+    assert!(container.shapes[0].interpolates_normals());
+
+    // Shared corners receive the same normal in both triangles
+    assert!(smooth_triangles[0].normal_1 == smooth_triangles[1].normal_1);
+    assert!(smooth_triangles[0].normal_2 == smooth_triangles[1].normal_3);
+  }
+
+  #[test]
+  fn reports_missing_files() {
+    let mut triangles: Vec<Triangle> = Vec::new();
+    let result = StlFileParser::load_file("does_not_exist.stl".to_string(), Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0), &mut triangles);
+
+    match result {
+      Err(StlError::Io { file_name, error: _ }) => assert_eq!(file_name, "does_not_exist.stl"),
+      _ => panic!("Expected io error")
+    }
+  }
+
+  #[test]
+  fn loads_file_as_mesh_with_welded_vertices() {
+    let file_name = write_stl_file("mesh_side.stl", ASCII_PYRAMID_SIDE.as_bytes());
+
+    let mesh = StlFileParser::load_mesh(file_name, Matrix4x4::identity(), Color::new(1.0, 1.0, 1.0, 1.0)).unwrap();
+
+    assert_eq!(mesh.faces.len(), 2);
+    assert_eq!(mesh.data.vertices.len(), 4);
+  }
+}
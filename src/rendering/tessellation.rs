@@ -0,0 +1,321 @@
+use std::f64;
+use std::rc::Rc;
+
+use crate::rendering::math::tuple::Tuple;
+use crate::rendering::math::Point;
+use crate::rendering::math::Vector;
+
+use crate::rendering::math::Matrix4x4;
+
+use crate::rendering::Material;
+
+// Fewer segments could not enclose any volume
+const MINIMUM_SEGMENTS: usize = 3;
+
+// Triangles approximating surface of a shape, with one normal per vertex
+#[derive(Clone)]
+pub struct Tessellation {
+  pub group: String,
+  pub vertices: Vec<Point>,
+  pub normals: Vec<Vector>,
+  pub faces: Vec<[usize; 3]>,
+  pub material: Material,
+  pub override_material: Option<Rc<Material>>
+}
+
+impl Tessellation {
+  pub fn new(material: Material) -> Tessellation {
+    Tessellation {
+      group: String::new(),
+      vertices: Vec::new(),
+      normals: Vec::new(),
+      faces: Vec::new(),
+      material,
+      override_material: None
+    }
+  }
+
+  // Material of container holding shape takes precedence, matching intersections
+  pub fn get_material(&self) -> &Material {
+    match &self.override_material {
+      Some(material) => material,
+      None => &self.material
+    }
+  }
+
+  pub fn add_vertex(&mut self, point: Point, normal: Vector) -> usize {
+    self.vertices.push(point);
+    self.normals.push(normal);
+
+    self.vertices.len() - 1
+  }
+
+  pub fn add_face(&mut self, face: [usize; 3]) {
+    self.faces.push(face);
+  }
+
+  // Orders face counter clockwise around its normals, skipping faces with no area
+  pub fn add_oriented_face(&mut self, face: [usize; 3]) {
+    let edge_1 = self.vertices[face[1]].subtract_point(&self.vertices[face[0]]);
+    let edge_2 = self.vertices[face[2]].subtract_point(&self.vertices[face[0]]);
+    let winding_normal = edge_1.cross(&edge_2);
+
+    if winding_normal.magnitude() < f64::EPSILON {
+      return;
+    }
+
+    let normal = self.normals[face[0]].add_vector(&self.normals[face[1]]).add_vector(&self.normals[face[2]]);
+
+    if winding_normal.dot(&normal) < 0.0 {
+      self.faces.push([face[0], face[2], face[1]]);
+    } else {
+      self.faces.push(face);
+    }
+  }
+
+  // Moves vertices into space of parent, normals are transformed by inverse transpose
+  pub fn transform(mut self, transform: &Matrix4x4) -> Tessellation {
+    let transpose = transform.inverse().transpose();
+
+    for vertex in &mut self.vertices {
+      *vertex = transform.mult_point(vertex);
+    }
+
+    for normal in &mut self.normals {
+      if normal.magnitude() > 0.0 {
+        *normal = transpose.mult_vector(normal).normalize();
+      }
+    }
+
+    self
+  }
+
+  // Segments around a full circle, never fewer than can enclose a volume
+  pub fn segments(resolution: usize) -> usize {
+    resolution.max(MINIMUM_SEGMENTS)
+  }
+
+  pub fn sphere(resolution: usize, material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    let slices = Tessellation::segments(resolution);
+    let stacks = (slices / 2).max(2);
+
+    for stack in 0..=stacks {
+      let polar_angle = f64::consts::PI * stack as f64 / stacks as f64;
+
+      for slice in 0..=slices {
+        let azimuth = 2.0 * f64::consts::PI * slice as f64 / slices as f64;
+
+        let normal = Vector::new(polar_angle.sin() * azimuth.cos(), polar_angle.cos(), polar_angle.sin() * azimuth.sin());
+        tessellation.add_vertex(Point::new(normal.x, normal.y, normal.z), normal);
+      }
+    }
+
+    // Faces touching poles collapse to single triangles, as their other triangle has no area
+    for stack in 0..stacks {
+      for slice in 0..slices {
+        let top_1 = stack * (slices + 1) + slice;
+        let top_2 = top_1 + 1;
+        let bottom_1 = top_1 + slices + 1;
+        let bottom_2 = bottom_1 + 1;
+
+        tessellation.add_oriented_face([top_1, bottom_1, top_2]);
+        tessellation.add_oriented_face([top_2, bottom_1, bottom_2]);
+      }
+    }
+
+    tessellation
+  }
+
+  pub fn cube(material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    let normals = [
+      Vector::new(1.0, 0.0, 0.0),
+      Vector::new(-1.0, 0.0, 0.0),
+      Vector::new(0.0, 1.0, 0.0),
+      Vector::new(0.0, -1.0, 0.0),
+      Vector::new(0.0, 0.0, 1.0),
+      Vector::new(0.0, 0.0, -1.0)
+    ];
+
+    for normal in normals.iter() {
+      // Two axes spanning face, perpendicular to its normal
+      let tangent = Vector::new(normal.y.abs() + normal.z.abs(), normal.x.abs(), 0.0);
+      let bitangent = normal.cross(&tangent);
+
+      let mut corners = [0; 4];
+      for (corner_index, (tangent_sign, bitangent_sign)) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].iter().enumerate() {
+        let offset = normal.add_vector(&tangent.multiply(*tangent_sign)).add_vector(&bitangent.multiply(*bitangent_sign));
+
+        corners[corner_index] = tessellation.add_vertex(Point::new(offset.x, offset.y, offset.z), *normal);
+      }
+
+      tessellation.add_oriented_face([corners[0], corners[1], corners[2]]);
+      tessellation.add_oriented_face([corners[0], corners[2], corners[3]]);
+    }
+
+    tessellation
+  }
+
+  // Infinite plane is cut to a square reaching extent along x and z
+  pub fn plane(extent: f64, material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    let normal = Vector::new(0.0, 1.0, 0.0);
+
+    let corner_1 = tessellation.add_vertex(Point::new(-extent, 0.0, -extent), normal);
+    let corner_2 = tessellation.add_vertex(Point::new(extent, 0.0, -extent), normal);
+    let corner_3 = tessellation.add_vertex(Point::new(extent, 0.0, extent), normal);
+    let corner_4 = tessellation.add_vertex(Point::new(-extent, 0.0, extent), normal);
+
+    tessellation.add_oriented_face([corner_1, corner_2, corner_3]);
+    tessellation.add_oriented_face([corner_1, corner_3, corner_4]);
+
+    tessellation
+  }
+
+  pub fn torus(major_radius: f64, minor_radius: f64, resolution: usize, material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    let slices = Tessellation::segments(resolution);
+    let tube_slices = Tessellation::segments(resolution / 2);
+
+    for slice in 0..=slices {
+      let azimuth = 2.0 * f64::consts::PI * slice as f64 / slices as f64;
+
+      for tube_slice in 0..=tube_slices {
+        let tube_angle = 2.0 * f64::consts::PI * tube_slice as f64 / tube_slices as f64;
+
+        let normal = Vector::new(tube_angle.cos() * azimuth.cos(), tube_angle.sin(), tube_angle.cos() * azimuth.sin());
+        let ring_distance = major_radius + minor_radius * tube_angle.cos();
+
+        tessellation.add_vertex(Point::new(ring_distance * azimuth.cos(), minor_radius * tube_angle.sin(), ring_distance * azimuth.sin()), normal);
+      }
+    }
+
+    for slice in 0..slices {
+      for tube_slice in 0..tube_slices {
+        let vertex_1 = slice * (tube_slices + 1) + tube_slice;
+        let vertex_2 = vertex_1 + 1;
+        let vertex_3 = vertex_1 + tube_slices + 1;
+        let vertex_4 = vertex_3 + 1;
+
+        tessellation.add_oriented_face([vertex_1, vertex_3, vertex_2]);
+        tessellation.add_oriented_face([vertex_2, vertex_3, vertex_4]);
+      }
+    }
+
+    tessellation
+  }
+
+  // Flat ring in xz plane facing up, disks have no inner radius
+  pub fn annulus(inner_radius: f64, outer_radius: f64, resolution: usize, material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    if inner_radius <= 0.0 {
+      tessellation.add_cap(0.0, outer_radius, 1.0, resolution);
+
+      return tessellation;
+    }
+
+    tessellation.add_band(0.0, inner_radius, 0.0, outer_radius, resolution, |_, _| Vector::new(0.0, 1.0, 0.0));
+
+    tessellation
+  }
+
+  // Unbounded cylinders are cut at extent
+  pub fn cylinder(minimum: f64, maximum: f64, capped: bool, resolution: usize, extent: f64, material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    let minimum = minimum.max(-extent);
+    let maximum = maximum.min(extent);
+
+    tessellation.add_band(minimum, 1.0, maximum, 1.0, resolution, |_, direction| direction);
+
+    if capped {
+      tessellation.add_cap(minimum, 1.0, -1.0, resolution);
+      tessellation.add_cap(maximum, 1.0, 1.0, resolution);
+    }
+
+    tessellation
+  }
+
+  // Double cones are split at apex, where side normals change direction
+  pub fn cone(minimum: f64, maximum: f64, capped: bool, resolution: usize, extent: f64, material: Material) -> Tessellation {
+    let mut tessellation = Tessellation::new(material);
+
+    let minimum = minimum.max(-extent);
+    let maximum = maximum.min(extent);
+
+    let side_normal = |y: f64, direction: Vector| {
+      let slope = if y > 0.0 { -1.0 } else { 1.0 };
+
+      Vector::new(direction.x, slope, direction.z).normalize()
+    };
+
+    if minimum < 0.0 && maximum > 0.0 {
+      tessellation.add_band(minimum, minimum.abs(), 0.0, 0.0, resolution, |_, direction| side_normal(-1.0, direction));
+      tessellation.add_band(0.0, 0.0, maximum, maximum.abs(), resolution, |_, direction| side_normal(1.0, direction));
+    } else {
+      let middle = (minimum + maximum) / 2.0;
+
+      tessellation.add_band(minimum, minimum.abs(), maximum, maximum.abs(), resolution, |_, direction| side_normal(middle, direction));
+    }
+
+    if capped {
+      tessellation.add_cap(minimum, minimum.abs(), -1.0, resolution);
+      tessellation.add_cap(maximum, maximum.abs(), 1.0, resolution);
+    }
+
+    tessellation
+  }
+
+  // Side of a surface of revolution around y axis between two circles
+  pub fn add_band(&mut self, bottom: f64, bottom_radius: f64, top: f64, top_radius: f64, resolution: usize, normal_at: impl Fn(f64, Vector) -> Vector) {
+    let slices = Tessellation::segments(resolution);
+
+    let first_vertex = self.vertices.len();
+
+    for slice in 0..=slices {
+      let angle = 2.0 * f64::consts::PI * slice as f64 / slices as f64;
+      let direction = Vector::new(angle.cos(), 0.0, angle.sin());
+
+      self.add_vertex(Point::new(direction.x * bottom_radius, bottom, direction.z * bottom_radius), normal_at(bottom, direction));
+      self.add_vertex(Point::new(direction.x * top_radius, top, direction.z * top_radius), normal_at(top, direction));
+    }
+
+    for slice in 0..slices {
+      let bottom_1 = first_vertex + slice * 2;
+      let top_1 = bottom_1 + 1;
+      let bottom_2 = bottom_1 + 2;
+      let top_2 = bottom_1 + 3;
+
+      self.add_oriented_face([bottom_1, top_1, bottom_2]);
+      self.add_oriented_face([bottom_2, top_1, top_2]);
+    }
+  }
+
+  pub fn add_cap(&mut self, y: f64, radius: f64, facing: f64, resolution: usize) {
+    if radius <= 0.0 {
+      return;
+    }
+
+    let slices = Tessellation::segments(resolution);
+    let normal = Vector::new(0.0, facing, 0.0);
+
+    let center = self.add_vertex(Point::new(0.0, y, 0.0), normal);
+
+    let first_vertex = self.vertices.len();
+    for slice in 0..slices {
+      let angle = 2.0 * f64::consts::PI * slice as f64 / slices as f64;
+
+      self.add_vertex(Point::new(angle.cos() * radius, y, angle.sin() * radius), normal);
+    }
+
+    for slice in 0..slices {
+      self.add_oriented_face([center, first_vertex + slice, first_vertex + (slice + 1) % slices]);
+    }
+  }
+}
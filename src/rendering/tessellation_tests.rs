@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests {
+  use std::f64;
+
+  use crate::rendering::math::tuple::Tuple;
+  use crate::rendering::math::Point;
+  use crate::rendering::math::Vector;
+
+  use crate::rendering::math::Color;
+
+  use crate::rendering::math::Matrix4x4;
+
+  use crate::rendering::shapes::shape::Shape;
+  use crate::rendering::shapes::Cone;
+
+  use crate::rendering::Tessellation;
+
+  use crate::rendering::Material;
+
+  fn material() -> Material {
+    Material::solid(0.1, 0.9, 0.9, 200.0, 0.0, 0.0, 1.0, Color::new(1.0, 1.0, 1.0, 1.0), Matrix4x4::identity())
+  }
+
+  // Every face wound counter clockwise when viewed from side its normals point to
+  fn faces_follow_normals(tessellation: &Tessellation) -> bool {
+    tessellation.faces.iter().all(|face| {
+      let edge_1 = tessellation.vertices[face[1]].subtract_point(&tessellation.vertices[face[0]]);
+      let edge_2 = tessellation.vertices[face[2]].subtract_point(&tessellation.vertices[face[0]]);
+
+      edge_1.cross(&edge_2).dot(&tessellation.normals[face[0]]) > 0.0
+    })
+  }
+
+  #[test]
+  fn tessellation_created_with_material() {
+    let mut tessellation = Tessellation::new(material());
+
+    let vertex_1 = tessellation.add_vertex(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    let vertex_2 = tessellation.add_vertex(Point::new(1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    let vertex_3 = tessellation.add_vertex(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_face([vertex_1, vertex_3, vertex_2]);
+
+    assert_eq!(tessellation.vertices.len(), 3);
+    assert_eq!(tessellation.normals.len(), 3);
+    assert_eq!(tessellation.faces, vec![[0, 2, 1]]);
+    assert!(tessellation.group.is_empty());
+    assert!(tessellation.override_material.is_none());
+    assert!(tessellation.get_material().color_1 == Color::new(1.0, 1.0, 1.0, 1.0));
+  }
+
+  #[test]
+  fn oriented_faces_wind_around_their_normals() {
+    let mut tessellation = Tessellation::new(material());
+
+    tessellation.add_vertex(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_vertex(Point::new(1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_vertex(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    tessellation.add_vertex(Point::new(2.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+    tessellation.add_oriented_face([0, 1, 2]);
+    tessellation.add_oriented_face([0, 2, 1]);
+
+    // No area
+    tessellation.add_oriented_face([0, 1, 3]);
+
+    assert_eq!(tessellation.faces, vec![[0, 1, 2], [0, 1, 2]]);
+  }
+
+  #[test]
+  fn transforming_tessellation_moves_vertices_and_normals() {
+    let mut tessellation = Tessellation::new(material());
+    tessellation.add_vertex(Point::new(1.0, 0.0, 0.0), Vector::new(1.0, 1.0, 0.0).normalize());
+
+    let tessellation = tessellation.transform(&Matrix4x4::translate(0.0, 5.0, 0.0).mult4x4(&Matrix4x4::scale(2.0, 1.0, 1.0)));
+
+    assert!(tessellation.vertices[0] == Point::new(2.0, 5.0, 0.0));
+    assert!((tessellation.normals[0].x - 1.0 / 5.0_f64.sqrt()).abs() < 0.0001);
+    assert!((tessellation.normals[0].y - 2.0 / 5.0_f64.sqrt()).abs() < 0.0001);
+  }
+
+  #[test]
+  fn curved_shapes_use_at_least_three_segments() {
+    assert_eq!(Tessellation::segments(0), 3);
+    assert_eq!(Tessellation::segments(16), 16);
+  }
+
+  #[test]
+  fn tessellating_sphere() {
+    let tessellation = Tessellation::sphere(8, material());
+
+    // Rings touching poles need one triangle per segment instead of two
+    assert_eq!(tessellation.faces.len(), 48);
+    assert!(faces_follow_normals(&tessellation));
+
+    for (vertex, normal) in tessellation.vertices.iter().zip(tessellation.normals.iter()) {
+      assert!((Vector::new(vertex.x, vertex.y, vertex.z).magnitude() - 1.0).abs() < 0.0001);
+      assert!((normal.x - vertex.x).abs() < 0.0001 && (normal.y - vertex.y).abs() < 0.0001 && (normal.z - vertex.z).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn tessellating_cube() {
+    let tessellation = Tessellation::cube(material());
+
+    assert_eq!(tessellation.vertices.len(), 24);
+    assert_eq!(tessellation.faces.len(), 12);
+    assert!(faces_follow_normals(&tessellation));
+
+    for vertex in &tessellation.vertices {
+      assert!(vertex.x.abs() == 1.0 && vertex.y.abs() == 1.0 && vertex.z.abs() == 1.0);
+    }
+  }
+
+  #[test]
+  fn tessellating_plane_bounds_it_by_extent() {
+    let tessellation = Tessellation::plane(50.0, material());
+
+    assert_eq!(tessellation.faces.len(), 2);
+    assert!(faces_follow_normals(&tessellation));
+    assert!(tessellation.vertices.contains(&Point::new(-50.0, 0.0, -50.0)));
+    assert!(tessellation.vertices.contains(&Point::new(50.0, 0.0, 50.0)));
+  }
+
+  #[test]
+  fn tessellating_cylinder() {
+    let tessellation = Tessellation::cylinder(1.0, 2.0, false, 6, 100.0, material());
+
+    assert_eq!(tessellation.faces.len(), 12);
+    assert!(faces_follow_normals(&tessellation));
+
+    for (vertex, normal) in tessellation.vertices.iter().zip(tessellation.normals.iter()) {
+      assert!(vertex.y == 1.0 || vertex.y == 2.0);
+      assert!((vertex.x * vertex.x + vertex.z * vertex.z - 1.0).abs() < 0.0001);
+      assert!(normal.y == 0.0);
+    }
+
+    // Caps add one triangle per segment at each end
+    let tessellation = Tessellation::cylinder(1.0, 2.0, true, 6, 100.0, material());
+
+    assert_eq!(tessellation.faces.len(), 24);
+    assert!(faces_follow_normals(&tessellation));
+  }
+
+  #[test]
+  fn tessellating_infinite_cylinder_cuts_it_at_extent() {
+    let tessellation = Tessellation::cylinder(f64::NEG_INFINITY, f64::INFINITY, false, 6, 10.0, material());
+
+    for vertex in &tessellation.vertices {
+      assert!(vertex.y == -10.0 || vertex.y == 10.0);
+    }
+  }
+
+  #[test]
+  fn tessellating_double_cone_splits_it_at_apex() {
+    let tessellation = Tessellation::cone(-1.0, 2.0, true, 8, 100.0, material());
+    let cone = Cone::new(Matrix4x4::identity(), true, -1.0, 2.0, material());
+
+    // Two sides, with apex rings having no area, and two caps
+    assert_eq!(tessellation.faces.len(), 32);
+    assert!(faces_follow_normals(&tessellation));
+
+    let mut side_vertices = 0;
+    for (vertex, normal) in tessellation.vertices.iter().zip(tessellation.normals.iter()) {
+      let radius = (vertex.x * vertex.x + vertex.z * vertex.z).sqrt();
+
+      // Side normals match cone, away from apex
+      if radius > 0.0001 && normal.y.abs() < 1.0 {
+        let cone_normal = cone.normal_at(vertex);
+
+        assert!((normal.x - cone_normal.x).abs() < 0.0001);
+        assert!((normal.y - cone_normal.y).abs() < 0.0001);
+        assert!((normal.z - cone_normal.z).abs() < 0.0001);
+
+        side_vertices += 1;
+      }
+    }
+
+    assert_eq!(side_vertices, 18);
+  }
+
+  #[test]
+  fn tessellating_torus() {
+    let tessellation = Tessellation::torus(1.0, 0.25, 8, material());
+
+    assert_eq!(tessellation.faces.len(), 64);
+    assert!(faces_follow_normals(&tessellation));
+
+    for vertex in &tessellation.vertices {
+      let ring_distance = (vertex.x * vertex.x + vertex.z * vertex.z).sqrt();
+
+      assert!(((ring_distance - 1.0).powi(2) + vertex.y * vertex.y - 0.0625).abs() < 0.0001);
+    }
+  }
+}